@@ -3,7 +3,7 @@
 mod analyze_test;
 
 use super::*;
-use vrp_cli::extensions::analyze::get_clusters;
+use vrp_cli::extensions::analyze::{get_clusters, get_reachability};
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -13,52 +13,83 @@ const EPSILON_ARG_NAME: &str = "epsilon";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
 
 pub fn get_analyze_app() -> Command<'static> {
-    Command::new("analyze").about("Provides helper functionality to analyze problem or solution").subcommand(
-        Command::new("clusters")
-            .about("Analyzes job clusters")
-            .arg(
-                Arg::new(FORMAT_ARG_NAME)
-                    .help("Specifies input type")
-                    .required(true)
-                    .possible_values(&["pragmatic"])
-                    .index(1),
-            )
-            .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
-            .arg(
-                Arg::new(MIN_POINTS_ARG_NAME)
-                    .help("Minimum cluster size")
-                    .short('c')
-                    .default_value("3")
-                    .long(MIN_POINTS_ARG_NAME)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(EPSILON_ARG_NAME)
-                    .help("Epsilon parameter in DBSCAN")
-                    .short('e')
-                    .long(EPSILON_ARG_NAME)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(MATRIX_ARG_NAME)
-                    .help("Specifies path to file with routing matrix")
-                    .short('m')
-                    .long(MATRIX_ARG_NAME)
-                    .multiple_values(true)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(OUT_RESULT_ARG_NAME)
-                    .help("Specifies path to the file for result output")
-                    .short('o')
-                    .long(OUT_RESULT_ARG_NAME)
-                    .required(true)
-                    .takes_value(true),
-            ),
-    )
+    Command::new("analyze")
+        .about("Provides helper functionality to analyze problem or solution")
+        .subcommand(
+            Command::new("clusters")
+                .about("Analyzes job clusters")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .possible_values(&["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(MIN_POINTS_ARG_NAME)
+                        .help("Minimum cluster size")
+                        .short('c')
+                        .default_value("3")
+                        .long(MIN_POINTS_ARG_NAME)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(EPSILON_ARG_NAME)
+                        .help("Epsilon parameter in DBSCAN")
+                        .short('e')
+                        .long(EPSILON_ARG_NAME)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix in json or binary format")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .multiple_values(true)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            Command::new("reachability")
+                .about("Analyzes reachability of locations using routing matrix error codes")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .possible_values(&["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix in json or binary format")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .multiple_values(true)
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(false)
+                        .takes_value(true),
+                ),
+        )
 }
 
 pub fn run_analyze(
@@ -92,6 +123,29 @@ pub fn run_analyze(
 
             geo_writer.write_all(clusters.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
         }
+        Some(("reachability", reachability_matches)) => {
+            let problem_path = reachability_matches.value_of(PROBLEM_ARG_NAME).unwrap();
+            let problem_format = reachability_matches.value_of(FORMAT_ARG_NAME).unwrap();
+
+            if problem_format != "pragmatic" {
+                return Err(format!("unknown problem format: '{}'", problem_format));
+            }
+
+            let problem_reader = BufReader::new(open_file(problem_path, "problem"));
+
+            let matrices_readers = reachability_matches
+                .values_of(MATRIX_ARG_NAME)
+                .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+            let reachability = get_reachability(problem_reader, matrices_readers)
+                .map_err(|err| format!("cannot analyze reachability: '{}'", err))?;
+
+            let out_result =
+                reachability_matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_writer = out_writer_func(out_result);
+
+            out_writer.write_all(reachability.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
+        }
         _ => Err("no argument with analyze subcommand was used. Use -h to print help information".to_string()),
     }
 }
@@ -38,7 +38,7 @@ pub fn get_check_app() -> Command<'static> {
         )
         .arg(
             Arg::new(MATRIX_ARG_NAME)
-                .help("Specifies path to file with routing matrix")
+                .help("Specifies path to file with routing matrix in json or binary format")
                 .short('m')
                 .long(MATRIX_ARG_NAME)
                 .multiple_values(true)
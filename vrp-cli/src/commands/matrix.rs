@@ -0,0 +1,123 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/matrix_test.rs"]
+mod matrix_test;
+
+use super::*;
+use std::collections::HashMap;
+use std::path::Path;
+use vrp_cli::extensions::matrix::{create_matrices, MatrixBackend};
+use vrp_pragmatic::format::problem::{deserialize_road_graph, serialize_matrix_binary, Matrix, RoadNetwork};
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "PROBLEM";
+const BACKEND_ARG_NAME: &str = "backend";
+const ROAD_GRAPH_ARG_NAME: &str = "road-graph";
+const BINARY_ARG_NAME: &str = "binary";
+const OUT_DIR_ARG_NAME: &str = "out-dir";
+
+pub fn get_matrix_app() -> Command<'static> {
+    Command::new("matrix")
+        .about("Provides the way to build routing matrices for the problem")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::new(BACKEND_ARG_NAME)
+                .help(
+                    "Specifies routing backend used to calculate durations and distances: approx uses profile \
+                     distance metric, haversine, manhattan and euclidean override it (geocoordinates are projected \
+                     on a plane for the last two). All, except road-graph, apply profile speed, speed curve and circuity",
+                )
+                .short('b')
+                .long(BACKEND_ARG_NAME)
+                .required(false)
+                .default_value("approx")
+                .possible_values(&["approx", "haversine", "manhattan", "euclidean", "road-graph"])
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(ROAD_GRAPH_ARG_NAME)
+                .help("Specifies path to file with road graph, required by road-graph backend")
+                .short('g')
+                .long(ROAD_GRAPH_ARG_NAME)
+                .required_if_eq(BACKEND_ARG_NAME, "road-graph")
+                .takes_value(true),
+        )
+        .arg(Arg::new(BINARY_ARG_NAME).help("Writes matrices in binary format").long(BINARY_ARG_NAME).required(false))
+        .arg(
+            Arg::new(OUT_DIR_ARG_NAME)
+                .help("Specifies path to the directory for matrix files output")
+                .short('o')
+                .long(OUT_DIR_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+}
+
+pub fn run_matrix(matches: &ArgMatches) -> Result<(), String> {
+    let problem_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    if problem_format != "pragmatic" {
+        return Err(format!("unknown problem format: '{}'", problem_format));
+    }
+
+    let problem_reader = BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let backend = get_backend(matches)?;
+
+    let matrices =
+        create_matrices(problem_reader, &backend).map_err(|err| format!("cannot create matrices: '{}'", err))?;
+
+    write_matrices(matches, matrices.as_slice())
+}
+
+fn get_backend(matches: &ArgMatches) -> Result<MatrixBackend, String> {
+    match matches.value_of(BACKEND_ARG_NAME).unwrap() {
+        "approx" => Ok(MatrixBackend::Approximation),
+        "haversine" => Ok(MatrixBackend::Haversine),
+        "manhattan" => Ok(MatrixBackend::Manhattan),
+        "euclidean" => Ok(MatrixBackend::Euclidean),
+        "road-graph" => {
+            let path = matches.value_of(ROAD_GRAPH_ARG_NAME).unwrap();
+            let graph = deserialize_road_graph(BufReader::new(open_file(path, "road graph")))?;
+
+            RoadNetwork::new(graph)
+                .map(MatrixBackend::RoadNetwork)
+                .map_err(|err| format!("cannot create road network: '{}'", err))
+        }
+        backend => Err(format!("unknown backend: '{}'", backend)),
+    }
+}
+
+fn write_matrices(matches: &ArgMatches, matrices: &[Matrix]) -> Result<(), String> {
+    let out_dir = Path::new(matches.value_of(OUT_DIR_ARG_NAME).unwrap());
+    let is_binary = matches.is_present(BINARY_ARG_NAME);
+    let extension = if is_binary { "bin" } else { "json" };
+
+    let mut profile_counts = HashMap::<String, usize>::new();
+    let has_timestamps = matrices.iter().any(|matrix| matrix.timestamp.is_some());
+
+    matrices.iter().try_for_each(|matrix| {
+        let profile = matrix.profile.clone().unwrap_or_default();
+        let file_name = if has_timestamps {
+            let count = profile_counts.entry(profile.clone()).or_insert(0);
+            *count += 1;
+            format!("{}.{}.matrix.{}", profile, *count - 1, extension)
+        } else {
+            format!("{}.matrix.{}", profile, extension)
+        };
+
+        let path = out_dir.join(file_name);
+        let writer = BufWriter::new(create_file(path.to_str().unwrap_or_default(), "routing matrix"));
+
+        if is_binary {
+            serialize_matrix_binary(writer, matrix, true).map_err(|err| err.to_string())
+        } else {
+            serde_json::to_writer(writer, matrix).map_err(|err| err.to_string())
+        }
+        .map_err(|err| format!("cannot write matrix to '{}': '{}'", path.display(), err))
+    })
+}
@@ -41,6 +41,10 @@ const PARALLELISM_ARG_NAME: &str = "parallelism";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
+const REOPTIMIZE_ARG_NAME: &str = "reoptimize";
+const REOPTIMIZE_AT_ARG_NAME: &str = "at";
+const FLEET_SIZING_ARG_NAME: &str = "fleet-sizing";
+const PLAN_REFERENCE_ARG_NAME: &str = "plan-reference";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
@@ -116,18 +120,24 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
-    use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
+fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>, logger: Option<InfoLogger>) {
+    use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
     use vrp_pragmatic::format::solution::PragmaticSolution;
 
     formats.insert(
         "pragmatic",
         (
-            ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+            ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
                 if let Some(matrices) = matrices {
-                    let matrices = matrices.into_iter().map(BufReader::new).collect();
-                    (BufReader::new(problem), matrices).read_pragmatic()
+                    deserialize_problem(BufReader::new(problem)).and_then(|problem| {
+                        let matrices = matrices
+                            .into_iter()
+                            .map(|matrix| deserialize_matrix(BufReader::new(matrix)))
+                            .collect::<Result<Vec<_>, _>>()?;
+
+                        read_pragmatic_with_warnings(problem, Some(matrices), None, logger.as_ref())
+                    })
                 } else {
                     BufReader::new(problem).read_pragmatic()
                 }
@@ -158,11 +168,49 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>)
     );
 }
 
-fn get_formats<'a>(matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) -> FormatMap<'a> {
+/// Reads pragmatic problem with optional routing matrices and plan reference and logs validation
+/// warnings when logger is specified.
+fn read_pragmatic_with_warnings(
+    problem: vrp_pragmatic::format::problem::Problem,
+    matrices: Option<Vec<vrp_pragmatic::format::problem::Matrix>>,
+    plan_reference: Option<&vrp_pragmatic::format::solution::Solution>,
+    logger: Option<&InfoLogger>,
+) -> Result<Problem, Vec<vrp_pragmatic::format::FormatError>> {
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+    use vrp_pragmatic::format::CoordIndex;
+    use vrp_pragmatic::validation::ValidationContext;
+
+    // NOTE warnings do not prevent solving, so they are only logged
+    if let Some(logger) = logger {
+        let coord_index = CoordIndex::new(&problem);
+        ValidationContext::new(&problem, matrices.as_ref(), &coord_index)
+            .get_warnings()
+            .iter()
+            .for_each(|warning| (logger)(format!("warning: {}", warning).as_str()));
+    }
+
+    if let Some(plan_reference) = plan_reference {
+        (problem, matrices, plan_reference).read_pragmatic()
+    } else {
+        (problem, matrices).read_pragmatic()
+    }
+}
+
+fn get_logger(matches: &ArgMatches, environment: &Environment) -> Option<InfoLogger> {
+    if matches.is_present(LOG_ARG_NAME) {
+        Some(environment.logger.clone())
+    } else {
+        None
+    }
+}
+
+fn get_formats<'a>(matches: &ArgMatches, environment: &Environment) -> FormatMap<'a> {
     let mut formats = FormatMap::default();
+    let random = environment.random.clone();
+    let logger = get_logger(matches, environment);
 
     add_scientific(&mut formats, matches, random.clone());
-    add_pragmatic(&mut formats, random);
+    add_pragmatic(&mut formats, random, logger);
 
     formats
 }
@@ -221,7 +269,7 @@ pub fn get_solve_app() -> Command<'static> {
         )
         .arg(
             Arg::new(MATRIX_ARG_NAME)
-                .help("Specifies path to file with routing matrix")
+                .help("Specifies path to file with routing matrix in json or binary format")
                 .short('m')
                 .long(MATRIX_ARG_NAME)
                 .multiple_values(true)
@@ -313,6 +361,47 @@ pub fn get_solve_app() -> Command<'static> {
                 .required(false)
                 .takes_value(false),
         )
+        .arg(
+            Arg::new(REOPTIMIZE_ARG_NAME)
+                .help(
+                    "Specifies path to previous solution which executed part is kept and the rest is re-optimized. \
+                     Applicable only for pragmatic format.",
+                )
+                .long(REOPTIMIZE_ARG_NAME)
+                .required(false)
+                .requires(REOPTIMIZE_AT_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(REOPTIMIZE_AT_ARG_NAME)
+                .help("Specifies re-optimization time in RFC3339 format")
+                .long(REOPTIMIZE_AT_ARG_NAME)
+                .required(false)
+                .requires(REOPTIMIZE_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(FLEET_SIZING_ARG_NAME)
+                .help(
+                    "Specifies whether fleet sizing mode is enabled: vehicles of each type are added on demand and \
+                     the solver determines how many of each type are needed. The summary is written to solution \
+                     extras. Applicable only for pragmatic format.",
+                )
+                .long(FLEET_SIZING_ARG_NAME)
+                .required(false)
+                .conflicts_with(REOPTIMIZE_ARG_NAME)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::new(PLAN_REFERENCE_ARG_NAME)
+                .help(
+                    "Specifies path to previous solution used as a reference plan by minimize-plan-changes objective. \
+                     Applicable only for pragmatic format.",
+                )
+                .long(PLAN_REFERENCE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 /// Runs solver commands.
@@ -324,7 +413,7 @@ pub fn run_solve(
 
     let environment = get_environment(matches, max_time)?;
 
-    let formats = get_formats(matches, environment.random.clone());
+    let formats = get_formats(matches, environment.as_ref());
 
     // required
     let problem_path = matches.value_of(PROBLEM_ARG_NAME).unwrap();
@@ -349,6 +438,11 @@ pub fn run_solve(
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
     let mode = matches.value_of(SEARCH_MODE_ARG_NAME);
+    let reoptimization = matches.value_of(REOPTIMIZE_ARG_NAME).map(|path| open_file(path, "previous solution"));
+    let reoptimization_at = matches.value_of(REOPTIMIZE_AT_ARG_NAME);
+    let is_fleet_sizing = matches.is_present(FLEET_SIZING_ARG_NAME);
+    let plan_reference = matches.value_of(PLAN_REFERENCE_ARG_NAME).map(|path| open_file(path, "plan reference"));
+    let logger = get_logger(matches, environment.as_ref());
 
     match formats.get(problem_format) {
         Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
@@ -358,7 +452,30 @@ pub fn run_solve(
             if is_get_locations_set {
                 locations_writer.0(problem_file, out_buffer).map_err(|err| format!("cannot get locations '{}'", err))
             } else {
-                match problem_reader.0(problem_file, matrix_files) {
+                let problem = match (reoptimization, reoptimization_at) {
+                    (Some(solution_file), Some(at)) if problem_format == "pragmatic" => read_reoptimization_problem(
+                        problem_file,
+                        solution_file,
+                        at,
+                        matrix_files,
+                        plan_reference,
+                        logger.as_ref(),
+                    ),
+                    (Some(_), _) => Err("re-optimization is supported only for pragmatic format".to_string()),
+                    _ if is_fleet_sizing && problem_format == "pragmatic" => {
+                        read_fleet_sizing_problem(problem_file, matrix_files, plan_reference, logger.as_ref())
+                    }
+                    _ if is_fleet_sizing => Err("fleet sizing is supported only for pragmatic format".to_string()),
+                    _ if plan_reference.is_some() && problem_format == "pragmatic" => {
+                        read_modified_pragmatic_problem(problem_file, matrix_files, plan_reference, logger.as_ref(), Ok)
+                    }
+                    _ if plan_reference.is_some() => {
+                        Err("plan reference is supported only for pragmatic format".to_string())
+                    }
+                    _ => problem_reader.0(problem_file, matrix_files),
+                };
+
+                match problem {
                     Ok(problem) => {
                         let problem = Arc::new(problem);
                         let solutions = init_solution
@@ -520,6 +637,69 @@ fn get_heuristic(
     }
 }
 
+fn read_reoptimization_problem(
+    problem: File,
+    solution: File,
+    at: &str,
+    matrices: Option<Vec<File>>,
+    plan_reference: Option<File>,
+    logger: Option<&InfoLogger>,
+) -> Result<Problem, String> {
+    use vrp_pragmatic::format::solution::{create_reoptimization_problem, deserialize_solution};
+
+    let solution = deserialize_solution(BufReader::new(solution))
+        .map_err(|err| format!("cannot read previous solution: '{}'", err))?;
+
+    read_modified_pragmatic_problem(problem, matrices, plan_reference, logger, |problem| {
+        create_reoptimization_problem(&problem, &solution, at)
+    })
+}
+
+fn read_fleet_sizing_problem(
+    problem: File,
+    matrices: Option<Vec<File>>,
+    plan_reference: Option<File>,
+    logger: Option<&InfoLogger>,
+) -> Result<Problem, String> {
+    use vrp_pragmatic::format::problem::create_fleet_sizing_problem;
+
+    read_modified_pragmatic_problem(problem, matrices, plan_reference, logger, |problem| {
+        create_fleet_sizing_problem(&problem)
+    })
+}
+
+fn read_modified_pragmatic_problem<F>(
+    problem: File,
+    matrices: Option<Vec<File>>,
+    plan_reference: Option<File>,
+    logger: Option<&InfoLogger>,
+    modify_fn: F,
+) -> Result<Problem, String>
+where
+    F: FnOnce(vrp_pragmatic::format::problem::Problem) -> Result<vrp_pragmatic::format::problem::Problem, String>,
+{
+    use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
+    use vrp_pragmatic::format::solution::deserialize_solution;
+
+    let problem = deserialize_problem(BufReader::new(problem)).map_err(|errors| get_errors_serialized(&errors))?;
+    let matrices = matrices
+        .map(|matrices| {
+            matrices.into_iter().map(|matrix| deserialize_matrix(BufReader::new(matrix))).collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|errors| get_errors_serialized(&errors))?;
+
+    let plan_reference = plan_reference
+        .map(|solution| deserialize_solution(BufReader::new(solution)))
+        .transpose()
+        .map_err(|err| format!("cannot read plan reference: '{}'", err))?;
+
+    let problem = modify_fn(problem)?;
+
+    read_pragmatic_with_warnings(problem, matrices, plan_reference.as_ref(), logger)
+        .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
+}
+
 fn check_pragmatic_solution_with_args(matches: &ArgMatches) -> Result<(), String> {
     check_solution(matches, "pragmatic", PROBLEM_ARG_NAME, OUT_RESULT_ARG_NAME, MATRIX_ARG_NAME)
 }
@@ -2,3 +2,6 @@
 
 mod clusters;
 pub use self::clusters::get_clusters;
+
+mod reachability;
+pub use self::reachability::*;
@@ -0,0 +1,171 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/reachability_test.rs"]
+mod reachability_test;
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::{CoordIndex, FormatError, Location};
+
+/// Reachability of locations within routing matrices of a single profile.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileReachability {
+    /// A profile name.
+    pub profile: String,
+    /// Locations which cannot be reached from and cannot reach any other location.
+    pub isolated: Vec<IsolatedLocation>,
+    /// Pairs of locations which are reachable only in one direction.
+    pub asymmetric: Vec<AsymmetricLeg>,
+    /// Vehicle shifts which cannot reach some job locations.
+    pub shifts: Vec<ShiftReachability>,
+}
+
+/// An isolated location.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedLocation {
+    /// A location.
+    pub location: Location,
+    /// Ids of jobs which use the location.
+    pub job_ids: Vec<String>,
+}
+
+/// A leg which is reachable from `from` to `to`, but not in the opposite direction.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AsymmetricLeg {
+    /// A start location of reachable leg.
+    pub from: Location,
+    /// An end location of reachable leg.
+    pub to: Location,
+}
+
+/// Job locations which are unreachable for specific vehicle shift.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShiftReachability {
+    /// A vehicle type id.
+    pub type_id: String,
+    /// A shift index.
+    pub shift_index: usize,
+    /// Job locations which cannot be reached from shift start.
+    pub unreachable_from_start: Vec<Location>,
+    /// Job locations from which shift end cannot be reached.
+    pub unreachable_to_end: Vec<Location>,
+}
+
+/// Analyzes reachability of locations using routing matrix error codes and returns result in json format.
+pub fn get_reachability<F: Read>(
+    problem_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<String, String> {
+    let problem = deserialize_problem(problem_reader).map_err(|errs| FormatError::format_many(&errs, ","))?;
+    let matrices = matrices_readers
+        .ok_or_else(|| "routing matrices are required to analyze reachability".to_string())?
+        .into_iter()
+        .map(deserialize_matrix)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|errs| FormatError::format_many(&errs, ","))?;
+
+    let reachability = analyze_reachability(&problem, matrices.as_slice());
+
+    serde_json::to_string_pretty(&reachability).map_err(|err| format!("cannot write reachability: '{}'", err))
+}
+
+fn analyze_reachability(problem: &Problem, matrices: &[Matrix]) -> Vec<ProfileReachability> {
+    let coord_index = CoordIndex::new(problem);
+    let get_location = |idx: usize| coord_index.get_by_idx(idx).expect("unknown location index");
+
+    let mut indices =
+        coord_index.unique().iter().filter_map(|location| coord_index.get_by_loc(location)).collect::<Vec<_>>();
+    indices.sort_unstable();
+
+    let job_ids = problem.plan.jobs.iter().fold(HashMap::<usize, Vec<String>>::new(), |mut acc, job| {
+        job.pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .chain(job.replacements.iter())
+            .chain(job.services.iter())
+            .flat_map(|tasks| tasks.iter().flat_map(|task| task.places.iter()))
+            .filter_map(|place| coord_index.get_by_loc(&place.location))
+            .for_each(|idx| {
+                let ids = acc.entry(idx).or_default();
+                if !ids.contains(&job.id) {
+                    ids.push(job.id.clone());
+                }
+            });
+        acc
+    });
+    let mut job_indices = job_ids.keys().cloned().collect::<Vec<_>>();
+    job_indices.sort_unstable();
+
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| {
+            let profile_matrices = get_profile_matrices(problem, matrices, &profile.name);
+            let is_unreachable = |from: usize, to: usize| is_unreachable_leg(profile_matrices.as_slice(), from, to);
+
+            let isolated = indices
+                .iter()
+                .filter(|&&idx| {
+                    indices.len() > 1
+                        && indices
+                            .iter()
+                            .filter(|&&other| other != idx)
+                            .all(|&other| is_unreachable(idx, other) && is_unreachable(other, idx))
+                })
+                .map(|&idx| IsolatedLocation {
+                    location: get_location(idx),
+                    job_ids: job_ids.get(&idx).cloned().unwrap_or_default(),
+                })
+                .collect();
+
+            let asymmetric = indices
+                .iter()
+                .flat_map(|&from| indices.iter().map(move |&to| (from, to)))
+                .filter(|&(from, to)| from != to && !is_unreachable(from, to) && is_unreachable(to, from))
+                .map(|(from, to)| AsymmetricLeg { from: get_location(from), to: get_location(to) })
+                .collect();
+
+            let shifts = problem
+                .fleet
+                .vehicles
+                .iter()
+                .filter(|vehicle| vehicle.profile.matrix == profile.name)
+                .flat_map(|vehicle| {
+                    vehicle.shifts.iter().enumerate().map(move |(shift_index, shift)| (vehicle, shift_index, shift))
+                })
+                .map(|(vehicle, shift_index, shift)| {
+                    let start = coord_index.get_by_loc(&shift.start.location);
+                    let end = shift.end.as_ref().and_then(|end| coord_index.get_by_loc(&end.location));
+
+                    let get_unreachable = |is_leg_unreachable: &dyn Fn(usize) -> bool| {
+                        job_indices
+                            .iter()
+                            .filter(|&&idx| is_leg_unreachable(idx))
+                            .map(|&idx| get_location(idx))
+                            .collect()
+                    };
+
+                    ShiftReachability {
+                        type_id: vehicle.type_id.clone(),
+                        shift_index,
+                        unreachable_from_start: get_unreachable(&|idx| {
+                            start.map_or(false, |start| is_unreachable(start, idx))
+                        }),
+                        unreachable_to_end: get_unreachable(&|idx| end.map_or(false, |end| is_unreachable(idx, end))),
+                    }
+                })
+                .filter(|shift: &ShiftReachability| {
+                    !shift.unreachable_from_start.is_empty() || !shift.unreachable_to_end.is_empty()
+                })
+                .collect();
+
+            ProfileReachability { profile: profile.name.clone(), isolated, asymmetric, shifts }
+        })
+        .collect()
+}
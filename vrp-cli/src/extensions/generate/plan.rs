@@ -37,6 +37,8 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            parking: place.parking,
+                            access: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -68,11 +70,15 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                consistency_key: job_proto.consistency_key.clone(),
+                frequency: job_proto.frequency.clone(),
+                priority: None,
+                value_curve: None,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, areas: None, clustering: None })
+    Ok(Plan { jobs, relations: None, areas: None, clustering: None, max_neighbors: None })
 }
 
 type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
@@ -71,6 +71,8 @@ mod actual {
                 duration: job.duration as f64 * 60.,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                parking: None,
+                access: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
@@ -102,6 +104,10 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                consistency_key: None,
+                frequency: None,
+                priority: None,
+                value_curve: None,
             })
             .collect();
 
@@ -117,7 +123,7 @@ mod actual {
                 VehicleType {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
-                    profile: VehicleProfile { matrix: vehicle.profile, scale: None },
+                    profile: VehicleProfile { matrix: vehicle.profile, scale: None, buffer: None },
                     costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
@@ -129,10 +135,15 @@ mod actual {
                         dispatch: None,
                         breaks: None,
                         reloads: None,
+                        onboard: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
                     limits: None,
+                    parking: None,
+                    tags: None,
+                    attributes: None,
+                    is_unlimited: None,
                 }
             })
             .collect();
@@ -159,13 +170,18 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, areas: None, clustering: None },
+            plan: Plan { jobs, relations: None, areas: None, clustering: None, max_neighbors: None },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, speed_curve: None, circuity: None, metric: None })
+                    .collect(),
                 resources: None,
+                limits: None,
             },
             objectives: None,
+            precision: None,
         })
     }
 }
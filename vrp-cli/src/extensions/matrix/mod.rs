@@ -0,0 +1,92 @@
+//! Provides functionality to build routing matrices for pragmatic problem.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/matrix/matrix_test.rs"]
+mod matrix_test;
+
+use std::io::{BufReader, Read};
+use vrp_pragmatic::core::algorithms::geometry::Point;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::{CoordIndex, FormatError, Location};
+use vrp_pragmatic::get_unique_locations;
+use vrp_pragmatic::validation::ValidationContext;
+
+/// Specifies a backend used to calculate routing matrices. All backends, except road network, respect
+/// profile speed, speed curve (time of day) and circuity.
+pub enum MatrixBackend {
+    /// The same approximation as used when no routing matrix is passed: distance metric is picked by profile.
+    Approximation,
+    /// Haversine distance between geocoordinates.
+    Haversine,
+    /// Manhattan distance, geocoordinates are projected on a plane.
+    Manhattan,
+    /// Euclidean distance, geocoordinates are projected on a plane.
+    Euclidean,
+    /// Fastest paths on a local road network.
+    RoadNetwork(RoadNetwork),
+}
+
+/// Creates routing matrices for each profile of pragmatic problem using given backend.
+pub fn create_matrices<R: Read>(problem_reader: BufReader<R>, backend: &MatrixBackend) -> Result<Vec<Matrix>, String> {
+    let problem = deserialize_problem(problem_reader).map_err(|errs| FormatError::format_many(&errs, ","))?;
+
+    let matrices = match backend {
+        MatrixBackend::Approximation => create_approx_matrices(&problem),
+        MatrixBackend::Haversine => {
+            let locations = get_unique_locations(&problem);
+            if !locations.iter().all(|location| matches!(location, Location::Coordinate { .. })) {
+                return Err("haversine backend requires geocoordinates".to_string());
+            }
+
+            // NOTE approximation always uses haversine formula for geocoordinates
+            create_approx_matrices(&problem)
+        }
+        MatrixBackend::Manhattan => {
+            let distances = get_planar_distances(&problem, |p1, p2| p1.manhattan_distance_to_point(p2))?;
+            create_approx_matrices_with_distances(&problem, distances.as_slice())
+        }
+        MatrixBackend::Euclidean => {
+            let distances = get_planar_distances(&problem, |p1, p2| p1.distance_to_point(p2))?;
+            create_approx_matrices_with_distances(&problem, distances.as_slice())
+        }
+        MatrixBackend::RoadNetwork(network) => create_road_network_matrices(&problem, network)?,
+    };
+
+    let coord_index = CoordIndex::new(&problem);
+    ValidationContext::new(&problem, Some(&matrices), &coord_index)
+        .validate()
+        .map_err(|errs| FormatError::format_many(&errs, ","))?;
+
+    Ok(matrices)
+}
+
+fn get_planar_distances<F>(problem: &Problem, distance_fn: F) -> Result<Vec<f64>, String>
+where
+    F: Fn(&Point, &Point) -> f64,
+{
+    // NOTE use equirectangular projection which is accurate enough within a city
+    const EARTH_RADIUS: f64 = 6_371_000.;
+
+    let locations = get_unique_locations(problem);
+    let latitudes = locations
+        .iter()
+        .filter_map(|location| match location {
+            Location::Coordinate { lat, .. } => Some(lat.to_radians()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let mean_lat = latitudes.iter().sum::<f64>() / latitudes.len().max(1) as f64;
+
+    let points = locations
+        .into_iter()
+        .map(|location| match location {
+            Location::Coordinate { lat, lng } => {
+                Ok(Point::new(EARTH_RADIUS * lng.to_radians() * mean_lat.cos(), EARTH_RADIUS * lat.to_radians()))
+            }
+            Location::Point { x, y } => Ok(Point::new(x, y)),
+            Location::Reference { .. } => Err("planar distance requires geocoordinates or points".to_string()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(points.iter().flat_map(|p1| points.iter().map(move |p2| (p1, p2))).map(|(p1, p2)| distance_fn(p1, p2)).collect())
+}
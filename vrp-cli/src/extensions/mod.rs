@@ -6,6 +6,8 @@ pub mod analyze;
 pub mod check;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod generate;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod matrix;
 
 pub mod import;
 pub mod solve;
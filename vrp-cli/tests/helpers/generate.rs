@@ -12,6 +12,10 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        consistency_key: None,
+        frequency: None,
+        priority: None,
+        value_curve: None,
     }
 }
 
@@ -20,18 +24,25 @@ pub fn create_empty_job_task() -> JobTask {
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        parking: None,
+        access: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, areas: None, clustering: None }
+    Plan { jobs: vec![], relations: None, areas: None, clustering: None, max_neighbors: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
     VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
-        profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+        profile: VehicleProfile { matrix: "car".to_string(), scale: None, buffer: None },
         costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
         shifts: vec![VehicleShift {
             start: ShiftStart {
@@ -43,15 +54,20 @@ pub fn create_test_vehicle_type() -> VehicleType {
             dispatch: None,
             breaks: None,
             reloads: None,
+            onboard: None,
         }],
         capacity: vec![10],
         skills: None,
         limits: None,
+        parking: None,
+        tags: None,
+        attributes: None,
+        is_unlimited: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, speed_curve: None, circuity: None, metric: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
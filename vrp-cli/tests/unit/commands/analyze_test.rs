@@ -1,6 +1,7 @@
 use super::*;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
 
 struct DummyWrite {}
 
@@ -30,6 +31,21 @@ fn can_run_analyze_clusters() {
     run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
 }
 
+#[test]
+fn can_run_analyze_reachability() {
+    let args = vec!["analyze", "reachability", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--matrix", PRAGMATIC_MATRIX_PATH];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
+
+#[test]
+fn can_detect_missing_matrix_for_reachability() {
+    let args = vec!["analyze", "reachability", "pragmatic", PRAGMATIC_PROBLEM_PATH];
+
+    assert!(get_analyze_app().try_get_matches_from(args).is_err());
+}
+
 #[test]
 fn can_detect_wrong_argument() {
     let args = vec!["analyze", "clusters", "solomon", PRAGMATIC_PROBLEM_PATH, "--out-result", "/some/path"];
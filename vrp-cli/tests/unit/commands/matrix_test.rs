@@ -0,0 +1,57 @@
+use super::*;
+use vrp_pragmatic::format::problem::deserialize_matrix;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn run_matrix_with_args(params: &[&str]) -> Result<tempfile::TempDir, String> {
+    let out_dir = tempfile::tempdir().unwrap();
+    let args =
+        [&["matrix", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--out-dir", out_dir.path().to_str().unwrap()], params]
+            .concat();
+    let matches = get_matrix_app().try_get_matches_from(args).unwrap();
+
+    run_matrix(&matches).map(|_| out_dir)
+}
+
+fn read_matrix(out_dir: &tempfile::TempDir, file_name: &str) -> Matrix {
+    let file = File::open(out_dir.path().join(file_name)).expect("cannot open matrix file");
+
+    deserialize_matrix(BufReader::new(file)).expect("cannot read matrix")
+}
+
+#[test]
+fn can_write_matrix_per_profile() {
+    let out_dir = run_matrix_with_args(&["--backend", "approx"]).unwrap();
+
+    let matrix = read_matrix(&out_dir, "normal_car.matrix.json");
+
+    assert_eq!(matrix.profile, Some("normal_car".to_string()));
+    assert_eq!(matrix.travel_times.len(), 16);
+}
+
+#[test]
+fn can_write_matrix_with_metric_backend() {
+    let out_dir = run_matrix_with_args(&["--backend", "manhattan"]).unwrap();
+
+    let matrix = read_matrix(&out_dir, "normal_car.matrix.json");
+
+    assert_eq!(matrix.profile, Some("normal_car".to_string()));
+    assert_eq!(matrix.distances.len(), 16);
+}
+
+#[test]
+fn can_write_matrix_in_binary_format() {
+    let out_dir = run_matrix_with_args(&["--binary"]).unwrap();
+
+    let matrix = read_matrix(&out_dir, "normal_car.matrix.bin");
+
+    assert_eq!(matrix.profile, Some("normal_car".to_string()));
+    assert!(matrix.timestamp.is_none());
+}
+
+#[test]
+fn can_require_road_graph_for_road_graph_backend() {
+    let args = vec!["matrix", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--out-dir", ".", "--backend", "road-graph"];
+
+    assert!(get_matrix_app().try_get_matches_from(args).is_err());
+}
@@ -1,6 +1,8 @@
 use super::*;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
 const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
 const LILIM_PROBLEM_PATH: &str = "../examples/data/scientific/lilim/LC101.txt";
 
@@ -34,6 +36,99 @@ fn can_solve_pragmatic_problem_with_generation_limit() {
     run_solve_with_out_writer(&matches);
 }
 
+#[test]
+fn can_solve_pragmatic_problem_with_binary_matrix() {
+    use vrp_pragmatic::format::problem::{deserialize_matrix, serialize_matrix_binary};
+
+    let matrix = deserialize_matrix(BufReader::new(File::open(PRAGMATIC_MATRIX_PATH).unwrap())).unwrap();
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    serialize_matrix_binary(BufWriter::new(tmpfile.reopen().unwrap()), &matrix, true).unwrap();
+    let matrix_path = tmpfile.path().to_str().unwrap();
+
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--matrix", matrix_path, "--max-generations", "1"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_reoptimize_pragmatic_solution() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--reoptimize",
+        PRAGMATIC_SOLUTION_PATH,
+        "--at",
+        "2019-07-04T10:30:00Z",
+        "--max-generations",
+        "1",
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_require_reoptimization_time() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--reoptimize", PRAGMATIC_SOLUTION_PATH];
+
+    assert!(get_solve_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_solve_pragmatic_problem_in_fleet_sizing_mode() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--fleet-sizing",
+        "--max-generations",
+        "1",
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_reject_fleet_sizing_with_reoptimization() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--fleet-sizing",
+        "--reoptimize",
+        PRAGMATIC_SOLUTION_PATH,
+        "--at",
+        "2019-07-04T10:30:00Z",
+    ];
+
+    assert!(get_solve_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_plan_reference() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--plan-reference",
+        PRAGMATIC_SOLUTION_PATH,
+        "--max-generations",
+        "1",
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
 #[test]
 fn can_solve_lilim_problem_with_multiple_limits() {
     let args = vec!["solve", "lilim", LILIM_PROBLEM_PATH, "--max-time", "300", "--max-generations", "1"];
@@ -0,0 +1,96 @@
+use super::*;
+use std::fs::File;
+
+const PROBLEM: &str = r#"
+{
+  "plan": {
+    "jobs": [
+      { "id": "job1", "deliveries": [{ "places": [{ "location": { "lat": 0, "lng": 1 }, "duration": 0 }], "demand": [1] }] },
+      { "id": "job2", "deliveries": [{ "places": [{ "location": { "lat": 0, "lng": 2 }, "duration": 0 }], "demand": [1] }] }
+    ]
+  },
+  "fleet": {
+    "vehicles": [{
+      "typeId": "vehicle", "vehicleIds": ["vehicle_1"], "profile": { "matrix": "car" },
+      "costs": { "fixed": 0, "distance": 1, "time": 1 },
+      "shifts": [{
+        "start": { "earliest": "2020-07-04T09:00:00Z", "location": { "lat": 0, "lng": 0 } },
+        "end": { "latest": "2020-07-04T18:00:00Z", "location": { "lat": 0, "lng": 0 } }
+      }],
+      "capacity": [2]
+    }],
+    "profiles": [{ "name": "car" }]
+  }
+}
+"#;
+
+const MATRIX: &str = r#"
+{
+  "profile": "car",
+  "travelTimes": [0, 1, 1, 1, 0, 1, 1, 1, 0],
+  "distances": [0, 1, 1, 1, 0, 1, 1, 1, 0],
+  "errorCodes": [0, 1, 1, 1, 0, 1, 0, 1, 0]
+}
+"#;
+
+fn to_lng(location: &Location) -> f64 {
+    match location {
+        Location::Coordinate { lng, .. } => *lng,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn can_analyze_reachability() {
+    let problem = deserialize_problem(BufReader::new(PROBLEM.as_bytes())).unwrap();
+    let matrix = deserialize_matrix(BufReader::new(MATRIX.as_bytes())).unwrap();
+
+    let result = analyze_reachability(&problem, &[matrix]);
+
+    assert_eq!(result.len(), 1);
+    let result = result.first().unwrap();
+    assert_eq!(result.profile, "car");
+    assert_eq!(result.isolated.len(), 1);
+    assert_eq!(to_lng(&result.isolated[0].location), 2.);
+    assert_eq!(result.isolated[0].job_ids, vec!["job2".to_string()]);
+    assert_eq!(result.asymmetric.len(), 1);
+    assert_eq!((to_lng(&result.asymmetric[0].from), to_lng(&result.asymmetric[0].to)), (0., 1.));
+    assert_eq!(result.shifts.len(), 1);
+    assert_eq!(result.shifts[0].type_id, "vehicle");
+    assert_eq!(result.shifts[0].shift_index, 0);
+    assert_eq!(result.shifts[0].unreachable_from_start.iter().map(to_lng).collect::<Vec<_>>(), vec![2.]);
+    assert_eq!(result.shifts[0].unreachable_to_end.iter().map(to_lng).collect::<Vec<_>>(), vec![1., 2.]);
+}
+
+#[test]
+fn can_get_reachability_as_json() {
+    let result =
+        get_reachability(BufReader::new(PROBLEM.as_bytes()), Some(vec![BufReader::new(MATRIX.as_bytes())])).unwrap();
+
+    assert!(result.contains("isolated"));
+    assert!(result.contains("asymmetric"));
+    assert!(result.contains("unreachableFromStart"));
+}
+
+#[test]
+fn can_report_no_issues_for_reachable_locations() {
+    let problem = BufReader::new(
+        File::open("../examples/data/pragmatic/simple.basic.problem.json").expect("cannot read problem file"),
+    );
+    let matrix = BufReader::new(
+        File::open("../examples/data/pragmatic/simple.basic.matrix.json").expect("cannot read matrix file"),
+    );
+
+    let result = get_reachability(problem, Some(vec![matrix])).unwrap();
+
+    assert!(result.contains("\"isolated\": []"));
+    assert!(result.contains("\"asymmetric\": []"));
+    assert!(result.contains("\"shifts\": []"));
+}
+
+#[test]
+fn can_detect_missing_matrices() {
+    let result = get_reachability(BufReader::new(PROBLEM.as_bytes()), None);
+
+    assert!(result.is_err());
+}
@@ -8,6 +8,7 @@ fn can_generate_bounding_box() {
         relations: None,
         areas: None,
         clustering: None,
+        max_neighbors: None,
     };
 
     let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_plan(&plan);
@@ -25,6 +26,7 @@ fn can_get_bounding_box_from_size() {
         relations: None,
         areas: None,
         clustering: None,
+        max_neighbors: None,
     };
 
     let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_size(&plan, 100.);
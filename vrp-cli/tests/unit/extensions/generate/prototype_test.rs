@@ -18,8 +18,10 @@ fn can_generate_jobs_with_time_windows() {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![create_test_vehicle_profile()],
             resources: None,
+            limits: None,
         },
         objectives: None,
+        precision: None,
     };
 
     let result =
@@ -0,0 +1,148 @@
+use super::*;
+use std::fs::File;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn create_test_matrices(backend: &MatrixBackend) -> Result<Vec<Matrix>, String> {
+    let problem = BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).expect("cannot read problem file"));
+
+    create_matrices(problem, backend)
+}
+
+fn create_planar_problem(profiles: &str, precision: &str) -> String {
+    format!(
+        r#"
+    {{
+      "plan": {{
+        "jobs": [
+          {{ "id": "job1", "deliveries": [{{ "places": [{{ "location": {{ "x": 30, "y": 40 }}, "duration": 0 }}], "demand": [1] }}] }}
+        ]
+      }},
+      "fleet": {{
+        "vehicles": [{{
+          "typeId": "vehicle", "vehicleIds": ["vehicle_1"], "profile": {{ "matrix": "car" }},
+          "costs": {{ "fixed": 0, "distance": 1, "time": 1 }},
+          "shifts": [{{ "start": {{ "earliest": "2020-07-04T09:00:00Z", "location": {{ "x": 0, "y": 0 }} }} }}],
+          "capacity": [1]
+        }}],
+        "profiles": [{}]
+      }}{}
+    }}
+    "#,
+        profiles, precision
+    )
+}
+
+#[test]
+fn can_create_matrices_with_approximation_backend() {
+    let problem = deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap();
+
+    let matrices = create_test_matrices(&MatrixBackend::Approximation).unwrap();
+
+    assert_eq!(matrices.len(), 1);
+    assert_eq!(matrices[0].profile, Some("normal_car".to_string()));
+    assert_eq!(matrices[0].travel_times.len(), 16);
+    assert!(matrices[0].timestamp.is_none());
+    let expected = create_approx_matrices(&problem);
+    assert_eq!(matrices[0].travel_times, expected[0].travel_times);
+    assert_eq!(matrices[0].distances, expected[0].distances);
+}
+
+#[test]
+fn can_use_profile_metric_for_planar_points() {
+    let problem = create_planar_problem(
+        r#"{ "name": "car", "speed": 1 }, { "name": "walk", "speed": 1, "metric": "manhattan" }"#,
+        "",
+    );
+
+    let matrices = create_matrices(BufReader::new(problem.as_bytes()), &MatrixBackend::Approximation).unwrap();
+
+    assert_eq!(matrices.len(), 2);
+    for (matrix, (profile, expected)) in matrices.iter().zip([("car", 50), ("walk", 70)]) {
+        assert_eq!(matrix.profile, Some(profile.to_string()));
+        assert_eq!(matrix.distances, vec![0, expected, expected, 0]);
+        assert_eq!(matrix.travel_times, vec![0, expected, expected, 0]);
+    }
+}
+
+#[test]
+fn can_use_profile_speed_curve() {
+    let problem = create_planar_problem(
+        r#"{ "name": "car", "speedCurve": [{ "hour": 0, "speed": 10 }, { "hour": 7, "speed": 5 }] }"#,
+        "",
+    );
+
+    let matrices = create_matrices(BufReader::new(problem.as_bytes()), &MatrixBackend::Approximation).unwrap();
+
+    assert!(matrices.len() > 1);
+    assert!(matrices.iter().all(|matrix| matrix.timestamp.is_some()));
+    assert_eq!(matrices[0].travel_times, vec![0, 5, 5, 0]);
+    assert_eq!(matrices[1].travel_times, vec![0, 10, 10, 0]);
+}
+
+#[test]
+fn can_override_profile_metric_for_planar_points() {
+    let problem = create_planar_problem(r#"{ "name": "car", "speed": 1, "metric": "manhattan" }"#, "");
+
+    let matrices = create_matrices(BufReader::new(problem.as_bytes()), &MatrixBackend::Euclidean).unwrap();
+
+    assert_eq!(matrices[0].distances, vec![0, 50, 50, 0]);
+}
+
+#[test]
+fn can_use_planar_metric_for_geocoordinates() {
+    let haversine = create_test_matrices(&MatrixBackend::Haversine).unwrap();
+    let manhattan = create_test_matrices(&MatrixBackend::Manhattan).unwrap();
+    let euclidean = create_test_matrices(&MatrixBackend::Euclidean).unwrap();
+
+    let zipped = haversine[0].distances.iter().zip(manhattan[0].distances.iter()).zip(euclidean[0].distances.iter());
+    zipped.for_each(|((&haversine, &manhattan), &euclidean)| {
+        assert!(manhattan >= euclidean);
+        assert!((euclidean - haversine).abs() <= 1 + haversine / 100);
+    });
+    assert!(manhattan[0].distances.iter().zip(euclidean[0].distances.iter()).any(|(m, e)| m > e));
+}
+
+#[test]
+fn can_reject_haversine_backend_for_planar_points() {
+    let problem = create_planar_problem(r#"{ "name": "car" }"#, "");
+
+    let result = create_matrices(BufReader::new(problem.as_bytes()), &MatrixBackend::Haversine);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_create_matrices_with_road_network_backend() {
+    let create_edge = |from: usize, to: usize| RoadEdge {
+        from,
+        to,
+        length: 1000.,
+        speeds: vec![("normal_car".to_string(), 10.)].into_iter().collect(),
+    };
+    let graph = RoadGraph {
+        nodes: vec![RoadNode { lat: 52.52, lng: 13.4 }, RoadNode { lat: 52.53, lng: 13.4 }],
+        edges: vec![create_edge(0, 1), create_edge(1, 0)],
+    };
+    let network = RoadNetwork::new(graph).unwrap();
+
+    let matrices = create_test_matrices(&MatrixBackend::RoadNetwork(network)).unwrap();
+
+    assert_eq!(matrices.len(), 1);
+    assert!(matrices[0].error_codes.is_none());
+    assert!(matrices[0].travel_times.iter().all(|&duration| duration == 0 || duration == 100));
+    assert!(matrices[0].distances.iter().any(|&distance| distance == 1000));
+}
+
+#[test]
+fn can_create_matrices_with_float_precision() {
+    let problem = create_planar_problem(r#"{ "name": "car", "speed": 2 }"#, r#", "precision": "float""#)
+        .replace(r#""x": 30, "y": 40"#, r#""x": 1, "y": 1"#);
+
+    let matrices = create_matrices(BufReader::new(problem.as_bytes()), &MatrixBackend::Approximation).unwrap();
+
+    let fractions = matrices[0].fractions.as_ref().expect("no fractions");
+    assert_eq!(matrices[0].distances, vec![0, 1, 1, 0]);
+    assert_eq!(fractions.distances, vec![0., 2_f64.sqrt(), 2_f64.sqrt(), 0.]);
+    assert_eq!(fractions.travel_times, vec![0., 2_f64.sqrt() / 2., 2_f64.sqrt() / 2., 0.]);
+}
@@ -6,8 +6,9 @@ use vrp_pragmatic::format::problem::{Fleet, MatrixProfile, Plan};
 fn can_get_locations_serialized() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], ..create_empty_plan() },
-        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], resources: None, limits: None },
         objectives: None,
+        precision: None,
     };
 
     let locations = get_locations_serialized(&problem).unwrap().replace(' ', "").replace('\n', "");
@@ -21,10 +22,18 @@ fn can_get_solution_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_curve: None,
+                circuity: None,
+                metric: None,
+            }],
             resources: None,
+            limits: None,
         },
         objectives: None,
+        precision: None,
     };
     let problem = Arc::new(problem.read_pragmatic().unwrap());
 
@@ -29,6 +29,11 @@ impl Point {
         (delta_x * delta_x + delta_y * delta_y).sqrt()
     }
 
+    /// Computes manhattan distance from given point to `other`
+    pub fn manhattan_distance_to_point(&self, other: &Point) -> f64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
     /// Computes distance from line, drawn by points a and b, to the point.
     pub fn distance_to_line(&self, a: &Point, b: &Point) -> f64 {
         let a_b_distance = a.distance_to_point(b);
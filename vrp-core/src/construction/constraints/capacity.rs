@@ -67,8 +67,11 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
             .cloned()
             .unwrap_or_else(|| vec![(0, route_ctx.route.tour.total() - 1)]);
 
+        // NOTE initial load stays on board until it is delivered
+        let initial_load = Self::get_initial_load(route_ctx.route.actor.vehicle.dimens.get_initial_load());
+
         let (_, max_load) =
-            reload_intervals.into_iter().fold((T::default(), T::default()), |(acc, max), (start_idx, end_idx)| {
+            reload_intervals.into_iter().fold((initial_load, T::default()), |(acc, max), (start_idx, end_idx)| {
                 let (route, state) = route_ctx.as_mut();
 
                 // determine static deliveries loaded at the begin and static pickups brought to the end
@@ -116,13 +119,16 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
     fn has_demand_violation(
         state: &RouteState,
         pivot: &Activity,
-        capacity: Option<&T>,
+        vehicle_dimens: &Dimensions,
         demand: Option<&Demand<T>>,
         stopped: bool,
     ) -> Option<bool> {
+        let capacity: Option<&T> = vehicle_dimens.get_capacity();
+
         if let Some(demand) = demand {
             if let Some(&capacity) = capacity {
-                let default = T::default();
+                // NOTE states are not yet calculated for empty route, so its load is the initial one
+                let default = Self::get_initial_load(vehicle_dimens.get_initial_load());
 
                 // check how static delivery affect past max load
                 if demand.delivery.0.is_not_empty() {
@@ -173,7 +179,7 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
             CapacityConstraintModule::<T>::has_demand_violation(
                 &ctx.state,
                 activity,
-                ctx.route.actor.vehicle.dimens.get_capacity(),
+                &ctx.route.actor.vehicle.dimens,
                 demand,
                 true,
             )
@@ -198,6 +204,10 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
     fn get_demand(activity: &Activity) -> Option<&Demand<T>> {
         activity.job.as_ref().and_then(|job| job.dimens.get_demand())
     }
+
+    fn get_initial_load(initial_load: Option<&T>) -> T {
+        initial_load.cloned().unwrap_or_default()
+    }
 }
 
 impl<T: LoadOps> ConstraintModule for CapacityConstraintModule<T> {
@@ -357,7 +367,7 @@ impl<T: LoadOps> HardActivityConstraint for CapacityHardActivityConstraint<T> {
             CapacityConstraintModule::<T>::has_demand_violation(
                 &route_ctx.state,
                 activity_ctx.prev,
-                route_ctx.route.actor.vehicle.dimens.get_capacity(),
+                &route_ctx.route.actor.vehicle.dimens,
                 demand,
                 !self.multi_trip.has_markers(route_ctx),
             )
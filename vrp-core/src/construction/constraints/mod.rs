@@ -55,6 +55,8 @@
 //! All constraint modules are organized inside one `ConstraintPipeline` which specifies the order
 //! of their execution.
 
+use crate::construction::heuristics::{RouteContext, SolutionContext, UnassignmentInfo};
+
 /// A key which tracks latest arrival.
 pub const LATEST_ARRIVAL_KEY: i32 = 1;
 /// A key which tracks waiting time.
@@ -65,6 +67,8 @@ pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
 /// A key which tracks global duration limit.
 pub const LIMIT_DURATION_KEY: i32 = 5;
+/// A key which tracks total extra transport cost, e.g. tolls.
+pub const TOTAL_EXTRA_COST_KEY: i32 = 6;
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
@@ -92,6 +96,33 @@ fn success() -> Option<ActivityConstraintViolation> {
     None
 }
 
+/// Removes used routes which are not allowed by given predicate and moves their jobs directly to unassigned
+/// with given code. As unassigned jobs are changed, the pipeline repeats its pass, so other modules can react
+/// on them (e.g. ignore breaks of removed tours). Dissolved jobs are returned to required list only on next
+/// insertion attempt. Routes with locked jobs are kept untouched.
+fn dissolve_routes<F>(solution_ctx: &mut SolutionContext, code: i32, is_not_allowed: F)
+where
+    F: Fn(&RouteContext) -> bool,
+{
+    // NOTE check only complete solutions as partial ones are expected to grow
+    if !solution_ctx.required.is_empty() {
+        return;
+    }
+
+    let locked = &solution_ctx.locked;
+    let (dissolved, routes): (Vec<_>, Vec<_>) = solution_ctx.routes.drain(..).partition(|route_ctx| {
+        route_ctx.route.tour.has_jobs()
+            && !route_ctx.route.tour.jobs().any(|job| locked.contains(&job))
+            && is_not_allowed(route_ctx)
+    });
+    solution_ctx.routes = routes;
+
+    dissolved.iter().for_each(|route_ctx| {
+        solution_ctx.registry.free_route(route_ctx);
+        solution_ctx.unassigned.extend(route_ctx.route.tour.jobs().map(|job| (job, UnassignmentInfo::Simple(code))));
+    });
+}
+
 mod pipeline;
 pub use self::pipeline::*;
 
@@ -118,3 +149,6 @@ pub use self::fleet_usage::*;
 
 mod travel_limit;
 pub use self::travel_limit::*;
+
+mod waiting_limit;
+pub use self::waiting_limit::*;
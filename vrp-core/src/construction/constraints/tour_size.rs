@@ -12,10 +12,13 @@ use std::sync::Arc;
 /// A function which returns tour size limit for given actor.
 pub type TourSizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 
-/// Limits amount of job activities per tour.
+/// Limits amount of job activities per tour. Max limit is checked on each insertion, min limit is checked
+/// on complete solution: a used tour with less job activities is dissolved and its jobs are unassigned
+/// with min limit code.
 pub struct TourSizeModule {
     state_keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
+    min_limit: Option<(TourSizeResolver, i32)>,
 }
 
 impl TourSizeModule {
@@ -24,8 +27,20 @@ impl TourSizeModule {
         Self {
             constraints: vec![ConstraintVariant::HardRoute(Arc::new(TourSizeHardRouteConstraint { code, limit_func }))],
             state_keys: vec![],
+            min_limit: None,
         }
     }
+
+    /// Creates a new instance of `TourSizeModule` with min tour size limit. Jobs of a dissolved tour are
+    /// unassigned with `min_code`.
+    pub fn new_with_min_limits(
+        max_limit_func: TourSizeResolver,
+        min_limit_func: TourSizeResolver,
+        code: i32,
+        min_code: i32,
+    ) -> Self {
+        Self { min_limit: Some((min_limit_func, min_code)), ..Self::new(max_limit_func, code) }
+    }
 }
 
 impl ConstraintModule for TourSizeModule {
@@ -33,7 +48,14 @@ impl ConstraintModule for TourSizeModule {
 
     fn accept_route_state(&self, _: &mut RouteContext) {}
 
-    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        if let Some((min_limit_func, min_code)) = self.min_limit.as_ref() {
+            dissolve_routes(solution_ctx, *min_code, |route_ctx| {
+                min_limit_func.deref()(route_ctx.route.actor.as_ref())
+                    .map_or(false, |limit| route_ctx.route.tour.job_activity_count() < limit)
+            });
+        }
+    }
 
     fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
         Ok(source)
@@ -5,8 +5,8 @@ mod transport_test;
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
 use crate::models::common::{Cost, Distance, Timestamp};
-use crate::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
-use crate::models::solution::Activity;
+use crate::models::problem::{get_setup_time, ActivityCost, Job, Single, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
 use crate::models::OP_START_MSG;
 use std::slice::Iter;
 use std::sync::Arc;
@@ -70,7 +70,13 @@ impl TransportConstraintModule {
         time_window_code: i32,
     ) -> Self {
         Self {
-            state_keys: vec![LATEST_ARRIVAL_KEY, WAITING_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY],
+            state_keys: vec![
+                LATEST_ARRIVAL_KEY,
+                WAITING_KEY,
+                TOTAL_DISTANCE_KEY,
+                TOTAL_DURATION_KEY,
+                TOTAL_EXTRA_COST_KEY,
+            ],
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(TimeHardRouteConstraint { code: time_window_code })),
                 ConstraintVariant::SoftRoute(Arc::new(RouteCostSoftRouteConstraint {})),
@@ -102,7 +108,9 @@ impl TransportConstraintModule {
         let route = route_ctx.route.clone();
 
         route_ctx.route_mut().tour.all_activities_mut().skip(1).fold(init, |(loc, dep), a| {
-            a.schedule.arrival = dep + transport.duration(&route, loc, a.place.location, TravelTime::Departure(dep));
+            a.schedule.arrival = dep
+                + transport.duration(&route, loc, a.place.location, TravelTime::Departure(dep))
+                + get_setup_time(activity, &route, loc, a);
             a.schedule.departure = activity.estimate_departure(&route, a, a.schedule.arrival);
 
             (a.place.location, a.schedule.departure)
@@ -124,6 +132,13 @@ impl TransportConstraintModule {
                 .as_ref()
                 .unwrap_or_else(|| actor.detail.start.as_ref().unwrap_or_else(|| panic!("{}", OP_START_MSG)))
                 .location,
+            // NOTE setup time at the end is applied only for closed vrp
+            actor
+                .detail
+                .end
+                .as_ref()
+                .and(route_ctx.route.tour.end())
+                .map_or(0., |end| activity.setup_time(&route_ctx.route, end)),
             0_f64,
         );
 
@@ -135,16 +150,18 @@ impl TransportConstraintModule {
                 return acc;
             }
 
-            let (end_time, prev_loc, waiting) = acc;
+            let (end_time, prev_loc, prev_setup, waiting) = acc;
+            let end_time = end_time - if act.place.location == prev_loc { 0. } else { prev_setup };
             let latest_departure =
                 end_time - transport.duration(&route, act.place.location, prev_loc, TravelTime::Arrival(end_time));
-            let latest_arrival_time = activity.estimate_arrival(&route, act, latest_departure);
+            let latest_arrival_time =
+                activity.estimate_arrival(&route, act, latest_departure).min(get_latest_service_start(&route, act));
             let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0.);
 
             state.put_activity_state(LATEST_ARRIVAL_KEY, act, latest_arrival_time);
             state.put_activity_state(WAITING_KEY, act, future_waiting);
 
-            (latest_arrival_time, act.place.location, future_waiting)
+            (latest_arrival_time, act.place.location, activity.setup_time(&route, act), future_waiting)
         });
     }
 
@@ -155,20 +172,24 @@ impl TransportConstraintModule {
 
         let total_dur = end.schedule.departure - start.schedule.departure;
 
-        let init = (start.place.location, start.schedule.departure, Distance::default());
-        let (_, _, total_dist) = route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist), a| {
-            let total_dist =
-                total_dist + transport.distance(route.as_ref(), loc, a.place.location, TravelTime::Departure(dep));
-            let total_dur = a.schedule.departure - start.schedule.departure;
+        let init = (start.place.location, start.schedule.departure, Distance::default(), Cost::default());
+        let (_, _, total_dist, total_extra_cost) =
+            route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist, total_extra_cost), a| {
+                let travel_time = TravelTime::Departure(dep);
+                let total_dist = total_dist + transport.distance(route.as_ref(), loc, a.place.location, travel_time);
+                let total_extra_cost =
+                    total_extra_cost + transport.extra_cost(route.as_ref(), loc, a.place.location, travel_time);
+                let total_dur = a.schedule.departure - start.schedule.departure;
 
-            route_ctx.state_mut().put_activity_state(TOTAL_DISTANCE_KEY, a, total_dist);
-            route_ctx.state_mut().put_activity_state(TOTAL_DURATION_KEY, a, total_dur);
+                route_ctx.state_mut().put_activity_state(TOTAL_DISTANCE_KEY, a, total_dist);
+                route_ctx.state_mut().put_activity_state(TOTAL_DURATION_KEY, a, total_dur);
 
-            (a.place.location, a.schedule.departure, total_dist)
-        });
+                (a.place.location, a.schedule.departure, total_dist, total_extra_cost)
+            });
 
         route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, total_dist);
         route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, total_dur);
+        route_ctx.state_mut().put_route_state(TOTAL_EXTRA_COST_KEY, total_extra_cost);
     }
 
     /// Updates route departure to the new one.
@@ -242,22 +263,22 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             return fail(self.code);
         }
 
-        let (next_act_location, latest_arr_time_at_next) = if let Some(next) = next {
+        let (next_act, latest_arr_time_at_next) = if let Some(next) = next {
             // closed vrp
             if actor.detail.time.end < next.place.time.start {
                 return fail(self.code);
             }
-            (
-                next.place.location,
-                *route_ctx.state.get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.time.end),
-            )
+            (next, *route_ctx.state.get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.time.end))
         } else {
             // open vrp
-            (target.place.location, target.place.time.end.min(actor.detail.time.end))
+            (target, get_latest_service_start(route, target).min(actor.detail.time.end))
         };
+        let next_act_location = next_act.place.location;
+        let activity = self.activity.as_ref();
 
         let arr_time_at_next = departure
-            + self.transport.duration(route, prev.place.location, next_act_location, TravelTime::Departure(departure));
+            + self.transport.duration(route, prev.place.location, next_act_location, TravelTime::Departure(departure))
+            + get_setup_time(activity, route, prev.place.location, next_act);
 
         if arr_time_at_next > latest_arr_time_at_next {
             return fail(self.code);
@@ -272,18 +293,24 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
                 prev.place.location,
                 target.place.location,
                 TravelTime::Departure(departure),
-            );
+            )
+            + get_setup_time(activity, route, prev.place.location, target);
 
-        let latest_departure_at_target = latest_arr_time_at_next
+        let latest_travel_end_at_next =
+            latest_arr_time_at_next - get_setup_time(activity, route, target.place.location, next_act);
+        let latest_departure_at_target = latest_travel_end_at_next
             - self.transport.duration(
                 route,
                 target.place.location,
                 next_act_location,
-                TravelTime::Arrival(latest_arr_time_at_next),
+                TravelTime::Arrival(latest_travel_end_at_next),
             );
 
-        let latest_arr_time_at_target =
-            target.place.time.end.min(self.activity.estimate_arrival(route, target, latest_departure_at_target));
+        let latest_arr_time_at_target = get_latest_service_start(route, target).min(self.activity.estimate_arrival(
+            route,
+            target,
+            latest_departure_at_target,
+        ));
 
         if arr_time_at_target > latest_arr_time_at_target {
             return stop(self.code);
@@ -301,7 +328,8 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
                 target.place.location,
                 next_act_location,
                 TravelTime::Departure(end_time_at_target),
-            );
+            )
+            + get_setup_time(activity, route, target.place.location, next_act);
 
         if arr_time_at_next > latest_arr_time_at_next {
             stop(self.code)
@@ -340,13 +368,17 @@ impl CostSoftActivityConstraint {
     ) -> (Cost, Cost, Timestamp) {
         let route = route_ctx.route.as_ref();
 
+        let setup_time = get_setup_time(self.activity.as_ref(), route, start.place.location, end);
         let arrival = time
-            + self.transport.duration(route, start.place.location, end.place.location, TravelTime::Departure(time));
+            + self.transport.duration(route, start.place.location, end.place.location, TravelTime::Departure(time))
+            + setup_time;
         let departure = self.activity.estimate_departure(route, end, arrival);
 
         let transport_cost =
             self.transport.cost(route, start.place.location, end.place.location, TravelTime::Departure(time));
-        let activity_cost = self.activity.cost(route, end, arrival);
+        let setup_cost =
+            setup_time * (route.actor.driver.costs.per_service_time + route.actor.vehicle.costs.per_service_time);
+        let activity_cost = self.activity.cost(route, end, arrival) + setup_cost;
 
         (transport_cost, activity_cost, departure)
     }
@@ -388,3 +420,10 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
         new_costs - old_costs
     }
 }
+
+/// Returns the latest service start of the activity taking into account profile's slack for job activities.
+fn get_latest_service_start(route: &Route, activity: &Activity) -> Timestamp {
+    let slack = if activity.job.is_some() { route.actor.vehicle.profile.get_slack() } else { 0. };
+
+    activity.place.time.end - slack
+}
@@ -9,11 +9,13 @@ use std::sync::Arc;
 
 type LimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
 
-/// A module which controls travel limits.
+/// A module which controls travel limits. Min duration limit is checked on complete solution:
+/// a used tour with shorter duration is dissolved and its jobs are unassigned with min duration code.
 pub struct TravelLimitModule {
     state_keys: Vec<i32>,
     constraints: Vec<ConstraintVariant>,
     tour_duration_limit: LimitFn<Duration>,
+    tour_min_duration_limit: Option<(LimitFn<Duration>, i32)>,
 }
 
 impl ConstraintModule for TravelLimitModule {
@@ -25,7 +27,15 @@ impl ConstraintModule for TravelLimitModule {
         }
     }
 
-    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        if let Some((tour_min_duration_limit, min_duration_code)) = self.tour_min_duration_limit.as_ref() {
+            dissolve_routes(solution_ctx, *min_duration_code, |route_ctx| {
+                tour_min_duration_limit.deref()(route_ctx.route.actor.as_ref()).map_or(false, |limit| {
+                    route_ctx.state.get_route_state::<Duration>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.) < limit
+                })
+            });
+        }
+    }
 
     fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
         Ok(source)
@@ -51,6 +61,7 @@ impl TravelLimitModule {
     ) -> Self {
         Self {
             tour_duration_limit: tour_duration_limit.clone(),
+            tour_min_duration_limit: None,
             state_keys: Vec::default(),
             constraints: vec![ConstraintVariant::HardActivity(Arc::new(TravelHardActivityConstraint {
                 distance_code,
@@ -61,6 +72,23 @@ impl TravelLimitModule {
             }))],
         }
     }
+
+    /// Creates a new instance of `TravelLimitModule` with min tour duration limit. Jobs of a dissolved
+    /// tour are unassigned with `min_duration_code`.
+    pub fn new_with_min_limits(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        tour_distance_limit: LimitFn<Distance>,
+        tour_duration_limit: LimitFn<Duration>,
+        tour_min_duration_limit: LimitFn<Duration>,
+        distance_code: i32,
+        duration_code: i32,
+        min_duration_code: i32,
+    ) -> Self {
+        Self {
+            tour_min_duration_limit: Some((tour_min_duration_limit, min_duration_code)),
+            ..Self::new(transport, tour_distance_limit, tour_duration_limit, distance_code, duration_code)
+        }
+    }
 }
 
 /// A hard activity constraint which allows to limit actor's traveling distance and time.
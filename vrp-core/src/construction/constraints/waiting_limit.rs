@@ -0,0 +1,162 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/waiting_limit_test.rs"]
+mod waiting_limit_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::*;
+use crate::models::problem::*;
+use crate::models::solution::*;
+use rosomaxa::prelude::compare_floats;
+use std::cmp::Ordering;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns waiting time limit for given actor.
+pub type WaitingLimitFn = Arc<dyn Fn(&Actor) -> Option<Duration> + Send + Sync>;
+
+/// A module which limits waiting time of the actor per stop and accumulated per tour.
+pub struct WaitingLimitModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl WaitingLimitModule {
+    /// Creates a new instance of `WaitingLimitModule`.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        stop_limit: WaitingLimitFn,
+        tour_limit: WaitingLimitFn,
+        code: i32,
+    ) -> Self {
+        Self {
+            state_keys: vec![],
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(WaitingHardActivityConstraint {
+                code,
+                transport,
+                activity,
+                stop_limit,
+                tour_limit,
+            }))],
+        }
+    }
+}
+
+impl ConstraintModule for WaitingLimitModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct WaitingHardActivityConstraint {
+    code: i32,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    stop_limit: WaitingLimitFn,
+    tour_limit: WaitingLimitFn,
+}
+
+impl HardActivityConstraint for WaitingHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let actor = route_ctx.route.actor.as_ref();
+        let stop_limit = self.stop_limit.deref()(actor);
+        let tour_limit = self.tour_limit.deref()(actor);
+
+        if stop_limit.is_none() && tour_limit.is_none() {
+            return None;
+        }
+
+        let route = route_ctx.route.as_ref();
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        // NOTE waiting decreases when target is served later, so search is not stopped here
+        let arrival = self.get_arrival(route, prev, target, prev.schedule.departure);
+        let target_waiting = (target.place.time.start - arrival).max(0.);
+
+        if stop_limit.map_or(false, |limit| target_waiting > limit) {
+            return stop(self.code);
+        }
+
+        if let Some(limit) = tour_limit {
+            let get_future_waiting = |activity: &Activity| {
+                route_ctx.state.get_activity_state::<Duration>(WAITING_KEY, activity).cloned().unwrap_or(0.)
+            };
+
+            let total_waiting = route_ctx.route.tour.get(1).map_or(0., get_future_waiting);
+            let prev_waiting = total_waiting - activity_ctx.next.map_or(0., get_future_waiting);
+
+            let new_total_waiting = prev_waiting
+                + target_waiting
+                + self.get_next_waiting(route_ctx, activity_ctx, arrival, limit - prev_waiting - target_waiting);
+
+            if new_total_waiting > limit {
+                return stop(self.code);
+            }
+        }
+
+        None
+    }
+}
+
+impl WaitingHardActivityConstraint {
+    /// Propagates schedule shift caused by target insertion to the next activities and returns their new
+    /// total waiting time. Propagation stops when schedule is not changed anymore or waiting exceeds the limit.
+    fn get_next_waiting(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        target_arrival: Timestamp,
+        limit: Duration,
+    ) -> Duration {
+        let route = route_ctx.route.as_ref();
+        let mut prev = activity_ctx.target;
+        let mut departure = self.activity.estimate_departure(route, prev, target_arrival);
+        let mut waiting = 0.;
+
+        for next in route.tour.all_activities().skip(activity_ctx.index + 1) {
+            if next.job.is_none() || waiting > limit {
+                break;
+            }
+
+            let arrival = self.get_arrival(route, prev, next, departure);
+
+            if compare_floats(arrival, next.schedule.arrival) == Ordering::Equal {
+                waiting += route_ctx.state.get_activity_state::<Duration>(WAITING_KEY, next).cloned().unwrap_or(0.);
+                break;
+            }
+
+            waiting += (next.place.time.start - arrival).max(0.);
+            departure = self.activity.estimate_departure(route, next, arrival);
+            prev = next;
+        }
+
+        waiting
+    }
+
+    fn get_arrival(&self, route: &Route, from: &Activity, to: &Activity, departure: Timestamp) -> Timestamp {
+        departure
+            + self.transport.duration(route, from.place.location, to.place.location, TravelTime::Departure(departure))
+            + get_setup_time(self.activity.as_ref(), route, from.place.location, to)
+    }
+}
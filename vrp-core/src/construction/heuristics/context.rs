@@ -256,8 +256,11 @@ impl RouteContext {
         let actor = &self.route.actor;
         let distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
         let duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+        let extra_cost = self.state.get_route_state::<f64>(TOTAL_EXTRA_COST_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        get_cost(&actor.vehicle.costs, distance, duration)
+            + get_cost(&actor.driver.costs, distance, duration)
+            + extra_cost
     }
 
     /// Unwraps given `RouteContext` as pair of mutable references.
@@ -460,8 +463,12 @@ impl RouteModifier {
 pub struct RegistryContext {
     registry: Registry,
     index: HashMap<Arc<Actor>, RouteContext>,
+    route_factory: RouteFactory,
 }
 
+/// Creates an empty route for actor added to the registry on fleet expansion.
+type RouteFactory = Arc<dyn Fn(Arc<Actor>) -> RouteContext + Sync + Send>;
+
 impl RegistryContext {
     /// Creates a new instance of `RouteRegistry`.
     pub fn new(constraint: Arc<ConstraintPipeline>, registry: Registry) -> Self {
@@ -474,6 +481,9 @@ impl RegistryContext {
         registry: Registry,
         modifier: &RouteModifier,
     ) -> Self {
+        let mut registry = registry;
+        registry.take_changes();
+
         let index = registry
             .all()
             .map(|actor| {
@@ -484,7 +494,15 @@ impl RegistryContext {
             })
             .collect();
 
-        Self { registry, index }
+        let modifier = modifier.modifier.clone();
+        let route_factory: RouteFactory = Arc::new(move |actor| {
+            let mut route_ctx = RouteContext::new(actor);
+            constraint.accept_route_state(&mut route_ctx);
+
+            modifier.deref()(route_ctx)
+        });
+
+        Self { registry, index, route_factory }
     }
 
     /// Returns underlying registry.
@@ -505,18 +523,26 @@ impl RegistryContext {
     /// Sets this route as used.
     /// Returns whether the route was already marked as used in the registry.
     pub fn use_route(&mut self, route: &RouteContext) -> bool {
-        self.registry.use_actor(&route.route.actor)
+        let is_used = self.registry.use_actor(&route.route.actor);
+        self.sync_actors();
+
+        is_used
     }
 
     /// Sets this route as unused.
     /// Returns whether the route was already unused in the registry.
     pub fn free_route(&mut self, route: &RouteContext) {
         self.registry.free_actor(&route.route.actor);
+        self.sync_actors();
     }
 
     /// Creates a deep copy of `RegistryContext`.
     pub fn deep_copy(&self) -> Self {
-        Self { registry: self.registry.deep_copy(), index: self.index.clone() }
+        Self {
+            registry: self.registry.deep_copy(),
+            index: self.index.clone(),
+            route_factory: self.route_factory.clone(),
+        }
     }
 
     /// Creates a deep sliced copy of RegistryContext` keeping only specific actors data.
@@ -527,7 +553,27 @@ impl RegistryContext {
             .filter(|(actor, _)| filter(actor.as_ref()))
             .map(|(actor, route_ctx)| (actor.clone(), route_ctx.clone()))
             .collect();
-        Self { registry: self.registry.deep_slice(filter), index }
+        Self { registry: self.registry.deep_slice(filter), index, route_factory: self.route_factory.clone() }
+    }
+
+    /// Returns route for given actor known by the registry, regardless of its usage.
+    pub fn get_route(&self, actor: &Actor) -> Option<RouteContext> {
+        self.index.get(actor).cloned()
+    }
+
+    /// Creates empty routes for actors added to the registry when fleet is expanded and drops
+    /// routes of removed ones.
+    fn sync_actors(&mut self) {
+        let (added, removed) = self.registry.take_changes();
+
+        removed.iter().for_each(|actor| {
+            self.index.remove(actor);
+        });
+
+        added.into_iter().for_each(|actor| {
+            let route_ctx = self.route_factory.deref()(actor.clone());
+            self.index.insert(actor, route_ctx);
+        });
     }
 }
 
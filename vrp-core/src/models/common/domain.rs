@@ -21,18 +21,52 @@ pub struct Profile {
     pub index: usize,
     /// A duration scale factor.
     pub scale: f64,
+    /// A travel time buffer.
+    pub buffer: Option<TravelBuffer>,
 }
 
 impl Profile {
     /// Creates a new instance of `Profile`.
     pub fn new(index: usize, scale: Option<f64>) -> Profile {
-        Self { index, scale: scale.unwrap_or(1.) }
+        Self { index, scale: scale.unwrap_or(1.), buffer: None }
+    }
+
+    /// Returns a travel time buffer for given travel duration.
+    pub fn get_buffer(&self, duration: Duration) -> Duration {
+        self.buffer.as_ref().map_or(0., |buffer| buffer.get_buffer(duration))
+    }
+
+    /// Returns a minimum slack required between job activity's service start and its time window end.
+    pub fn get_slack(&self) -> Duration {
+        self.buffer.as_ref().map_or(0., |buffer| buffer.slack)
     }
 }
 
 impl Default for Profile {
     fn default() -> Self {
-        Self { index: 0, scale: 1. }
+        Self { index: 0, scale: 1., buffer: None }
+    }
+}
+
+/// Specifies travel time buffers which make a schedule robust against small delays.
+#[derive(Clone, Debug, Default)]
+pub struct TravelBuffer {
+    /// A percentage of travel duration added to each leg.
+    pub percentage: f64,
+    /// A fixed duration added to each leg.
+    pub fixed: Duration,
+    /// A minimum slack between job activity's service start and its time window end.
+    pub slack: Duration,
+}
+
+impl TravelBuffer {
+    /// Returns a buffer for given travel duration. No buffer is applied to legs with zero duration.
+    pub fn get_buffer(&self, duration: Duration) -> Duration {
+        if duration > 0. {
+            duration * self.percentage / 100. + self.fixed
+        } else {
+            0.
+        }
     }
 }
 
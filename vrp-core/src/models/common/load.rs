@@ -12,6 +12,7 @@ use std::ops::{Add, Mul, Sub};
 
 const CAPACITY_DIMENSION_KEY: &str = "cpc";
 const DEMAND_DIMENSION_KEY: &str = "dmd";
+const INITIAL_LOAD_DIMENSION_KEY: &str = "ild";
 const LOAD_DIMENSION_SIZE: usize = 8;
 
 /// Represents a load type used to represent customer's demand or vehicle's load.
@@ -52,6 +53,15 @@ pub trait CapacityDimension<T: LoadOps> {
     fn get_capacity(&self) -> Option<&T>;
 }
 
+/// A trait to get or set vehicle's initial load: a cargo which is already on board at the start
+/// and stays there until it is delivered by some job activity.
+pub trait InitialLoadDimension<T: LoadOps> {
+    /// Sets initial load.
+    fn set_initial_load(&mut self, load: T) -> &mut Self;
+    /// Gets initial load.
+    fn get_initial_load(&self) -> Option<&T>;
+}
+
 /// A trait to get or set demand.
 pub trait DemandDimension<T: LoadOps> {
     /// Sets demand.
@@ -101,6 +111,17 @@ impl<T: LoadOps> CapacityDimension<T> for Dimensions {
     }
 }
 
+impl<T: LoadOps> InitialLoadDimension<T> for Dimensions {
+    fn set_initial_load(&mut self, load: T) -> &mut Self {
+        self.set_value(INITIAL_LOAD_DIMENSION_KEY, load);
+        self
+    }
+
+    fn get_initial_load(&self) -> Option<&T> {
+        self.get_value(INITIAL_LOAD_DIMENSION_KEY)
+    }
+}
+
 impl<T: LoadOps> DemandDimension<T> for Dimensions {
     fn set_demand(&mut self, demand: Demand<T>) -> &mut Self {
         self.set_value(DEMAND_DIMENSION_KEY, demand);
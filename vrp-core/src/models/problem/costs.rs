@@ -108,6 +108,12 @@ pub trait ActivityCost {
 
     /// Estimates arrival time for activity and actor at given departure time.
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp;
+
+    /// Returns a setup time (e.g. parking) which is spent once when actor arrives at activity's
+    /// location from a different location. Consecutive activities at the same location share it.
+    fn setup_time(&self, _route: &Route, _activity: &Activity) -> Duration {
+        0.
+    }
 }
 
 /// An actor independent activity costs.
@@ -124,6 +130,55 @@ impl ActivityCost for SimpleActivityCost {
     }
 }
 
+/// Returns setup time of the target activity when it is reached from given location: it is spent only
+/// when location is changed, so consecutive activities at the same location share it.
+pub fn get_setup_time(
+    activity: &(dyn ActivityCost + Send + Sync),
+    route: &Route,
+    from: Location,
+    target: &Activity,
+) -> Duration {
+    if from == target.place.location {
+        0.
+    } else {
+        activity.setup_time(route, target)
+    }
+}
+
+/// Specifies a function which returns a setup time for given route and activity.
+pub type SetupTimeFunc = Arc<dyn Fn(&Route, &Activity) -> Duration + Send + Sync>;
+
+/// An activity cost which decorates inner one with setup time applied once per stop.
+pub struct SetupActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+    setup_time_func: SetupTimeFunc,
+}
+
+impl SetupActivityCost {
+    /// Creates a new instance of `SetupActivityCost`.
+    pub fn new(inner: Arc<dyn ActivityCost + Send + Sync>, setup_time_func: SetupTimeFunc) -> Self {
+        Self { inner, setup_time_func }
+    }
+}
+
+impl ActivityCost for SetupActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        self.inner.cost(route, activity, arrival)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        self.inner.estimate_departure(route, activity, arrival)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        self.inner.estimate_arrival(route, activity, departure)
+    }
+
+    fn setup_time(&self, route: &Route, activity: &Activity) -> Duration {
+        self.setup_time_func.deref()(route, activity)
+    }
+}
+
 /// Specifies reserved time index type.
 pub type ReservedTimesIndex = HashMap<Arc<Actor>, Vec<TimeSpan>>;
 
@@ -196,6 +251,13 @@ pub trait TransportCost {
 
         distance * (actor.driver.costs.per_distance + actor.vehicle.costs.per_distance)
             + duration * (actor.driver.costs.per_driving_time + actor.vehicle.costs.per_driving_time)
+            + self.extra_cost(route, from, to, travel_time)
+    }
+
+    /// Returns time-dependent extra cost between two locations which is not derived from distance
+    /// or duration, e.g. tolls or road pricing. Default is zero.
+    fn extra_cost(&self, _route: &Route, _from: Location, _to: Location, _travel_time: TravelTime) -> Cost {
+        0.
     }
 
     /// Returns time-independent travel duration between locations specific for given profile.
@@ -209,6 +271,17 @@ pub trait TransportCost {
 
     /// Returns time-dependent travel distance between locations specific for given actor.
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance;
+
+    /// Returns a part of time-dependent travel duration which is a safety buffer. Default is zero.
+    fn duration_buffer(&self, _route: &Route, _from: Location, _to: Location, _travel_time: TravelTime) -> Duration {
+        0.
+    }
+
+    /// Returns a part of time-dependent travel duration which is spent on waiting for faster travel instead of
+    /// driving, e.g. when postponed departure leads to earlier arrival. Default is zero.
+    fn duration_waiting(&self, _route: &Route, _from: Location, _to: Location, _travel_time: TravelTime) -> Duration {
+        0.
+    }
 }
 
 /// Provides way to calculate transport costs which might contain reserved time.
@@ -251,6 +324,40 @@ impl TransportCost for DynamicTransportCost {
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
         self.inner.distance(route, from, to, travel_time)
     }
+
+    fn extra_cost(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Cost {
+        self.inner.extra_cost(route, from, to, travel_time)
+    }
+
+    fn duration_buffer(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.inner.duration_buffer(route, from, to, travel_time)
+    }
+
+    fn duration_waiting(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.inner.duration_waiting(route, from, to, travel_time)
+    }
+}
+
+/// Specifies how routing data is interpolated between matrices of the same profile with different timestamps.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MatrixInterpolation {
+    /// A value of the latest matrix with timestamp not after requested time is used.
+    Step,
+    /// A value is interpolated linearly between two adjacent matrices.
+    #[default]
+    Linear,
+}
+
+/// Specifies how matrix values are stored internally.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MatrixStorage {
+    /// Values are stored as 64 bit floats without precision loss.
+    #[default]
+    Full,
+    /// Values are stored as 32 bit floats to reduce memory usage: integral values up to 2^24 (16_777_216), e.g.
+    /// more than 194 days in seconds or 16_777 km in meters, are kept exactly, other values have relative error
+    /// not bigger than 2^-24 (about 6e-8).
+    Compact,
 }
 
 /// Contains matrix routing data for specific profile and, optionally, time.
@@ -263,12 +370,26 @@ pub struct MatrixData {
     pub durations: Vec<Duration>,
     /// Travel distances.
     pub distances: Vec<Distance>,
+    /// Extra travel costs which are not derived from distance or duration, e.g. tolls.
+    pub costs: Option<Vec<Cost>>,
+    /// An interpolation used between matrices of the same profile with different timestamps.
+    pub interpolation: MatrixInterpolation,
+    /// Specifies how values are stored by routing costs created from this data.
+    pub storage: MatrixStorage,
 }
 
 impl MatrixData {
     /// Creates `MatrixData` instance.
     pub fn new(index: usize, timestamp: Option<Timestamp>, durations: Vec<Duration>, distances: Vec<Distance>) -> Self {
-        Self { index, timestamp, durations, distances }
+        Self {
+            index,
+            timestamp,
+            durations,
+            distances,
+            costs: None,
+            interpolation: MatrixInterpolation::default(),
+            storage: MatrixStorage::default(),
+        }
     }
 }
 
@@ -292,6 +413,10 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn Tr
         return Err("duration lengths don't match".to_string());
     }
 
+    if costs.iter().any(|matrix| matrix.costs.as_ref().map_or(false, |costs| costs.len() != matrix.durations.len())) {
+        return Err("cost and duration collections have different length".to_string());
+    }
+
     Ok(if costs.iter().any(|costs| costs.timestamp.is_some()) {
         Arc::new(TimeAwareMatrixTransportCost::new(costs, size)?)
     } else {
@@ -299,10 +424,50 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn Tr
     })
 }
 
+/// Keeps matrix values using requested storage.
+enum MatrixValues {
+    Compact(Vec<f32>),
+    Full(Vec<f64>),
+}
+
+impl MatrixValues {
+    fn new(values: Vec<f64>, storage: MatrixStorage) -> Self {
+        match storage {
+            MatrixStorage::Compact => Self::Compact(values.into_iter().map(|value| value as f32).collect()),
+            MatrixStorage::Full => Self::Full(values),
+        }
+    }
+
+    fn get(&self, idx: usize) -> f64 {
+        match self {
+            Self::Compact(values) => values[idx] as f64,
+            Self::Full(values) => values[idx],
+        }
+    }
+}
+
+/// An internal representation of `MatrixData`.
+struct StoredMatrixData {
+    durations: MatrixValues,
+    distances: MatrixValues,
+    costs: Option<MatrixValues>,
+}
+
+impl From<MatrixData> for StoredMatrixData {
+    fn from(data: MatrixData) -> Self {
+        let storage = data.storage;
+
+        Self {
+            durations: MatrixValues::new(data.durations, storage),
+            distances: MatrixValues::new(data.distances, storage),
+            costs: data.costs.map(|costs| MatrixValues::new(costs, storage)),
+        }
+    }
+}
+
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost {
-    durations: Vec<Vec<Duration>>,
-    distances: Vec<Vec<Distance>>,
+    matrices: Vec<StoredMatrixData>,
     size: usize,
 }
 
@@ -320,24 +485,25 @@ impl TimeAgnosticMatrixTransportCost {
             return Err("duplicate profiles can be passed only for time aware routing".to_string());
         }
 
-        let (durations, distances) = costs.into_iter().fold((vec![], vec![]), |mut acc, data| {
-            acc.0.push(data.durations);
-            acc.1.push(data.distances);
+        let matrices = costs.into_iter().map(StoredMatrixData::from).collect();
 
-            acc
-        });
+        Ok(Self { matrices, size })
+    }
 
-        Ok(Self { durations, distances, size })
+    fn scaled_duration(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.matrices.get(profile.index).unwrap().durations.get(from * self.size + to) * profile.scale
     }
 }
 
 impl TransportCost for TimeAgnosticMatrixTransportCost {
     fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
-        *self.durations.get(profile.index).unwrap().get(from * self.size + to).unwrap() * profile.scale
+        let duration = self.scaled_duration(profile, from, to);
+
+        duration + profile.get_buffer(duration)
     }
 
     fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
-        *self.distances.get(profile.index).unwrap().get(from * self.size + to).unwrap()
+        self.matrices.get(profile.index).unwrap().distances.get(from * self.size + to)
     }
 
     fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
@@ -347,11 +513,24 @@ impl TransportCost for TimeAgnosticMatrixTransportCost {
     fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
         self.distance_approx(&route.actor.vehicle.profile, from, to)
     }
+
+    fn extra_cost(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Cost {
+        self.matrices
+            .get(route.actor.vehicle.profile.index)
+            .and_then(|matrix| matrix.costs.as_ref())
+            .map_or(0., |costs| costs.get(from * self.size + to))
+    }
+
+    fn duration_buffer(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        let profile = &route.actor.vehicle.profile;
+
+        profile.get_buffer(self.scaled_duration(profile, from, to))
+    }
 }
 
 /// A time aware matrix costs.
 struct TimeAwareMatrixTransportCost {
-    costs: HashMap<usize, (Vec<u64>, Vec<MatrixData>)>,
+    costs: HashMap<usize, TimeAwareMatrices>,
     size: usize,
 }
 
@@ -368,98 +547,278 @@ impl TimeAwareMatrixTransportCost {
             return Err("should not use time aware matrix routing with single matrix".to_string());
         }
 
+        if costs
+            .iter()
+            .any(|(_, matrices)| matrices.windows(2).any(|pair| pair[0].interpolation != pair[1].interpolation))
+        {
+            return Err("matrices of the same profile should have the same interpolation".to_string());
+        }
+
         let costs = costs
             .into_iter()
             .map(|(profile, mut matrices)| {
-                matrices.sort_by(|a, b| (a.timestamp.unwrap() as u64).cmp(&(b.timestamp.unwrap() as u64)));
-                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap() as u64).collect();
+                matrices.sort_by(|a, b| compare_floats(a.timestamp.unwrap(), b.timestamp.unwrap()));
 
-                (profile, (timestamps, matrices))
+                (profile, TimeAwareMatrices::new(matrices))
             })
             .collect();
 
         Ok(Self { costs, size })
     }
 
-    fn interpolate_duration(
-        &self,
-        profile: &Profile,
-        from: Location,
-        to: Location,
-        travel_time: TravelTime,
-    ) -> Duration {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
-        };
+    fn get_matrices(&self, profile: &Profile) -> &TimeAwareMatrices {
+        self.costs.get(&profile.index).unwrap()
+    }
+}
 
-        let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
-        let data_idx = from * self.size + to;
+impl TransportCost for TimeAwareMatrixTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        let duration = self.get_matrices(profile).average.durations.get(from * self.size + to) * profile.scale;
 
-        profile.scale
-            * match timestamps.binary_search(&(timestamp as u64)) {
-                Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap(),
-                Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().durations.get(data_idx).unwrap(),
-                Err(matrix_idx) if matrix_idx == matrices.len() => {
-                    *matrices.last().unwrap().durations.get(data_idx).unwrap()
-                }
-                Err(matrix_idx) => {
-                    let left_matrix = matrices.get(matrix_idx - 1).unwrap();
-                    let right_matrix = matrices.get(matrix_idx).unwrap();
+        duration + profile.get_buffer(duration)
+    }
 
-                    let left_value = *matrices.get(matrix_idx - 1).unwrap().durations.get(data_idx).unwrap();
-                    let right_value = *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap();
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.get_matrices(profile).average.distances.get(from * self.size + to)
+    }
 
-                    // perform linear interpolation
-                    let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                        / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let profile = &route.actor.vehicle.profile;
+        let (duration, waiting) =
+            self.get_matrices(profile).get_duration(profile.scale, from * self.size + to, travel_time);
 
-                    left_value + ratio * (right_value - left_value)
-                }
-            }
+        // NOTE buffer is applied only to driving time
+        duration + profile.get_buffer(duration - waiting)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        let profile = &route.actor.vehicle.profile;
+
+        self.get_matrices(profile).get_distance(profile.scale, from * self.size + to, travel_time)
+    }
+
+    fn extra_cost(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Cost {
+        let profile = &route.actor.vehicle.profile;
+
+        self.get_matrices(profile).get_extra_cost(profile.scale, from * self.size + to, travel_time)
+    }
+
+    fn duration_buffer(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let profile = &route.actor.vehicle.profile;
+        let (duration, waiting) =
+            self.get_matrices(profile).get_duration(profile.scale, from * self.size + to, travel_time);
+
+        profile.get_buffer(duration - waiting)
     }
 
-    fn interpolate_distance(
-        &self,
-        profile: &Profile,
-        from: Location,
-        to: Location,
-        travel_time: TravelTime,
-    ) -> Distance {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
+    fn duration_waiting(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let profile = &route.actor.vehicle.profile;
+
+        self.get_matrices(profile).get_duration(profile.scale, from * self.size + to, travel_time).1
+    }
+}
+
+/// Keeps time dependent routing data of one profile and implements a time dependent travel model which satisfies
+/// FIFO property: departing later never leads to earlier arrival as waiting for faster travel is taken into account.
+struct TimeAwareMatrices {
+    timestamps: Vec<Timestamp>,
+    matrices: Vec<StoredMatrixData>,
+    interpolation: MatrixInterpolation,
+    /// Time averaged routing data used for time independent (approximated) queries.
+    average: StoredMatrixData,
+}
+
+impl TimeAwareMatrices {
+    /// Creates a new instance of `TimeAwareMatrices` from matrices sorted by their timestamps.
+    fn new(matrices: Vec<MatrixData>) -> Self {
+        let interpolation = matrices.first().map(|matrix| matrix.interpolation).unwrap_or_default();
+        let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap()).collect::<Vec<_>>();
+        let weights = get_time_weights(timestamps.as_slice(), interpolation);
+
+        let get_average = |values_fn: &dyn Fn(&MatrixData) -> &Vec<f64>| {
+            (0..matrices.first().map_or(0, |matrix| matrix.durations.len()))
+                .map(|data_idx| {
+                    matrices.iter().zip(weights.iter()).try_fold(0., |acc, (matrix, weight)| {
+                        let value = values_fn(matrix)[data_idx];
+                        // NOTE negative value means that location is not reachable
+                        if value < 0. {
+                            Err(value)
+                        } else {
+                            Ok(acc + weight * value)
+                        }
+                    })
+                })
+                .map(|value| value.unwrap_or_else(|value| value))
+                .collect::<Vec<_>>()
         };
 
-        let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
-        let data_idx = from * self.size + to;
+        let average = StoredMatrixData::from(MatrixData {
+            storage: matrices.first().map(|matrix| matrix.storage).unwrap_or_default(),
+            ..MatrixData::new(
+                matrices.first().map_or(0, |matrix| matrix.index),
+                None,
+                get_average(&|matrix| &matrix.durations),
+                get_average(&|matrix| &matrix.distances),
+            )
+        });
+
+        Self {
+            timestamps,
+            matrices: matrices.into_iter().map(StoredMatrixData::from).collect(),
+            interpolation,
+            average,
+        }
+    }
 
-        match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                *matrices.last().unwrap().distances.get(data_idx).unwrap()
+    /// Returns travel duration and its part which is spent on waiting for faster travel before departure.
+    /// For arrival time, the latest departure is used, so there is no waiting before it.
+    fn get_duration(&self, scale: f64, data_idx: usize, travel_time: TravelTime) -> (Duration, Duration) {
+        match travel_time {
+            TravelTime::Departure(departure) => {
+                let (effective_departure, duration) = self.get_departure(scale, data_idx, departure);
+                (duration, effective_departure - departure)
             }
-            Err(matrix_idx) => *matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx).unwrap(),
+            TravelTime::Arrival(arrival) => (arrival - self.get_latest_departure(scale, data_idx, arrival), 0.),
         }
     }
-}
 
-impl TransportCost for TimeAwareMatrixTransportCost {
-    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
-        self.interpolate_duration(profile, from, to, TravelTime::Departure(0.))
+    fn get_distance(&self, scale: f64, data_idx: usize, travel_time: TravelTime) -> Distance {
+        let departure = self.get_effective_departure(scale, data_idx, travel_time);
+
+        self.get_value(departure, |matrix| matrix.distances.get(data_idx))
     }
 
-    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
-        self.interpolate_distance(profile, from, to, TravelTime::Departure(0.))
+    fn get_extra_cost(&self, scale: f64, data_idx: usize, travel_time: TravelTime) -> Cost {
+        let departure = self.get_effective_departure(scale, data_idx, travel_time);
+
+        // NOTE extra costs, such as tolls, are not interpolated
+        let matrix_idx = self.get_left_idx(departure).unwrap_or(0);
+        self.matrices[matrix_idx].costs.as_ref().map_or(0., |costs| costs.get(data_idx))
     }
 
-    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
-        self.interpolate_duration(&route.actor.vehicle.profile, from, to, travel_time)
+    fn get_effective_departure(&self, scale: f64, data_idx: usize, travel_time: TravelTime) -> Timestamp {
+        match travel_time {
+            TravelTime::Departure(departure) => self.get_departure(scale, data_idx, departure).0,
+            TravelTime::Arrival(arrival) => self.get_latest_departure(scale, data_idx, arrival),
+        }
     }
 
-    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
-        self.interpolate_distance(&route.actor.vehicle.profile, from, to, travel_time)
+    /// Returns effective departure time and travel duration, including waiting, for given departure time.
+    /// Effective departure is later than the given one when waiting for faster travel leads to earlier arrival.
+    fn get_departure(&self, scale: f64, data_idx: usize, departure: Timestamp) -> (Timestamp, Duration) {
+        let duration = self.get_value(departure, |matrix| matrix.durations.get(data_idx)) * scale;
+
+        if duration < 0. {
+            return (departure, duration);
+        }
+
+        let next_idx = self.get_left_idx(departure).map_or(0, |idx| idx + 1);
+
+        let mut best = (departure, duration);
+        for (&timestamp, matrix) in self.timestamps.iter().zip(self.matrices.iter()).skip(next_idx) {
+            let waiting = timestamp - departure;
+            if waiting >= best.1 {
+                break;
+            }
+
+            let duration = matrix.durations.get(data_idx) * scale;
+            if duration >= 0. && waiting + duration < best.1 {
+                best = (timestamp, waiting + duration);
+            }
+        }
+
+        best
+    }
+
+    /// Returns the latest departure time which allows to arrive not later than given arrival time.
+    fn get_latest_departure(&self, scale: f64, data_idx: usize, arrival: Timestamp) -> Timestamp {
+        let durations = |idx: usize| self.matrices[idx].durations.get(data_idx) * scale;
+
+        if (0..self.matrices.len()).any(|idx| durations(idx) < 0.) {
+            return arrival - self.get_value(arrival, |matrix| matrix.durations.get(data_idx)) * scale;
+        }
+
+        // NOTE arrival time as a function of departure time is piecewise linear (or constant for step)
+        // between timestamps, so the latest departure can be found analytically from the latest segment
+        let arrival_at = |idx: usize| self.timestamps[idx] + durations(idx);
+        let last_idx = self.timestamps.len() - 1;
+
+        if arrival_at(last_idx) <= arrival {
+            return arrival - durations(last_idx);
+        }
+
+        (0..last_idx)
+            .rev()
+            .find_map(|idx| {
+                let (left, right) = (arrival_at(idx), arrival_at(idx + 1));
+                let (start, end) = (self.timestamps[idx], self.timestamps[idx + 1]);
+
+                if left > arrival {
+                    return None;
+                }
+
+                Some(match self.interpolation {
+                    MatrixInterpolation::Step => (arrival - durations(idx)).min(end),
+                    MatrixInterpolation::Linear => start + (arrival - left) / (right - left) * (end - start),
+                })
+            })
+            .unwrap_or_else(|| arrival - durations(0))
+    }
+
+    /// Returns a value at given time using interpolation between adjacent matrices.
+    fn get_value<F>(&self, time: Timestamp, value_fn: F) -> f64
+    where
+        F: Fn(&StoredMatrixData) -> f64,
+    {
+        match self.get_left_idx(time) {
+            None => value_fn(self.matrices.first().unwrap()),
+            Some(idx) if idx + 1 == self.matrices.len() => value_fn(self.matrices.last().unwrap()),
+            Some(idx) => {
+                let left = value_fn(&self.matrices[idx]);
+
+                match self.interpolation {
+                    MatrixInterpolation::Step => left,
+                    MatrixInterpolation::Linear => {
+                        let right = value_fn(&self.matrices[idx + 1]);
+
+                        if left < 0. || right < 0. {
+                            left.min(right)
+                        } else {
+                            let ratio =
+                                (time - self.timestamps[idx]) / (self.timestamps[idx + 1] - self.timestamps[idx]);
+
+                            left + ratio * (right - left)
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns index of the latest matrix which timestamp is not after given time.
+    fn get_left_idx(&self, time: Timestamp) -> Option<usize> {
+        self.timestamps.partition_point(|&timestamp| timestamp <= time).checked_sub(1)
+    }
+}
+
+/// Returns normalized weights of matrices which are proportional to time periods they are used for.
+fn get_time_weights(timestamps: &[Timestamp], interpolation: MatrixInterpolation) -> Vec<f64> {
+    let size = timestamps.len();
+    let weights = (0..size)
+        .map(|idx| match interpolation {
+            MatrixInterpolation::Step => timestamps.get(idx + 1).map_or(0., |next| next - timestamps[idx]),
+            MatrixInterpolation::Linear => {
+                (timestamps[(idx + 1).min(size - 1)] - timestamps[idx.saturating_sub(1)]) / 2.
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let total = weights.iter().sum::<f64>();
+
+    if total > 0. {
+        weights.into_iter().map(|weight| weight / total).collect()
+    } else {
+        vec![1. / size as f64; size]
     }
 }
 
@@ -2,11 +2,11 @@
 #[path = "../../../tests/unit/models/problem/fleet_test.rs"]
 mod fleet_test;
 
-use crate::models::common::{Dimensions, Location, Profile, TimeInterval, TimeWindow};
+use crate::models::common::{Dimensions, Location, Profile, TimeInterval, TimeWindow, ValueDimension};
 use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering::Less;
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// Represents operating costs for driver and vehicle.
 #[derive(Clone, Debug)]
@@ -106,6 +106,56 @@ pub struct Actor {
 /// A grouping function for collection of actors.
 pub type ActorGroupKeyFn = Box<dyn Fn(&[Arc<Actor>]) -> Box<dyn Fn(&Arc<Actor>) -> usize + Send + Sync>>;
 
+/// A function which creates a new vehicle using the given one as a template. It is called when all
+/// actors of the template's group are in use, the second argument is a one-based index of the new
+/// vehicle within the group. Returns `None` if the fleet should not be expanded with such vehicle.
+pub type VehicleFactory = Arc<dyn Fn(&Vehicle, usize) -> Option<Vehicle> + Send + Sync>;
+
+/// Keeps actors created on demand by vehicle factory. An actor with some group and index is created
+/// only once and shared between all registries, so each registry allocates indices on its own while
+/// the same vehicle is never represented by two different actors.
+pub(crate) struct ActorPool {
+    templates: HashMap<usize, Arc<Actor>>,
+    sizes: HashMap<usize, usize>,
+    slots: HashMap<usize, Vec<OnceLock<Option<Arc<Actor>>>>>,
+    factory: VehicleFactory,
+}
+
+impl ActorPool {
+    /// Returns an actor with given one-based index within the group, it is created using one of
+    /// the group's actors as a template on first request.
+    pub fn acquire(&self, group: usize, index: usize) -> Option<Arc<Actor>> {
+        let slot = index.checked_sub(self.get_base_size(group) + 1)?;
+        let slot = self.slots.get(&group).and_then(|slots| slots.get(slot))?;
+
+        slot.get_or_init(|| {
+            let template = self.templates.get(&group)?;
+
+            let mut vehicle = self.factory.as_ref()(template.vehicle.as_ref(), index)?;
+            vehicle.dimens.set_value(POOL_KEY_DIMENSION, (group, index));
+
+            Some(Arc::new(Actor {
+                vehicle: Arc::new(vehicle),
+                driver: template.driver.clone(),
+                detail: template.detail.clone(),
+            }))
+        })
+        .clone()
+    }
+
+    /// Returns a group and an index of the actor created by the pool.
+    pub fn get_key(&self, actor: &Actor) -> Option<(usize, usize)> {
+        actor.vehicle.dimens.get_value::<(usize, usize)>(POOL_KEY_DIMENSION).copied()
+    }
+
+    /// Returns amount of the group's actors defined in the fleet.
+    pub fn get_base_size(&self, group: usize) -> usize {
+        self.sizes.get(&group).copied().unwrap_or(0)
+    }
+}
+
+const POOL_KEY_DIMENSION: &str = "pool_key";
+
 /// Represents available resources to serve jobs.
 pub struct Fleet {
     /// All fleet drivers.
@@ -122,6 +172,9 @@ pub struct Fleet {
 
     /// A grouped actors.
     pub groups: HashMap<usize, HashSet<Arc<Actor>>>,
+
+    /// A pool of actors created on demand.
+    pub(crate) pool: Option<Arc<ActorPool>>,
 }
 
 impl Fleet {
@@ -160,7 +213,34 @@ impl Fleet {
             acc
         });
 
-        Fleet { drivers, vehicles, profiles, actors, groups }
+        Fleet { drivers, vehicles, profiles, actors, groups, pool: None }
+    }
+
+    /// Sets a vehicle factory which is used to expand the fleet on demand: when all actors of
+    /// some group are in use, a new one is created from the group's actor. A group is not expanded
+    /// beyond `max_size` actors, e.g. amount of jobs as each used actor serves at least one job.
+    pub fn with_vehicle_factory(mut self, factory: VehicleFactory, max_size: usize) -> Self {
+        let templates: HashMap<_, _> = self
+            .groups
+            .iter()
+            .filter_map(|(group, actors)| actors.iter().next().map(|actor| (*group, actor.clone())))
+            .collect();
+        let sizes: HashMap<_, _> = self.groups.iter().map(|(group, actors)| (*group, actors.len())).collect();
+        // NOTE allocate slots only for groups which the factory can expand
+        let slots = templates
+            .iter()
+            .filter(|(group, template)| factory.as_ref()(template.vehicle.as_ref(), sizes[*group] + 1).is_some())
+            .map(|(group, _)| (*group, (sizes[group]..max_size).map(|_| OnceLock::new()).collect()))
+            .collect();
+
+        self.pool = Some(Arc::new(ActorPool { templates, sizes, slots, factory }));
+
+        self
+    }
+
+    /// Returns true if the fleet can be expanded on demand.
+    pub fn has_vehicle_factory(&self) -> bool {
+        self.pool.is_some()
     }
 }
 
@@ -6,8 +6,11 @@ use crate::models::common::*;
 use crate::models::problem::{Costs, Fleet, TransportCost};
 use hashbrown::HashMap;
 use rosomaxa::prelude::compare_floats;
+use rosomaxa::utils::parallel_collect;
+use std::cmp::Ordering;
 use std::cmp::Ordering::Less;
 use std::hash::{Hash, Hasher};
+use std::mem::size_of;
 use std::sync::{Arc, Weak};
 
 /// Represents a job variant.
@@ -181,16 +184,38 @@ impl Multi {
 
 type JobIndex = HashMap<Job, (Vec<(Job, Cost)>, HashMap<Job, Cost>, Cost)>;
 
+/// Default max amount of closest neighbors kept per job in the neighborhood index.
+pub const DEFAULT_MAX_NEIGHBORS: usize = 256;
+
 /// Stores all jobs taking into account their neighborhood.
 pub struct Jobs {
     jobs: Vec<Job>,
     index: HashMap<usize, JobIndex>,
+    avg_costs: HashMap<usize, Costs>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    max_neighbors: usize,
 }
 
 impl Jobs {
-    /// Creates a new [`Jobs`].
+    /// Creates a new [`Jobs`] keeping up to [`DEFAULT_MAX_NEIGHBORS`] closest neighbors per job.
     pub fn new(fleet: &Fleet, jobs: Vec<Job>, transport: &Arc<dyn TransportCost + Send + Sync>) -> Jobs {
-        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport) }
+        Self::new_with_max_neighbors(fleet, jobs, transport, DEFAULT_MAX_NEIGHBORS)
+    }
+
+    /// Creates a new [`Jobs`] keeping up to `max_neighbors` closest neighbors per job. Jobs are grouped
+    /// around pivot jobs and costs are evaluated only for jobs of clusters which can contain closest
+    /// neighbors, so neither memory nor time of index creation is quadratic to amount of jobs. Use
+    /// `usize::MAX` to keep all jobs as neighbors and evaluate costs between all of them.
+    pub fn new_with_max_neighbors(
+        fleet: &Fleet,
+        jobs: Vec<Job>,
+        transport: &Arc<dyn TransportCost + Send + Sync>,
+        max_neighbors: usize,
+    ) -> Jobs {
+        let avg_costs = get_avg_profile_costs(fleet);
+        let index = create_index(fleet, jobs.clone(), transport, &avg_costs, max_neighbors);
+
+        Jobs { jobs, index, avg_costs, transport: transport.clone(), max_neighbors }
     }
 
     /// Returns all jobs in original order.
@@ -204,9 +229,14 @@ impl Jobs {
         self.index.get(&profile.index).unwrap().get(job).unwrap().0.iter()
     }
 
-    /// Returns cost distance between two jobs.
+    /// Returns cost distance between two jobs. Costs between neighbors are taken from the index,
+    /// others are calculated on demand using transport costs.
     pub fn distance(&self, profile: &Profile, from: &Job, to: &Job, _: Timestamp) -> Cost {
-        *self.index.get(&profile.index).unwrap().get(from).unwrap().1.get(to).unwrap()
+        self.index.get(&profile.index).unwrap().get(from).unwrap().1.get(to).cloned().unwrap_or_else(|| {
+            let avg_costs = self.avg_costs.get(&profile.index).unwrap();
+
+            get_cost_between_jobs(profile, avg_costs, self.transport.as_ref(), from, to)
+        })
     }
 
     /// Returns job rank as relative cost from any vehicle's start position.
@@ -218,6 +248,25 @@ impl Jobs {
     pub fn size(&self) -> usize {
         self.jobs.len()
     }
+
+    /// Returns max amount of neighbors kept per job.
+    pub fn max_neighbors(&self) -> usize {
+        self.max_neighbors
+    }
+
+    /// Returns an approximate memory usage of the neighborhood index in bytes.
+    pub fn index_memory_usage(&self) -> usize {
+        self.index
+            .values()
+            .flat_map(|index| index.values())
+            .map(|(neighbors, costs, _)| {
+                size_of::<Job>()
+                    + size_of::<(Vec<(Job, Cost)>, HashMap<Job, Cost>, Cost)>()
+                    + neighbors.capacity() * size_of::<(Job, Cost)>()
+                    + costs.capacity() * size_of::<(Job, Cost)>()
+            })
+            .sum()
+    }
 }
 
 impl PartialEq<Job> for Job {
@@ -260,14 +309,14 @@ pub fn get_job_locations<'a>(job: &'a Job) -> Box<dyn Iterator<Item = Option<Loc
 const DEFAULT_COST: Cost = 0.;
 const UNREACHABLE_COST: Cost = f64::MAX;
 
-/// Creates job index.
+/// Creates job index which keeps up to `max_neighbors` closest neighbors per job.
 fn create_index(
     fleet: &Fleet,
     jobs: Vec<Job>,
     transport: &Arc<dyn TransportCost + Send + Sync>,
+    avg_profile_costs: &HashMap<usize, Costs>,
+    max_neighbors: usize,
 ) -> HashMap<usize, JobIndex> {
-    let avg_profile_costs = get_avg_profile_costs(fleet);
-
     fleet.profiles.iter().fold(HashMap::new(), |mut acc, profile| {
         let avg_costs = avg_profile_costs.get(&profile.index).unwrap();
         // get all possible start positions for given profile
@@ -279,33 +328,149 @@ fn create_index(
             .flatten()
             .collect();
 
+        let get_cost = |from: &Job, to: &Job| get_cost_between_jobs(profile, avg_costs, transport.as_ref(), from, to);
+        let clusters = create_pivot_clusters(jobs.as_slice(), max_neighbors, &get_cost);
+
         // create job index
-        let item = jobs.iter().cloned().fold(HashMap::new(), |mut acc, job| {
-            let mut sorted_job_costs: Vec<(Job, Cost)> = jobs
-                .iter()
-                .filter(|j| **j != job)
-                .map(|j| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport.as_ref(), &job, j)))
-                .collect();
-            sorted_job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
+        let item = parallel_collect(&jobs, |job| {
+            let job_costs = if let Some(clusters) = clusters.as_ref() {
+                get_cluster_neighbors(jobs.as_slice(), job, clusters, max_neighbors, &get_cost)
+            } else {
+                jobs.iter().enumerate().filter(|(_, j)| *j != job).map(|(idx, j)| (idx, get_cost(job, j))).collect()
+            };
+
+            let sorted_job_costs = get_closest_neighbors(job_costs, max_neighbors)
+                .into_iter()
+                .map(|(idx, cost)| (jobs[idx].clone(), cost))
+                .collect::<Vec<_>>();
+            let job_costs = sorted_job_costs.iter().cloned().collect::<HashMap<_, _>>();
 
             let fleet_costs = starts
                 .iter()
                 .cloned()
-                .map(|s| get_cost_between_job_and_location(profile, avg_costs, transport.as_ref(), &job, s))
+                .map(|s| get_cost_between_job_and_location(profile, avg_costs, transport.as_ref(), job, s))
                 .min_by(|a, b| a.partial_cmp(b).unwrap_or(Less))
                 .unwrap_or(DEFAULT_COST);
 
-            let job_costs_map = sorted_job_costs.iter().cloned().collect::<HashMap<_, _>>();
-
-            acc.insert(job, (sorted_job_costs, job_costs_map, fleet_costs));
-            acc
-        });
+            (job.clone(), (sorted_job_costs, job_costs, fleet_costs))
+        })
+        .into_iter()
+        .collect::<HashMap<_, _>>();
 
         acc.insert(profile.index, item);
         acc
     })
 }
 
+type JobCostFn<'a> = dyn Fn(&Job, &Job) -> Cost + Sync + 'a;
+
+/// Keeps jobs grouped around pivot jobs.
+struct PivotClusters {
+    pivots: Vec<usize>,
+    members: Vec<Vec<usize>>,
+    radiuses: Vec<Cost>,
+}
+
+/// Groups jobs around square root of jobs amount pivots, so the closest neighbors are searched only within
+/// clusters which can contain them. Returns `None` if all jobs are kept as neighbors.
+fn create_pivot_clusters(jobs: &[Job], max_neighbors: usize, get_cost: &JobCostFn) -> Option<PivotClusters> {
+    if max_neighbors >= jobs.len().saturating_sub(1) {
+        return None;
+    }
+
+    let size = (jobs.len() as f64).sqrt().ceil() as usize;
+    let pivots = (0..size).map(|idx| idx * jobs.len() / size).collect::<Vec<_>>();
+
+    let assignments = parallel_collect(jobs, |job| {
+        pivots
+            .iter()
+            .enumerate()
+            .map(|(cluster, pivot)| (cluster, get_cost(job, &jobs[*pivot])))
+            .min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+            .unwrap()
+    });
+
+    let (members, radiuses) = assignments.into_iter().enumerate().fold(
+        (vec![Vec::new(); size], vec![0.; size]),
+        |(mut members, mut radiuses), (idx, (cluster, cost))| {
+            members[cluster].push(idx);
+            radiuses[cluster] = cost.max(radiuses[cluster]);
+
+            (members, radiuses)
+        },
+    );
+
+    Some(PivotClusters { pivots, members, radiuses })
+}
+
+/// Returns costs to neighbor candidates of the job visiting clusters starting from the nearest one. If costs
+/// satisfy triangle inequality, cost to any cluster member is not less than cost to its pivot minus cluster
+/// radius, so clusters which cannot contain closer jobs than already found ones are skipped.
+fn get_cluster_neighbors(
+    jobs: &[Job],
+    job: &Job,
+    clusters: &PivotClusters,
+    max_neighbors: usize,
+    get_cost: &JobCostFn,
+) -> Vec<(usize, Cost)> {
+    let mut pivot_costs = clusters
+        .pivots
+        .iter()
+        .enumerate()
+        .map(|(cluster, pivot)| (cluster, get_cost(job, &jobs[*pivot])))
+        .collect::<Vec<_>>();
+    pivot_costs.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+    let (job_costs, _) =
+        pivot_costs.into_iter().fold((Vec::new(), UNREACHABLE_COST), |(mut job_costs, threshold), (cluster, cost)| {
+            if cost - clusters.radiuses[cluster] > threshold {
+                return (job_costs, threshold);
+            }
+
+            job_costs.extend(
+                clusters.members[cluster]
+                    .iter()
+                    .filter(|idx| jobs[**idx] != *job)
+                    .map(|idx| (*idx, get_cost(job, &jobs[*idx]))),
+            );
+
+            // NOTE keep amount of candidates bounded, the farthest kept one limits costs of remaining ones
+            if job_costs.len() > 2 * max_neighbors {
+                let job_costs = get_closest_neighbors(job_costs, max_neighbors);
+                let threshold = job_costs.last().map_or(threshold, |(_, cost)| *cost);
+
+                (job_costs, threshold)
+            } else {
+                (job_costs, threshold)
+            }
+        });
+
+    job_costs
+}
+
+/// Returns up to `max_neighbors` closest neighbors sorted by cost. Ties are resolved by original
+/// job order to keep the index deterministic.
+fn get_closest_neighbors(mut job_costs: Vec<(usize, Cost)>, max_neighbors: usize) -> Vec<(usize, Cost)> {
+    let compare = |(a_idx, a_cost): &(usize, Cost), (b_idx, b_cost): &(usize, Cost)| match a_cost.partial_cmp(b_cost) {
+        Some(Ordering::Equal) | None => a_idx.cmp(b_idx),
+        Some(ordering) => ordering,
+    };
+
+    if max_neighbors == 0 {
+        return vec![];
+    }
+
+    if job_costs.len() > max_neighbors {
+        job_costs.select_nth_unstable_by(max_neighbors - 1, compare);
+        job_costs.truncate(max_neighbors);
+        job_costs.shrink_to_fit();
+    }
+
+    job_costs.sort_by(compare);
+
+    job_costs
+}
+
 fn get_cost_between_locations(
     profile: &Profile,
     costs: &Costs,
@@ -2,16 +2,21 @@
 #[path = "../../../tests/unit/models/solution/actor_test.rs"]
 mod actor_test;
 
-use crate::models::problem::{Actor, Fleet};
+use crate::models::problem::{Actor, ActorPool, Fleet};
 use hashbrown::{HashMap, HashSet};
 use rosomaxa::prelude::Random;
 use std::sync::Arc;
 
 /// Specifies an entity responsible for providing actors and keeping track of their usage.
+/// When fleet has a vehicle factory, the registry gets a new actor with the lowest free index each
+/// time when all actors of some group are in use and drops spare ones when they are freed.
 pub struct Registry {
     available: HashMap<usize, HashSet<Arc<Actor>>>,
     index: HashMap<Arc<Actor>, usize>,
     all: Vec<Arc<Actor>>,
+    added: Vec<Arc<Actor>>,
+    removed: Vec<Arc<Actor>>,
+    pool: Option<Arc<ActorPool>>,
     random: Arc<dyn Random + Send + Sync>,
 }
 
@@ -24,19 +29,41 @@ impl Registry {
             .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
             .collect();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        Self {
+            available: fleet.groups.clone(),
+            index,
+            all: fleet.actors.to_vec(),
+            added: vec![],
+            removed: vec![],
+            pool: fleet.pool.clone(),
+            random,
+        }
     }
 
     /// Removes an actor from the list of available actors.
     /// Returns whether the actor was present in the registry.
     pub fn use_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).unwrap()).unwrap().remove(actor)
+        let group = self.get_group(actor);
+        let available = self.available.get_mut(&group).unwrap();
+        let is_removed = available.remove(actor);
+
+        if is_removed && available.is_empty() {
+            self.expand(group);
+        }
+
+        is_removed
     }
 
     /// Adds actor to the list of available actors.
     /// Returns whether the actor was not present in the registry.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.available.get_mut(self.index.get(actor).unwrap()).unwrap().insert(actor.clone())
+        let is_known = self.index.contains_key(actor);
+        let group = self.get_group(actor);
+        let is_added = self.available.get_mut(&group).unwrap().insert(actor.clone()) || !is_known;
+
+        self.shrink(group);
+
+        is_added
     }
 
     /// Returns all actors.
@@ -64,6 +91,9 @@ impl Registry {
             available: self.available.clone(),
             index: self.index.clone(),
             all: self.all.clone(),
+            added: self.added.clone(),
+            removed: self.removed.clone(),
+            pool: self.pool.clone(),
             random: self.random.clone(),
         }
     }
@@ -86,7 +116,89 @@ impl Registry {
                 .map(|(actor, idx)| (actor.clone(), *idx))
                 .collect(),
             all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            added: self.added.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            removed: self.removed.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            pool: self.pool.clone(),
             random: self.random.clone(),
         }
     }
+
+    /// Returns actors added to and removed from the registry since the last call.
+    pub(crate) fn take_changes(&mut self) -> (Vec<Arc<Actor>>, Vec<Arc<Actor>>) {
+        (std::mem::take(&mut self.added), std::mem::take(&mut self.removed))
+    }
+
+    /// Returns actor's group registering the actor if it was created by the pool for another registry.
+    fn get_group(&mut self, actor: &Arc<Actor>) -> usize {
+        if let Some(group) = self.index.get(actor) {
+            return *group;
+        }
+
+        let (group, _) = self
+            .pool
+            .as_ref()
+            .and_then(|pool| pool.get_key(actor.as_ref()))
+            .expect("actor is not registered within the fleet");
+
+        self.register(actor.clone(), group);
+
+        group
+    }
+
+    /// Adds a new actor with the lowest index which is not used within the registry when fleet can be expanded.
+    fn expand(&mut self, group: usize) {
+        let pool = if let Some(pool) = self.pool.clone() { pool } else { return };
+
+        let indices = self.get_pool_actors(pool.as_ref(), group).map(|(index, _)| index).collect::<HashSet<_>>();
+        let index = (pool.get_base_size(group) + 1..).find(|index| !indices.contains(index)).unwrap();
+
+        if let Some(actor) = pool.acquire(group, index) {
+            self.register(actor, group);
+        }
+    }
+
+    /// Removes spare actors created by the pool keeping the lowest indices, so only one actor of the group stays available.
+    fn shrink(&mut self, group: usize) {
+        let pool = if let Some(pool) = self.pool.clone() { pool } else { return };
+
+        while self.available.get(&group).map_or(false, |available| available.len() > 1) {
+            let spare = self
+                .get_pool_actors(pool.as_ref(), group)
+                .filter(|(_, actor)| self.available[&group].contains(*actor))
+                .max_by_key(|(index, _)| *index)
+                .map(|(_, actor)| actor.clone());
+
+            if let Some(actor) = spare {
+                self.unregister(&actor, group);
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn get_pool_actors<'a>(
+        &'a self,
+        pool: &'a ActorPool,
+        group: usize,
+    ) -> impl Iterator<Item = (usize, &'a Arc<Actor>)> + 'a {
+        self.index
+            .iter()
+            .filter(move |(_, actor_group)| **actor_group == group)
+            .filter_map(move |(actor, _)| pool.get_key(actor.as_ref()).map(|(_, index)| (index, actor)))
+    }
+
+    fn register(&mut self, actor: Arc<Actor>, group: usize) {
+        self.available.entry(group).or_default().insert(actor.clone());
+        self.index.insert(actor.clone(), group);
+        self.all.push(actor.clone());
+        self.added.push(actor);
+    }
+
+    fn unregister(&mut self, actor: &Arc<Actor>, group: usize) {
+        self.available.get_mut(&group).unwrap().remove(actor);
+        self.index.remove(actor);
+        self.all.retain(|a| a != actor);
+        self.added.retain(|a| a != actor);
+        self.removed.push(actor.clone());
+    }
 }
@@ -294,9 +294,10 @@ impl Solver {
     /// or error description, if solution cannot be found.
     pub fn solve(self) -> Result<(Solution, Cost, Option<TelemetryMetrics>), String> {
         self.config.context.environment.logger.deref()(&format!(
-            "total jobs: {}, actors: {}",
+            "total jobs: {}, actors: {}, job index memory: ~{} KB",
             self.problem.jobs.size(),
-            self.problem.fleet.actors.len()
+            self.problem.fleet.actors.len(),
+            self.problem.jobs.index_memory_usage() / 1024
         ));
 
         let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
@@ -1,6 +1,6 @@
 //! The objective module specifies various objective functions for solving Vehicle Routing Problem.
 
-use crate::construction::heuristics::InsertionContext;
+use crate::construction::heuristics::{InsertionContext, RouteContext};
 use std::cmp::Ordering;
 
 mod generic_value;
@@ -9,6 +9,9 @@ pub use self::generic_value::*;
 mod minimize_arrival_time;
 pub use self::minimize_arrival_time::*;
 
+mod plan_changes;
+pub use self::plan_changes::*;
+
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
 
@@ -16,7 +19,7 @@ mod total_transport;
 pub use self::total_transport::*;
 
 mod total_unassigned_jobs;
-pub use self::total_unassigned_jobs::TotalUnassignedJobs;
+pub use self::total_unassigned_jobs::{JobPriorityFn, PrioritizedUnassignedJobs, TotalUnassignedJobs};
 
 mod total_value;
 pub use self::total_value::*;
@@ -26,3 +29,18 @@ pub use self::tour_order::*;
 
 mod work_balance;
 pub use self::work_balance::WorkBalance;
+
+/// A penalty used when route cost cannot be used as a penalty measure (e.g. route is empty).
+pub const DEFAULT_ROUTE_PENALTY: f64 = 1E6;
+
+/// Returns a penalty for a soft violation within the route: a route cost, if it is positive,
+/// otherwise `DEFAULT_ROUTE_PENALTY`.
+pub fn get_route_penalty(route_ctx: &RouteContext) -> f64 {
+    let route_cost = route_ctx.get_route_cost();
+
+    if route_cost > 0. {
+        route_cost
+    } else {
+        DEFAULT_ROUTE_PENALTY
+    }
+}
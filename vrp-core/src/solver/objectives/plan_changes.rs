@@ -0,0 +1,128 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/plan_changes_test.rs"]
+mod plan_changes_test;
+
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::problem::{Actor, Job, TargetConstraint, TargetObjective};
+use crate::solver::objectives::{get_route_penalty, GenericValue};
+use hashbrown::HashSet;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Specifies a function which returns reference of the job in the reference plan: an index of
+/// the reference tour and job position in it.
+pub type JobReferenceFn = Arc<dyn Fn(&Job) -> Option<(usize, usize)> + Send + Sync>;
+
+/// Specifies a function which returns an index of the reference tour served by the given actor.
+pub type ActorReferenceFn = Arc<dyn Fn(&Actor) -> Option<usize> + Send + Sync>;
+
+/// Specifies how job assignment relates to the reference plan.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PlanReference {
+    /// Job is served by the same actor as in the reference plan: keeps job position in the reference tour.
+    Same(usize),
+    /// Job is served by another actor.
+    Moved,
+    /// Job is not present in the reference plan.
+    Unknown,
+}
+
+/// A type which provides functionality needed to minimize changes compared to the reference plan:
+/// jobs moved to another actor are counted and, optionally, changes in job sequence within the same
+/// actor's tour.
+pub struct PlanChanges {}
+
+impl PlanChanges {
+    /// Creates _(constraint, objective)_  type pair which provides logic to minimize plan changes.
+    pub fn minimize(
+        job_reference_fn: JobReferenceFn,
+        actor_reference_fn: ActorReferenceFn,
+        is_sequence_aware: bool,
+        state_key: i32,
+        merge_code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        let get_route_value = {
+            let job_reference_fn = job_reference_fn.clone();
+            let actor_reference_fn = actor_reference_fn.clone();
+            Arc::new(move |route_ctx: &RouteContext| {
+                get_route_changes(route_ctx, &job_reference_fn, &actor_reference_fn, is_sequence_aware)
+            })
+        };
+
+        GenericValue::new_constrained_objective(
+            None,
+            Arc::new({
+                let job_reference_fn = job_reference_fn.clone();
+                move |source, candidate| {
+                    // NOTE jobs from different reference tours cannot be merged
+                    let get_tour = |job: &Job| job_reference_fn.deref()(job).map(|(tour, _)| tour);
+
+                    if get_tour(&source) == get_tour(&candidate) {
+                        Ok(source)
+                    } else {
+                        Err(merge_code)
+                    }
+                }
+            }),
+            get_route_value.clone(),
+            Arc::new(move |ctx: &SolutionContext| ctx.routes.iter().map(|route_ctx| get_route_value(route_ctx)).sum()),
+            Arc::new(move |_, route_ctx, job, _| {
+                match get_plan_reference(route_ctx.route.actor.as_ref(), job, &job_reference_fn, &actor_reference_fn) {
+                    PlanReference::Moved => get_route_penalty(route_ctx),
+                    _ => 0.,
+                }
+            }),
+            state_key,
+        )
+    }
+}
+
+fn get_plan_reference(
+    actor: &Actor,
+    job: &Job,
+    job_reference_fn: &JobReferenceFn,
+    actor_reference_fn: &ActorReferenceFn,
+) -> PlanReference {
+    match (job_reference_fn.deref()(job), actor_reference_fn.deref()(actor)) {
+        (Some((job_tour, position)), Some(actor_tour)) if job_tour == actor_tour => PlanReference::Same(position),
+        (Some(_), _) => PlanReference::Moved,
+        _ => PlanReference::Unknown,
+    }
+}
+
+fn get_route_changes(
+    route_ctx: &RouteContext,
+    job_reference_fn: &JobReferenceFn,
+    actor_reference_fn: &ActorReferenceFn,
+    is_sequence_aware: bool,
+) -> f64 {
+    let actor = route_ctx.route.actor.as_ref();
+    let mut visited = HashSet::new();
+
+    let references = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .filter(|job| visited.insert(job.clone()))
+        .map(|job| get_plan_reference(actor, &job, job_reference_fn, actor_reference_fn))
+        .collect::<Vec<_>>();
+
+    let moved = references.iter().filter(|reference| matches!(reference, PlanReference::Moved)).count();
+
+    let reordered = if is_sequence_aware {
+        let positions = references
+            .iter()
+            .filter_map(|reference| match reference {
+                PlanReference::Same(position) => Some(*position),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        positions.windows(2).filter(|pair| pair[0] > pair[1]).count()
+    } else {
+        0
+    };
+
+    (moved + reordered) as f64
+}
@@ -3,7 +3,7 @@
 mod total_transport_test;
 
 use super::*;
-use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY, WAITING_KEY};
 use crate::models::common::Cost;
 use crate::models::problem::TargetObjective;
 use rosomaxa::prelude::*;
@@ -40,6 +40,29 @@ impl TotalDuration {
     }
 }
 
+/// An objective function for total waiting time minimization as a target.
+pub struct TotalWaiting;
+
+impl TotalWaiting {
+    /// Creates an objective to minimize total waiting time.
+    pub fn minimize() -> TargetObjective {
+        Arc::new(TotalTransport {
+            fitness: Arc::new(|insertion_ctx| {
+                // NOTE waiting state of the first job activity keeps waiting time accumulated till the tour end
+                insertion_ctx
+                    .solution
+                    .routes
+                    .iter()
+                    .filter_map(|route_ctx| route_ctx.route.tour.get(1).map(|activity| (route_ctx, activity)))
+                    .map(|(route_ctx, activity)| {
+                        route_ctx.state.get_activity_state::<f64>(WAITING_KEY, activity).cloned().unwrap_or(0.)
+                    })
+                    .sum()
+            }),
+        })
+    }
+}
+
 struct TotalTransport {
     fitness: Arc<dyn Fn(&InsertionContext) -> f64 + Send + Sync>,
 }
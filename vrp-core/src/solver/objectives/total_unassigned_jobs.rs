@@ -3,12 +3,17 @@
 mod total_unassigned_jobs_test;
 
 use super::*;
-use crate::construction::heuristics::UnassignmentInfo;
-use crate::models::problem::Job;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext, UnassignmentInfo};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective};
 use rosomaxa::prelude::*;
 use std::ops::Deref;
+use std::slice::Iter;
 use std::sync::Arc;
 
+/// A penalty per priority tier used to prefer higher priority jobs on insertion.
+const PRIORITY_TIER_PENALTY: f64 = DEFAULT_ROUTE_PENALTY;
+
 /// A type which allows to control how job is estimated in objective fitness
 pub type UnassignedJobEstimator = Arc<dyn Fn(&InsertionContext, &Job, &UnassignmentInfo) -> f64 + Send + Sync>;
 
@@ -69,3 +74,125 @@ impl Objective for TotalUnassignedJobs {
             .sum::<f64>()
     }
 }
+
+/// A function which returns priority of the job: lower value means higher priority.
+pub type JobPriorityFn = Arc<dyn Fn(&Job) -> usize + Send + Sync>;
+
+/// An objective function which minimizes amount of unassigned jobs per priority tier in lexicographic
+/// order: a solution with less unassigned jobs of higher priority is always considered as better one,
+/// regardless of unassigned jobs of lower priorities.
+pub struct PrioritizedUnassignedJobs {
+    tiers: Vec<TotalUnassignedJobs>,
+}
+
+impl PrioritizedUnassignedJobs {
+    /// Creates a new instance of `PrioritizedUnassignedJobs` with a tier per each of given priorities.
+    pub fn new(
+        priorities: &[usize],
+        priority_fn: JobPriorityFn,
+        unassigned_job_estimator: UnassignedJobEstimator,
+    ) -> Self {
+        let mut priorities = priorities.to_vec();
+        priorities.sort_unstable();
+        priorities.dedup();
+
+        let tiers = priorities
+            .into_iter()
+            .map(|priority| {
+                let priority_fn = priority_fn.clone();
+                let unassigned_job_estimator = unassigned_job_estimator.clone();
+
+                TotalUnassignedJobs::new(Arc::new(move |insertion_ctx, job, info| {
+                    if priority_fn.deref()(job) == priority {
+                        unassigned_job_estimator.deref()(insertion_ctx, job, info)
+                    } else {
+                        0.
+                    }
+                }))
+            })
+            .collect();
+
+        Self { tiers }
+    }
+
+    /// Creates _(constraint, objective)_ type pair: the constraint guides insertion heuristics to
+    /// prefer jobs of higher priority, so they are not blocked by cheaper jobs of lower priorities.
+    pub fn new_constrained(
+        priorities: &[usize],
+        priority_fn: JobPriorityFn,
+        unassigned_job_estimator: UnassignedJobEstimator,
+    ) -> (TargetConstraint, TargetObjective) {
+        let objective = Self::new(priorities, priority_fn.clone(), unassigned_job_estimator);
+
+        let mut priorities = priorities.to_vec();
+        priorities.sort_unstable();
+        priorities.dedup();
+
+        let constraint = PriorityModule {
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(PrioritySoftRouteConstraint {
+                priorities,
+                priority_fn,
+            }))],
+            keys: vec![],
+        };
+
+        (Arc::new(constraint), Arc::new(objective))
+    }
+}
+
+impl Objective for PrioritizedUnassignedJobs {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        self.tiers
+            .iter()
+            .map(|tier| tier.total_order(a, b))
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        // NOTE lexicographic order cannot be expressed by a single value, so total estimate is returned
+        self.tiers.iter().map(|tier| tier.fitness(solution)).sum()
+    }
+}
+
+struct PriorityModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for PriorityModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PrioritySoftRouteConstraint {
+    priorities: Vec<usize>,
+    priority_fn: JobPriorityFn,
+}
+
+impl SoftRouteConstraint for PrioritySoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, _: &RouteContext, job: &Job) -> f64 {
+        // NOTE penalty is the same for any route, so it affects only the order in which jobs are inserted
+        let priority = self.priority_fn.deref()(job);
+        let tier = self.priorities.iter().position(|p| *p == priority).unwrap_or(0);
+
+        tier as f64 * PRIORITY_TIER_PENALTY
+    }
+}
@@ -3,9 +3,11 @@
 mod total_value_test;
 
 use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::models::common::Timestamp;
 use crate::models::problem::{Actor, Job, TargetConstraint, TargetObjective};
 use crate::solver::objectives::{GenericValue, SolutionValueFn};
 use crate::utils::Either;
+use hashbrown::HashMap;
 use rosomaxa::prelude::*;
 use std::cmp::Ordering;
 use std::ops::Deref;
@@ -19,6 +21,19 @@ pub type SimpleValueFn = Arc<dyn Fn(&Job) -> f64 + Send + Sync>;
 pub type ValueFn = Either<SimpleValueFn, ActorValueFn>;
 /// Specifies a job write value.
 pub type JobWriteValueFn = Arc<dyn Fn(Job, f64) -> Job + Send + Sync>;
+/// Specifies a job value function which takes into account service time of the job.
+pub type ScheduleValueFn = Arc<dyn Fn(&Job, Timestamp) -> f64 + Send + Sync>;
+/// Specifies a function which checks whether job value depends on its service time.
+pub type ScheduledJobFn = Arc<dyn Fn(&Job) -> bool + Send + Sync>;
+
+/// Specifies time dependent job value.
+#[derive(Clone)]
+pub struct ScheduleValue {
+    /// Checks whether job value depends on service time.
+    pub is_scheduled_func: ScheduledJobFn,
+    /// Returns job value for given service time.
+    pub value_func: ScheduleValueFn,
+}
 
 /// A type which provides functionality needed to maximize total value of served jobs.
 pub struct TotalValue {}
@@ -33,20 +48,57 @@ impl TotalValue {
         job_write_value_func: JobWriteValueFn,
         state_key: i32,
         merge_code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        Self::maximize_with_schedule(
+            max_value,
+            reduction_factor,
+            solution_value_func,
+            job_read_value_func,
+            None,
+            job_write_value_func,
+            state_key,
+            merge_code,
+        )
+    }
+
+    /// Creates _(constraint, objective)_  type pair which provides logic to maximize total value where
+    /// value of some jobs depends on their service time. Such value is evaluated on the last job's
+    /// activity schedule, insertion is estimated using job value returned by `job_read_value_func`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn maximize_with_schedule(
+        max_value: f64,
+        reduction_factor: f64,
+        solution_value_func: SolutionValueFn,
+        job_read_value_func: ValueFn,
+        schedule_value: Option<ScheduleValue>,
+        job_write_value_func: JobWriteValueFn,
+        state_key: i32,
+        merge_code: i32,
     ) -> (TargetConstraint, TargetObjective) {
         assert!(max_value > 0.);
 
         let get_route_value = {
             let value_func = job_read_value_func.clone();
+            let schedule_value = schedule_value.clone();
             Arc::new(move |rc: &RouteContext| {
+                let service_times = schedule_value.as_ref().map(|_| get_service_times(rc)).unwrap_or_default();
+
                 rc.route
                     .tour
                     .jobs()
                     .map(|job| {
-                        -1. * match &value_func {
+                        let scheduled_value = schedule_value
+                            .as_ref()
+                            .filter(|schedule_value| schedule_value.is_scheduled_func.deref()(&job))
+                            .zip(service_times.get(&job))
+                            .map(|(schedule_value, service_time)| {
+                                schedule_value.value_func.deref()(&job, *service_time)
+                            });
+
+                        -1. * scheduled_value.unwrap_or_else(|| match &value_func {
                             ValueFn::Left(left) => left.deref()(&job),
                             ValueFn::Right(right) => right.deref()(rc.route.actor.as_ref(), &job),
-                        }
+                        })
                     })
                     .sum()
             })
@@ -56,19 +108,31 @@ impl TotalValue {
             None,
             Arc::new({
                 let job_read_value_func = job_read_value_func.clone();
-                move |source, candidate| match &job_read_value_func {
-                    ValueFn::Left(left) => {
-                        let source_value = left.deref()(&source);
-                        let candidate_value = left.deref()(&candidate);
-                        let new_value = source_value + candidate_value;
+                let is_scheduled = move |job: &Job| {
+                    schedule_value
+                        .as_ref()
+                        .map_or(false, |schedule_value| schedule_value.is_scheduled_func.deref()(job))
+                };
+                move |source, candidate| {
+                    // NOTE time dependent values cannot be merged
+                    if is_scheduled(&source) || is_scheduled(&candidate) {
+                        return Err(merge_code);
+                    }
 
-                        Ok(if compare_floats(new_value, source_value) != Ordering::Equal {
-                            job_write_value_func.deref()(source, new_value)
-                        } else {
-                            source
-                        })
+                    match &job_read_value_func {
+                        ValueFn::Left(left) => {
+                            let source_value = left.deref()(&source);
+                            let candidate_value = left.deref()(&candidate);
+                            let new_value = source_value + candidate_value;
+
+                            Ok(if compare_floats(new_value, source_value) != Ordering::Equal {
+                                job_write_value_func.deref()(source, new_value)
+                            } else {
+                                source
+                            })
+                        }
+                        ValueFn::Right(_) => Err(merge_code),
                     }
-                    ValueFn::Right(_) => Err(merge_code),
                 }
             }),
             get_route_value.clone(),
@@ -94,3 +158,15 @@ impl TotalValue {
         )
     }
 }
+
+/// Returns service start time of the last activity per each job in the route.
+fn get_service_times(route_ctx: &RouteContext) -> HashMap<Job, Timestamp> {
+    route_ctx
+        .route
+        .tour
+        .all_activities()
+        .filter_map(|activity| {
+            activity.retrieve_job().map(|job| (job, activity.schedule.arrival.max(activity.place.time.start)))
+        })
+        .collect()
+}
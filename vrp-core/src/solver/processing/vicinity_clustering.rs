@@ -72,7 +72,12 @@ impl HeuristicContextProcessing for VicinityClustering {
 
             let problem = Arc::new(Problem {
                 fleet: problem.fleet.clone(),
-                jobs: Arc::new(Jobs::new(problem.fleet.as_ref(), jobs, &problem.transport)),
+                jobs: Arc::new(Jobs::new_with_max_neighbors(
+                    problem.fleet.as_ref(),
+                    jobs,
+                    &problem.transport,
+                    problem.jobs.max_neighbors(),
+                )),
                 locks: problem.locks.clone(),
                 constraint: problem.constraint.clone(),
                 activity: problem.activity.clone(),
@@ -12,6 +12,14 @@ pub fn can_calculate_distance_between_points() {
     assert_eq!(round(a.distance_to_point(&b)), 7.81);
 }
 
+#[test]
+pub fn can_calculate_manhattan_distance_between_points() {
+    let a = Point::new(3., 2.);
+    let b = Point::new(9., -7.);
+
+    assert_eq!(a.manhattan_distance_to_point(&b), 15.);
+}
+
 #[test]
 pub fn can_calculate_distance_to_line() {
     let a = Point::new(0., 2.);
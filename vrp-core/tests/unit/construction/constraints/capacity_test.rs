@@ -4,7 +4,7 @@ use crate::helpers::construction::constraints::*;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
-use crate::models::common::{Demand, DemandDimension, SingleDimLoad};
+use crate::models::common::{Demand, DemandDimension, InitialLoadDimension, SingleDimLoad};
 use crate::models::problem::{Job, Vehicle};
 use crate::models::solution::Activity;
 use std::sync::Arc;
@@ -85,6 +85,64 @@ fn can_evaluate_demand_on_route_impl(size: i32, expected: Option<RouteConstraint
     assert_eq!(result, expected);
 }
 
+fn create_test_vehicle_with_initial_load(capacity: i32, initial_load: i32) -> Vehicle {
+    let mut vehicle = create_test_vehicle(capacity);
+    vehicle.dimens.set_initial_load(SingleDimLoad::new(initial_load));
+
+    vehicle
+}
+
+#[test]
+fn can_calculate_capacity_state_values_with_initial_load() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_test_vehicle_with_initial_load(10, 5))
+        .build();
+    let mut ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_job(test_single_with_simple_demand(create_simple_dynamic_demand(-3))),
+            test_activity_with_job(test_single_with_simple_demand(create_simple_demand(-2))),
+            test_activity_with_job(test_single_with_simple_demand(create_simple_demand(4))),
+        ],
+    );
+
+    create_constraint_pipeline_with_simple_capacity().accept_route_state(&mut ctx);
+
+    let tour = &ctx.route.tour;
+    let state = &ctx.state;
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.start()), 7);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.get(1)), 4);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.get(2)), 2);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.get(3)), 6);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.end()), 6);
+}
+
+parameterized_test! {can_evaluate_demand_on_route_with_initial_load, (size, expected), {
+    can_evaluate_demand_on_route_with_initial_load_impl(size, expected);
+}}
+
+can_evaluate_demand_on_route_with_initial_load! {
+    case01: (-5, Some(RouteConstraintViolation { code: 2})),
+    case02: (-4, None),
+    case03: (5, Some(RouteConstraintViolation { code: 2})),
+}
+
+fn can_evaluate_demand_on_route_with_initial_load_impl(size: i32, expected: Option<RouteConstraintViolation>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_test_vehicle_with_initial_load(10, 6))
+        .build();
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let job = Job::Single(test_single_with_simple_demand(create_simple_demand(size)));
+
+    let result = create_constraint_pipeline_with_simple_capacity().evaluate_hard_route(&solution_ctx, &route_ctx, &job);
+
+    assert_eq!(result, expected);
+}
+
 parameterized_test! {can_evaluate_demand_on_activity, (sizes, neighbours, size, expected), {
     can_evaluate_demand_on_activity_impl(sizes, neighbours, size, expected);
 }}
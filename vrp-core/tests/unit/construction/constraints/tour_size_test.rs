@@ -1,6 +1,7 @@
-use crate::construction::constraints::{RouteConstraintViolation, TourSizeModule};
+use crate::construction::constraints::{ConstraintModule, RouteConstraintViolation, TourSizeModule};
+use crate::construction::heuristics::{SolutionContext, UnassignmentInfo};
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
-use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::domain::{create_empty_solution_context, create_registry_context};
 use crate::helpers::models::problem::{test_fleet, test_multi_job_with_locations, test_single_with_id};
 use crate::helpers::models::solution::{create_route_context_with_activities, test_activity_with_location};
 use crate::models::common::Location;
@@ -47,3 +48,42 @@ fn can_limit_by_job_activities_impl(
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_dissolve_tour_below_min_limit, (activities, required, min_limit, expected_unassigned), {
+    can_dissolve_tour_below_min_limit_impl(activities, required, min_limit, expected_unassigned);
+}}
+
+can_dissolve_tour_below_min_limit! {
+    case01_below_min: (2, 0, Some(3), 2),
+    case02_min_reached: (3, 0, Some(3), 0),
+    case03_no_min: (1, 0, None, 0),
+    case04_incomplete_solution: (2, 1, Some(3), 0),
+}
+
+fn can_dissolve_tour_below_min_limit_impl(
+    activities: usize,
+    required: usize,
+    min_limit: Option<usize>,
+    expected_unassigned: usize,
+) {
+    let fleet = test_fleet();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        (0..activities).map(|idx| test_activity_with_location(idx as Location)).collect(),
+    );
+    let mut solution_ctx = SolutionContext {
+        required: (0..required).map(|idx| Job::Single(test_single_with_id(format!("job{}", idx).as_str()))).collect(),
+        routes: vec![route_ctx],
+        registry: create_registry_context(&fleet),
+        ..create_empty_solution_context()
+    };
+    let module = TourSizeModule::new_with_min_limits(Arc::new(|_| None), Arc::new(move |_| min_limit), 1, 2);
+
+    module.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.unassigned.len(), expected_unassigned);
+    assert!(solution_ctx.unassigned.values().all(|info| matches!(info, UnassignmentInfo::Simple(2))));
+    assert_eq!(solution_ctx.routes.is_empty(), expected_unassigned > 0);
+    assert_eq!(solution_ctx.required.len(), required);
+}
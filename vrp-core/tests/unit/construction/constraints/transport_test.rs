@@ -119,6 +119,51 @@ mod timing {
         assert_eq!(result, expected);
     }
 
+    parameterized_test! {can_apply_profile_slack, (slack, expected), {
+        can_apply_profile_slack_impl(slack, expected);
+    }}
+
+    can_apply_profile_slack! {
+        case01: (0., (20., None)),
+        case02: (10., (10., Some(ActivityConstraintViolation { code: 1, stopped: false }))),
+    }
+
+    fn can_apply_profile_slack_impl(slack: Duration, expected: (Timestamp, Option<ActivityConstraintViolation>)) {
+        let profile = Profile { buffer: Some(TravelBuffer { slack, ..TravelBuffer::default() }), ..Profile::default() };
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .profile(profile)
+                .details(vec![create_detail((Some(0), Some(0)), Some((0., 100.)))])
+                .build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                test_activity_with_location_and_tw(10, TimeWindow::new(0., 20.)),
+                test_activity_with_location(20),
+                test_activity_with_location(30),
+            ],
+        );
+        let pipeline = create_constraint_pipeline_with_transport();
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let activity = route_ctx.route.tour.get(1).unwrap();
+        let latest_arrival = *route_ctx.state.get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, activity).unwrap();
+        let target = test_activity_with_location_and_tw(25, TimeWindow::new(0., 30.));
+        let activity_ctx = ActivityContext {
+            index: 0,
+            prev: route_ctx.route.tour.get(2).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(3),
+        };
+        let violation = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!((latest_arrival, violation), expected);
+    }
+
     #[test]
     fn can_update_activity_schedule() {
         let fleet = FleetBuilder::default()
@@ -524,3 +569,55 @@ mod time_dependent {
         }
     }
 }
+
+mod setup_time {
+    use super::*;
+    use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+    use crate::models::problem::SetupActivityCost;
+
+    #[test]
+    fn can_apply_setup_time_once_per_location() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((Some(0), Some(0)), Some((0., 100.)))])
+                .build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                test_activity_with_location_and_duration(10, 0.),
+                test_activity_with_location_and_duration(10, 0.),
+                test_activity_with_location_and_duration(20, 0.),
+            ],
+        );
+        let activity = Arc::new(SetupActivityCost::new(
+            TestActivityCost::new_shared(),
+            Arc::new(|_, activity| if activity.job.is_some() { 5. } else { 0. }),
+        ));
+        let pipeline = create_constraint_pipeline_with_module(Arc::new(TransportConstraintModule::new(
+            TestTransportCost::new_shared(),
+            activity,
+            1,
+        )));
+
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let schedules = route_ctx
+            .route
+            .tour
+            .all_activities()
+            .map(|a| (a.schedule.arrival, a.schedule.departure))
+            .collect::<Vec<_>>();
+        assert_eq!(schedules, vec![(0., 0.), (15., 15.), (15., 15.), (30., 30.), (50., 50.)]);
+        let latest_arrivals = route_ctx
+            .route
+            .tour
+            .all_activities()
+            .map(|a| route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, a).cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(latest_arrivals, vec![None, Some(65.), Some(65.), Some(80.), None]);
+    }
+}
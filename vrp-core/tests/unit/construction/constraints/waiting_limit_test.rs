@@ -0,0 +1,115 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_modules;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: i32 = 2;
+
+parameterized_test! {can_limit_waiting_time, (location, tw_start, stop_limit, tour_limit, expected), {
+    can_limit_waiting_time_impl(location, tw_start, stop_limit, tour_limit, expected);
+}}
+
+can_limit_waiting_time! {
+    case01_tour_limit_exceeded: (5, 0., None, Some(30.), stop(VIOLATION_CODE)),
+    case02_tour_waiting_reduced: (20, 0., None, Some(30.), None),
+    case03_stop_limit_exceeded: (5, 30., Some(20.), None, stop(VIOLATION_CODE)),
+    case04_waiting_absorbed: (5, 30., Some(30.), Some(40.), None),
+    case05_no_limits: (5, 30., None, None, None),
+}
+
+fn can_limit_waiting_time_impl(
+    location: Location,
+    tw_start: Timestamp,
+    stop_limit: Option<Duration>,
+    tour_limit: Option<Duration>,
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let fleet = test_fleet();
+    let transport = TestTransportCost::new_shared();
+    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(TestActivityCost::default());
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(transport.clone(), activity.clone(), 1)),
+        Arc::new(WaitingLimitModule::new(
+            transport,
+            activity,
+            Arc::new(move |_| stop_limit),
+            Arc::new(move |_| tour_limit),
+            VIOLATION_CODE,
+        )),
+    ]);
+    // NOTE existing activity has 40 units of waiting time
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_location_and_tw(10, TimeWindow::new(50., 1000.))],
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: route_ctx.route.tour.get(0).unwrap(),
+            target: &test_activity_with_location_and_tw(location, TimeWindow::new(tw_start, 1000.)),
+            next: route_ctx.route.tour.get(1),
+        },
+    );
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_propagate_schedule_shift, (index, location, tw_start, tour_limit, expected), {
+    can_propagate_schedule_shift_impl(index, location, tw_start, tour_limit, expected);
+}}
+
+can_propagate_schedule_shift! {
+    case01_no_shift_within_limit: (1, 15, 0., 80., None),
+    case02_no_shift_exceeded: (1, 15, 0., 79., stop(VIOLATION_CODE)),
+    case03_shift_absorbed: (1, 15, 90., 80., None),
+    case04_shift_not_absorbed: (1, 15, 110., 80., stop(VIOLATION_CODE)),
+    case05_first_no_shift: (0, 5, 0., 80., None),
+}
+
+fn can_propagate_schedule_shift_impl(
+    index: usize,
+    location: Location,
+    tw_start: Timestamp,
+    tour_limit: Duration,
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let fleet = test_fleet();
+    let transport = TestTransportCost::new_shared();
+    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(TestActivityCost::default());
+    let pipeline = create_constraint_pipeline_with_modules(vec![
+        Arc::new(TransportConstraintModule::new(transport.clone(), activity.clone(), 1)),
+        Arc::new(WaitingLimitModule::new(
+            transport,
+            activity,
+            Arc::new(|_| None),
+            Arc::new(move |_| Some(tour_limit)),
+            VIOLATION_CODE,
+        )),
+    ]);
+    // NOTE existing activities have 40 units of waiting time each
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_location_and_tw(10, TimeWindow::new(50., 1000.)),
+            test_activity_with_location_and_tw(20, TimeWindow::new(100., 1000.)),
+        ],
+    );
+    pipeline.accept_route_state(&mut route_ctx);
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route.tour.get(index).unwrap(),
+            target: &test_activity_with_location_and_tw(location, TimeWindow::new(tw_start, 1000.)),
+            next: route_ctx.route.tour.get(index + 1),
+        },
+    );
+
+    assert_eq!(result, expected);
+}
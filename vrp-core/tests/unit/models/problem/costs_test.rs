@@ -1,4 +1,5 @@
 use super::*;
+use crate::helpers::models::problem::{get_test_actor_from_fleet, test_driver, FleetBuilder, VehicleBuilder};
 use crate::helpers::models::solution::{create_empty_route_ctx, test_actor_with_profile};
 
 fn create_matrix_data(
@@ -12,9 +13,35 @@ fn create_matrix_data(
         timestamp,
         durations: vec![duration.0; duration.1],
         distances: vec![distance.0; distance.1],
+        costs: None,
+        interpolation: MatrixInterpolation::default(),
+        storage: MatrixStorage::default(),
     }
 }
 
+fn create_time_aware_costs(
+    interpolation: MatrixInterpolation,
+    data: Vec<(Timestamp, Duration, Distance)>,
+) -> (Route, TimeAwareMatrixTransportCost) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+
+    let costs = TimeAwareMatrixTransportCost::new(
+        data.into_iter()
+            .map(|(timestamp, duration, distance)| MatrixData {
+                durations: vec![0., duration, duration, 0.],
+                distances: vec![0., distance, distance, 0.],
+                interpolation,
+                ..create_matrix_data(profile.clone(), Some(timestamp), (0., 4), (0., 4))
+            })
+            .collect(),
+        2,
+    )
+    .unwrap();
+
+    (route, costs)
+}
+
 #[test]
 fn can_detect_dimensions_mismatch() {
     assert_eq!(
@@ -102,6 +129,243 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+#[test]
+fn can_detect_different_interpolations_of_the_same_profile() {
+    let profile = Profile::default();
+
+    let result = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (0., 1), (0., 1)),
+            MatrixData {
+                interpolation: MatrixInterpolation::Step,
+                ..create_matrix_data(profile, Some(10.), (0., 1), (0., 1))
+            },
+        ],
+        1,
+    );
+
+    assert_eq!(result.err(), Some("matrices of the same profile should have the same interpolation".to_string()));
+}
+
+parameterized_test! {can_interpolate_durations_and_distances, (interpolation, departure, expected), {
+    can_interpolate_durations_and_distances_impl(interpolation, departure, expected);
+}}
+
+can_interpolate_durations_and_distances! {
+    case01_linear_before: (MatrixInterpolation::Linear, 0., (100., 1000.)),
+    case02_linear_middle: (MatrixInterpolation::Linear, 50., (150., 1500.)),
+    case03_linear_after: (MatrixInterpolation::Linear, 150., (200., 2000.)),
+    case04_step_before: (MatrixInterpolation::Step, 0., (100., 1000.)),
+    case05_step_middle: (MatrixInterpolation::Step, 50., (100., 1000.)),
+    case06_step_after: (MatrixInterpolation::Step, 150., (200., 2000.)),
+}
+
+fn can_interpolate_durations_and_distances_impl(
+    interpolation: MatrixInterpolation,
+    departure: Timestamp,
+    expected: (Duration, Distance),
+) {
+    let (route, costs) = create_time_aware_costs(interpolation, vec![(0., 100., 1000.), (100., 200., 2000.)]);
+
+    let duration = costs.duration(&route, 0, 1, TravelTime::Departure(departure));
+    let distance = costs.distance(&route, 0, 1, TravelTime::Departure(departure));
+
+    assert_eq!((duration, distance), expected);
+}
+
+parameterized_test! {can_satisfy_fifo_property, (interpolation, departure, expected), {
+    can_satisfy_fifo_property_impl(interpolation, departure, expected);
+}}
+
+can_satisfy_fifo_property! {
+    case01_linear_start: (MatrixInterpolation::Linear, 0., (30., 20.)),
+    case02_linear_middle: (MatrixInterpolation::Linear, 5., (25., 20.)),
+    case03_linear_end: (MatrixInterpolation::Linear, 10., (20., 20.)),
+    case04_step_start: (MatrixInterpolation::Step, 0., (30., 20.)),
+    case05_step_middle: (MatrixInterpolation::Step, 9., (21., 20.)),
+}
+
+fn can_satisfy_fifo_property_impl(
+    interpolation: MatrixInterpolation,
+    departure: Timestamp,
+    expected: (Duration, Distance),
+) {
+    let (route, costs) = create_time_aware_costs(interpolation, vec![(0., 100., 100.), (10., 20., 20.)]);
+
+    let duration = costs.duration(&route, 0, 1, TravelTime::Departure(departure));
+    let distance = costs.distance(&route, 0, 1, TravelTime::Departure(departure));
+
+    assert_eq!((duration, distance), expected);
+}
+
+parameterized_test! {can_report_waiting_for_faster_travel, (interpolation, travel_time, expected), {
+    can_report_waiting_for_faster_travel_impl(interpolation, travel_time, expected);
+}}
+
+can_report_waiting_for_faster_travel! {
+    case01_linear_start: (MatrixInterpolation::Linear, TravelTime::Departure(0.), (30., 10.)),
+    case02_linear_middle: (MatrixInterpolation::Linear, TravelTime::Departure(5.), (25., 5.)),
+    case03_linear_end: (MatrixInterpolation::Linear, TravelTime::Departure(10.), (20., 0.)),
+    case04_step_middle: (MatrixInterpolation::Step, TravelTime::Departure(9.), (21., 1.)),
+    case05_step_arrival: (MatrixInterpolation::Step, TravelTime::Arrival(30.), (20., 0.)),
+}
+
+fn can_report_waiting_for_faster_travel_impl(
+    interpolation: MatrixInterpolation,
+    travel_time: TravelTime,
+    expected: (Duration, Duration),
+) {
+    let (route, costs) = create_time_aware_costs(interpolation, vec![(0., 100., 100.), (10., 20., 20.)]);
+
+    let duration = costs.duration(&route, 0, 1, travel_time);
+    let waiting = costs.duration_waiting(&route, 0, 1, travel_time);
+
+    assert_eq!((duration, waiting), expected);
+    assert_eq!(costs.duration_buffer(&route, 0, 1, travel_time), 0.);
+}
+
+#[test]
+fn can_keep_arrival_time_non_decreasing() {
+    for interpolation in [MatrixInterpolation::Linear, MatrixInterpolation::Step] {
+        let (route, costs) = create_time_aware_costs(
+            interpolation,
+            vec![(0., 100., 100.), (10., 20., 20.), (50., 300., 300.), (60., 50., 50.)],
+        );
+
+        (0..100).map(|time| time as f64).fold(0., |last_arrival, departure| {
+            let arrival = departure + costs.duration(&route, 0, 1, TravelTime::Departure(departure));
+            assert!(arrival >= last_arrival);
+
+            arrival
+        });
+    }
+}
+
+parameterized_test! {can_calculate_duration_for_arrival_time, (interpolation, arrival, expected), {
+    can_calculate_duration_for_arrival_time_impl(interpolation, arrival, expected);
+}}
+
+can_calculate_duration_for_arrival_time! {
+    case01_linear_before: (MatrixInterpolation::Linear, 50., (100., 1000.)),
+    case02_linear_middle: (MatrixInterpolation::Linear, 250., (175., 1750.)),
+    case03_linear_after: (MatrixInterpolation::Linear, 400., (200., 2000.)),
+    case04_step_before: (MatrixInterpolation::Step, 50., (100., 1000.)),
+    case05_step_middle: (MatrixInterpolation::Step, 150., (100., 1000.)),
+    case06_step_waiting: (MatrixInterpolation::Step, 250., (150., 2000.)),
+    case07_step_after: (MatrixInterpolation::Step, 400., (200., 2000.)),
+}
+
+fn can_calculate_duration_for_arrival_time_impl(
+    interpolation: MatrixInterpolation,
+    arrival: Timestamp,
+    expected: (Duration, Distance),
+) {
+    let (route, costs) = create_time_aware_costs(interpolation, vec![(0., 100., 1000.), (100., 200., 2000.)]);
+
+    let duration = costs.duration(&route, 0, 1, TravelTime::Arrival(arrival));
+    let distance = costs.distance(&route, 0, 1, TravelTime::Arrival(arrival));
+
+    assert_eq!((duration, distance), expected);
+}
+
+#[test]
+fn can_be_consistent_for_departure_and_arrival_time() {
+    let (route, costs) = create_time_aware_costs(
+        MatrixInterpolation::Linear,
+        vec![(0., 100., 100.), (10., 20., 20.), (50., 300., 300.)],
+    );
+
+    for departure in (0..100).map(|time| time as f64) {
+        let arrival = departure + costs.duration(&route, 0, 1, TravelTime::Departure(departure));
+        let latest_departure = arrival - costs.duration(&route, 0, 1, TravelTime::Arrival(arrival));
+
+        assert!(latest_departure >= departure - 1E-6);
+        assert!(
+            (latest_departure + costs.duration(&route, 0, 1, TravelTime::Departure(latest_departure)) - arrival).abs()
+                < 1E-6
+        );
+    }
+}
+
+parameterized_test! {can_use_time_averaged_values_for_approximation, (interpolation, expected), {
+    can_use_time_averaged_values_for_approximation_impl(interpolation, expected);
+}}
+
+can_use_time_averaged_values_for_approximation! {
+    case01_linear: (MatrixInterpolation::Linear, (162.5, 1625.)),
+    case02_step: (MatrixInterpolation::Step, (125., 1250.)),
+}
+
+fn can_use_time_averaged_values_for_approximation_impl(
+    interpolation: MatrixInterpolation,
+    expected: (Duration, Distance),
+) {
+    let (route, costs) =
+        create_time_aware_costs(interpolation, vec![(0., 100., 1000.), (30., 200., 2000.), (40., 200., 2000.)]);
+    let profile = &route.actor.vehicle.profile;
+
+    let result = (costs.duration_approx(profile, 0, 1), costs.distance_approx(profile, 0, 1));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_detect_extra_costs_dimension_mismatch() {
+    assert_eq!(
+        create_matrix_transport_cost(vec![MatrixData {
+            costs: Some(vec![1.]),
+            ..create_matrix_data(Profile::default(), None, (0., 4), (0., 4))
+        }])
+        .err(),
+        Some("cost and duration collections have different length".to_string())
+    );
+}
+
+#[test]
+fn can_add_extra_costs_to_leg_cost() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let create_matrix = || create_matrix_data(profile.clone(), None, (10., 4), (1., 4));
+
+    let no_costs = create_matrix_transport_cost(vec![create_matrix()]).unwrap();
+    let extra_costs =
+        create_matrix_transport_cost(vec![MatrixData { costs: Some(vec![0., 5., 0., 0.]), ..create_matrix() }])
+            .unwrap();
+
+    assert_eq!(extra_costs.extra_cost(&route, 0, 1, TravelTime::Departure(0.)), 5.);
+    assert_eq!(extra_costs.extra_cost(&route, 1, 0, TravelTime::Departure(0.)), 0.);
+    assert_eq!(no_costs.extra_cost(&route, 0, 1, TravelTime::Departure(0.)), 0.);
+    assert_eq!(
+        extra_costs.cost(&route, 0, 1, TravelTime::Departure(0.)),
+        no_costs.cost(&route, 0, 1, TravelTime::Departure(0.)) + 5.
+    );
+    assert_eq!(extra_costs.distance(&route, 0, 1, TravelTime::Departure(0.)), 1.);
+    assert_eq!(extra_costs.duration(&route, 0, 1, TravelTime::Departure(0.)), 10.);
+}
+
+parameterized_test! {can_store_matrix_values_within_tolerance, (values, storage, is_exact), {
+    can_store_matrix_values_within_tolerance_impl(values, storage, is_exact);
+}}
+
+can_store_matrix_values_within_tolerance! {
+    case01_integers: (vec![0., 1., 3600., 16_777_216.], MatrixStorage::Compact, true),
+    case02_halves: (vec![0.5, 1.25, -1.], MatrixStorage::Compact, true),
+    case03_large_integer: (vec![0., 16_777_217.], MatrixStorage::Compact, false),
+    case04_fraction: (vec![0., 0.1], MatrixStorage::Compact, false),
+    case05_full_large_integer: (vec![0., 16_777_217.], MatrixStorage::Full, true),
+    case06_full_fraction: (vec![0., 0.1], MatrixStorage::Full, true),
+}
+
+fn can_store_matrix_values_within_tolerance_impl(values: Vec<f64>, storage: MatrixStorage, is_exact: bool) {
+    let matrix_values = MatrixValues::new(values.clone(), storage);
+
+    values.iter().enumerate().for_each(|(idx, &value)| {
+        let actual = matrix_values.get(idx);
+        assert!((actual - value).abs() <= value.abs() * 2_f64.powi(-24));
+        assert_eq!(actual == value, is_exact || value == 0.);
+    });
+}
+
 parameterized_test! {can_search_for_reserved_time, (times, tests), {
     can_search_for_reserved_time_impl(times, tests);
 }}
@@ -249,3 +513,25 @@ mod objective {
         assert_eq!(result, expected);
     }
 }
+
+#[test]
+fn can_add_buffer_to_duration() {
+    let buffer = TravelBuffer { percentage: 10., fixed: 5., slack: 0. };
+    let profile = Profile { buffer: Some(buffer), ..Profile::new(0, Some(2.)) };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").profile(profile.clone()).build())
+        .build();
+    let route = Route { actor: get_test_actor_from_fleet(&fleet, "v1"), tour: Default::default() };
+    let costs = create_matrix_transport_cost(vec![MatrixData {
+        durations: vec![0., 100., 100., 0.],
+        ..create_matrix_data(profile.clone(), None, (0., 4), (1., 4))
+    }])
+    .unwrap();
+
+    assert_eq!(costs.duration_approx(&profile, 0, 1), 225.);
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Departure(0.)), 225.);
+    assert_eq!(costs.duration_buffer(&route, 0, 1, TravelTime::Departure(0.)), 25.);
+    assert_eq!(costs.duration(&route, 0, 0, TravelTime::Departure(0.)), 0.);
+    assert_eq!(costs.duration_buffer(&route, 0, 0, TravelTime::Departure(0.)), 0.);
+}
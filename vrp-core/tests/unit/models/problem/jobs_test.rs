@@ -251,3 +251,56 @@ fn can_handle_negative_distances_durations_impl(transport_costs: Arc<dyn Transpo
             .all(|(_, cost)| { (*cost - UNREACHABLE_COST).abs() < std::f64::EPSILON }));
     }
 }
+
+parameterized_test! {can_limit_job_neighbours, (max_neighbors, expected), {
+    can_limit_job_neighbours_impl(max_neighbors, expected.iter().map(|s| s.to_string()).collect());
+}}
+
+can_limit_job_neighbours! {
+    case1: (0, Vec::<&str>::new()),
+    case2: (1, vec!["s1"]),
+    case3: (2, vec!["s1", "s3"]),
+    case4: (3, vec!["s1", "s3", "s0"]),
+    case5: (10, vec!["s1", "s3", "s0", "s4"]),
+}
+
+fn can_limit_job_neighbours_impl(max_neighbors: usize, expected: Vec<String>) {
+    let p1 = Profile::new(1, None);
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(
+            VehicleBuilder::default().id("v1").profile(p1.clone()).details(vec![test_vehicle_detail()]).build(),
+        )
+        .build();
+    let species = (0..5)
+        .map(|idx| SingleBuilder::default().id(format!("s{}", idx).as_str()).location(Some(idx)).build_as_job_ref())
+        .collect::<Vec<_>>();
+    let jobs =
+        Jobs::new_with_max_neighbors(&fleet, species.clone(), &create_profile_aware_transport_cost(), max_neighbors);
+
+    let result: Vec<String> =
+        jobs.neighbors(&p1, species.get(2).unwrap(), 0.0).map(|(j, _)| get_job_id(j).clone()).collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(jobs.distance(&p1, &species[2], &species[4], 0.), 2.);
+    assert!(jobs.index_memory_usage() > 0);
+}
+
+#[test]
+fn can_find_same_closest_neighbours_within_clusters() {
+    let profile = Profile::default();
+    let species = (0..100)
+        .map(|idx| SingleBuilder::default().id(format!("s{}", idx).as_str()).location(Some(idx)).build_as_job_ref())
+        .collect::<Vec<_>>();
+    let transport = create_only_distance_transport_cost();
+    let all_jobs = Jobs::new_with_max_neighbors(&test_fleet(), species.clone(), &transport, usize::MAX);
+    let limited_jobs = Jobs::new_with_max_neighbors(&test_fleet(), species.clone(), &transport, 5);
+
+    species.iter().for_each(|job| {
+        let expected = all_jobs.neighbors(&profile, job, 0.).take(5).map(|(_, cost)| *cost).collect::<Vec<_>>();
+        let result = limited_jobs.neighbors(&profile, job, 0.).map(|(_, cost)| *cost).collect::<Vec<_>>();
+
+        assert_eq!(result, expected);
+    });
+    assert!(limited_jobs.index_memory_usage() < all_jobs.index_memory_usage());
+}
@@ -1,7 +1,7 @@
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::{test_driver, test_vehicle_detail, FleetBuilder, VehicleBuilder};
-use crate::models::common::TimeInterval;
-use crate::models::problem::{Actor, VehicleDetail, VehiclePlace};
+use crate::models::common::{IdDimension, TimeInterval};
+use crate::models::problem::{Actor, Vehicle, VehicleDetail, VehiclePlace};
 use crate::models::solution::Registry;
 use std::cmp::Ordering::Less;
 use std::sync::Arc;
@@ -57,6 +57,59 @@ fn can_provide_next_actors_from_registry() {
     assert_eq!(actors.last().unwrap().detail.start.as_ref().map(|s| s.location), Some(1));
 }
 
+#[test]
+fn can_expand_registry_when_all_actors_of_group_are_used() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").details(vec![test_vehicle_detail()]).build())
+        .build()
+        .with_vehicle_factory(
+            Arc::new(|vehicle, index| {
+                let mut dimens = vehicle.dimens.clone();
+                dimens.set_id(format!("v{}", index).as_str());
+
+                Some(Vehicle {
+                    profile: vehicle.profile.clone(),
+                    costs: vehicle.costs.clone(),
+                    dimens,
+                    details: vehicle.details.clone(),
+                })
+            }),
+            3,
+        );
+    let mut registry = Registry::new(&fleet, test_random());
+    let mut other = Registry::new(&fleet, test_random());
+    let get_ids = |registry: &Registry| {
+        let mut ids = registry.available().map(|a| a.vehicle.dimens.get_id().cloned().unwrap()).collect::<Vec<_>>();
+        ids.sort();
+        ids
+    };
+    let get_actor = |registry: &Registry, id: &str| {
+        registry.all().find(|a| a.vehicle.dimens.get_id().map_or(false, |a_id| a_id == id)).unwrap()
+    };
+
+    assert!(registry.use_actor(&get_actor(&registry, "v1")));
+    assert_eq!(get_ids(&registry), vec!["v2"]);
+    assert!(registry.use_actor(&get_actor(&registry, "v2")));
+    assert_eq!(get_ids(&registry), vec!["v3"]);
+    assert!(registry.use_actor(&get_actor(&registry, "v3")));
+    assert!(get_ids(&registry).is_empty());
+    assert_eq!(registry.all().count(), 3);
+
+    assert!(other.use_actor(&get_actor(&other, "v1")));
+    assert_eq!(get_ids(&other), vec!["v2"]);
+    assert!(Arc::ptr_eq(&get_actor(&other, "v2"), &get_actor(&registry, "v2")));
+    assert_eq!(other.all().count(), 2);
+
+    registry.free_actor(&get_actor(&registry, "v2"));
+    registry.free_actor(&get_actor(&registry, "v3"));
+    assert_eq!(get_ids(&registry), vec!["v2"]);
+    assert_eq!(registry.all().count(), 2);
+
+    assert!(registry.use_actor(&get_actor(&registry, "v2")));
+    assert_eq!(get_ids(&registry), vec!["v3"]);
+}
+
 fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
     vec![
         test_vehicle_detail(),
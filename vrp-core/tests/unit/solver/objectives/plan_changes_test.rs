@@ -0,0 +1,74 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::IdDimension;
+use hashbrown::HashMap;
+
+fn get_reference_fns(reference: Vec<(&str, &str, usize)>) -> (JobReferenceFn, ActorReferenceFn) {
+    let get_tour = |vehicle_id: &str| vehicle_id.trim_start_matches('v').parse::<usize>().ok();
+    let reference = reference
+        .into_iter()
+        .map(|(job_id, vehicle_id, position)| (job_id.to_string(), (get_tour(vehicle_id).unwrap(), position)))
+        .collect::<HashMap<_, _>>();
+
+    (
+        Arc::new(move |job| job.dimens().get_id().and_then(|job_id| reference.get(job_id)).cloned()),
+        Arc::new(move |actor| actor.vehicle.dimens.get_id().and_then(|vehicle_id| get_tour(vehicle_id))),
+    )
+}
+
+parameterized_test! {can_count_route_changes, (reference, is_sequence_aware, expected), {
+    can_count_route_changes_impl(reference, is_sequence_aware, expected);
+}}
+
+can_count_route_changes! {
+    case01_same_plan: (vec![("job1", "v1", 0), ("job2", "v1", 1), ("job3", "v1", 2)], true, 0.),
+    case02_unknown_jobs: (vec![("job2", "v1", 0)], true, 0.),
+    case03_moved_job: (vec![("job1", "v1", 0), ("job2", "v2", 0), ("job3", "v1", 1)], true, 1.),
+    case04_all_moved: (vec![("job1", "v2", 0), ("job2", "v2", 1), ("job3", "v2", 2)], true, 3.),
+    case05_changed_sequence: (vec![("job1", "v1", 2), ("job2", "v1", 1), ("job3", "v1", 0)], true, 2.),
+    case06_changed_sequence_ignored: (vec![("job1", "v1", 2), ("job2", "v1", 1), ("job3", "v1", 0)], false, 0.),
+    case07_moved_and_changed: (vec![("job1", "v1", 1), ("job2", "v2", 0), ("job3", "v1", 0)], true, 2.),
+}
+
+fn can_count_route_changes_impl(reference: Vec<(&str, &str, usize)>, is_sequence_aware: bool, expected: f64) {
+    let fleet = test_fleet();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_job(test_single_with_id("job1")),
+            test_activity_with_job(test_single_with_id("job2")),
+            test_activity_with_job(test_single_with_id("job3")),
+        ],
+    );
+
+    let (job_reference_fn, actor_reference_fn) = get_reference_fns(reference);
+
+    let changes = get_route_changes(&route_ctx, &job_reference_fn, &actor_reference_fn, is_sequence_aware);
+
+    assert_eq!(changes, expected);
+}
+
+parameterized_test! {can_merge_jobs_from_same_reference_tour, (source, candidate, expected), {
+    can_merge_jobs_from_same_reference_tour_impl(source, candidate, expected);
+}}
+
+can_merge_jobs_from_same_reference_tour! {
+    case01_same_tour: ("job1", "job2", true),
+    case02_different_tours: ("job1", "job3", false),
+    case03_unknown_jobs: ("job4", "job5", true),
+    case04_known_and_unknown: ("job1", "job4", false),
+}
+
+fn can_merge_jobs_from_same_reference_tour_impl(source: &str, candidate: &str, expected: bool) {
+    let (job_reference_fn, actor_reference_fn) =
+        get_reference_fns(vec![("job1", "v1", 0), ("job2", "v1", 1), ("job3", "v2", 0)]);
+    let (constraint, _) = PlanChanges::minimize(job_reference_fn, actor_reference_fn, false, 1, -1);
+    let source = Job::Single(test_single_with_id(source));
+    let candidate = Job::Single(test_single_with_id(candidate));
+
+    let result = constraint.merge(source, candidate);
+
+    assert_eq!(result.is_ok(), expected);
+}
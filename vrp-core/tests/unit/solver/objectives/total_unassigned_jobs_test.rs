@@ -1,5 +1,7 @@
 use super::*;
 use crate::helpers::models::domain::{create_empty_insertion_context, create_simple_insertion_ctx};
+use crate::helpers::models::problem::test_single_with_id;
+use crate::models::common::IdDimension;
 
 #[test]
 fn can_properly_estimate_empty_solution() {
@@ -11,3 +13,55 @@ fn can_properly_estimate_empty_solution() {
 
     assert_eq!(result, Ordering::Greater);
 }
+
+parameterized_test! {can_compare_unassigned_jobs_by_priority, (unassigned_a, unassigned_b, expected), {
+    can_compare_unassigned_jobs_by_priority_impl(unassigned_a, unassigned_b, expected);
+}}
+
+can_compare_unassigned_jobs_by_priority! {
+    case01_less_high_priority: (vec!["low1", "low2"], vec!["high1"], Ordering::Less),
+    case02_more_high_priority: (vec!["high1"], vec!["low1", "low2"], Ordering::Greater),
+    case03_same_high_priority: (vec!["high1"], vec!["high2", "low1"], Ordering::Less),
+    case04_equal: (vec!["high1", "low1"], vec!["high2", "low2"], Ordering::Equal),
+}
+
+fn can_compare_unassigned_jobs_by_priority_impl(unassigned_a: Vec<&str>, unassigned_b: Vec<&str>, expected: Ordering) {
+    let create_insertion_ctx = |unassigned: Vec<&str>| {
+        let mut insertion_ctx = create_simple_insertion_ctx(10., 0);
+        insertion_ctx.solution.unassigned = unassigned
+            .into_iter()
+            .map(|id| (Job::Single(test_single_with_id(id)), UnassignmentInfo::Unknown))
+            .collect();
+        insertion_ctx
+    };
+    let objective = PrioritizedUnassignedJobs::new(
+        &[2, 1],
+        Arc::new(|job| if job.dimens().get_id().map_or(false, |id| id.starts_with("high")) { 1 } else { 2 }),
+        Arc::new(|_, _, _| 1.),
+    );
+
+    let result = objective.total_order(&create_insertion_ctx(unassigned_a), &create_insertion_ctx(unassigned_b));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_prefer_higher_priority_job_on_insertion_tie() {
+    let insertion_ctx = create_simple_insertion_ctx(10., 0);
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let (module, _) = PrioritizedUnassignedJobs::new_constrained(
+        &[2, 1],
+        Arc::new(|job| if job.dimens().get_id().map_or(false, |id| id.starts_with("high")) { 1 } else { 2 }),
+        Arc::new(|_, _, _| 1.),
+    );
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(module);
+    let estimate = |id: &str| {
+        pipeline.evaluate_soft_route(&insertion_ctx.solution, route_ctx, &Job::Single(test_single_with_id(id)))
+    };
+
+    let high = estimate("high1");
+    let low = estimate("low1");
+
+    assert!(high < low);
+}
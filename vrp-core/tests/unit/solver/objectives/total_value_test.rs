@@ -2,9 +2,9 @@ use super::*;
 use crate::construction::heuristics::{InsertionContext, UnassignmentInfo};
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
 use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
-use crate::helpers::models::problem::{get_job_id, test_single_with_id};
+use crate::helpers::models::problem::{get_job_id, test_fleet, test_single_with_id};
 use crate::helpers::models::solution::*;
-use crate::models::common::ValueDimension;
+use crate::models::common::{Schedule, ValueDimension};
 use crate::models::problem::Single;
 
 parameterized_test! {can_estimate_job_value, (value, max_cost, expected), {
@@ -91,3 +91,72 @@ fn can_merge_value() {
 
     assert_eq!(merged.dimens().get_value::<f64>("value").cloned(), Some(12.))
 }
+
+parameterized_test! {can_estimate_route_value_by_schedule, (job1_arrival, expected), {
+    can_estimate_route_value_by_schedule_impl(job1_arrival, expected);
+}}
+
+can_estimate_route_value_by_schedule! {
+    case_01: (10., -190.),
+    case_02: (50., -150.),
+    case_03: (100., -100.),
+}
+
+fn can_estimate_route_value_by_schedule_impl(job1_arrival: f64, expected: f64) {
+    let state_key = 1;
+    let (constraint, _) = TotalValue::maximize_with_schedule(
+        1000.,
+        0.1,
+        Arc::new(|_| 0.),
+        ValueFn::Left(Arc::new(|_| 100.)),
+        Some(ScheduleValue {
+            is_scheduled_func: Arc::new(|job| get_job_id(job) == "job1"),
+            value_func: Arc::new(|_, time| (100. - time).max(0.)),
+        }),
+        Arc::new(|job, _| job),
+        state_key,
+        1,
+    );
+    let fleet = test_fleet();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            ActivityBuilder::default()
+                .job(Some(test_single_with_id("job1")))
+                .schedule(Schedule::new(job1_arrival, job1_arrival))
+                .build(),
+            ActivityBuilder::default()
+                .job(Some(test_single_with_id("job2")))
+                .schedule(Schedule::new(job1_arrival + 10., job1_arrival + 10.))
+                .build(),
+        ],
+    );
+
+    constraint.accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<f64>(state_key).cloned(), Some(expected));
+}
+
+#[test]
+fn can_reject_merge_of_time_dependent_value() {
+    let (constraint, _) = TotalValue::maximize_with_schedule(
+        1000.,
+        0.1,
+        Arc::new(|_| 0.),
+        ValueFn::Left(Arc::new(|_| 10.)),
+        Some(ScheduleValue {
+            is_scheduled_func: Arc::new(|job| get_job_id(job) == "source"),
+            value_func: Arc::new(|_, _| 10.),
+        }),
+        Arc::new(|job, _| job),
+        1,
+        -1,
+    );
+    let source = Job::Single(test_single_with_id("source"));
+    let candidate = Job::Single(test_single_with_id("candidate"));
+
+    let result = constraint.merge(source, candidate).map(|_| ());
+
+    assert_eq!(result, Err(-1));
+}
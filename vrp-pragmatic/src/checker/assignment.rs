@@ -19,16 +19,17 @@ pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<String>> {
         check_jobs_match(ctx),
         check_dispatch(ctx),
         check_groups(ctx),
+        check_onboard_jobs(ctx),
+        check_periodic_jobs(ctx),
     ])
 }
 
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
 fn check_vehicles(ctx: &CheckerContext) -> Result<(), String> {
-    let all_vehicles: HashSet<_> = ctx.problem.fleet.vehicles.iter().flat_map(|v| v.vehicle_ids.iter()).collect();
     let mut used_vehicles = HashSet::<(String, usize)>::new();
 
     ctx.solution.tours.iter().try_for_each(|tour| {
-        if !all_vehicles.contains(&tour.vehicle_id) {
+        if ctx.get_vehicle(&tour.vehicle_id).is_err() {
             return Err(format!("used vehicle with unknown id: '{}'", tour.vehicle_id));
         }
 
@@ -61,7 +62,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
     let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
 
     let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
-    let mut used_jobs = HashMap::<String, JobAssignment>::new();
+    let mut used_jobs = HashMap::<String, Vec<JobAssignment>>::new();
 
     ctx.solution.tours.iter().try_for_each(|tour| {
         tour.stops
@@ -71,12 +72,19 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
             .filter(|(_, activity)| activity_types.contains(&activity.activity_type.as_str()))
             .try_for_each(|(idx, activity)| {
                 let tour_info = (tour.vehicle_id.clone(), tour.shift_index);
-                let asgn =
-                    used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
-
-                if asgn.tour_info != tour_info {
-                    return Err(format!("job served in multiple tours: '{}'", activity.job_id));
-                }
+                let is_periodic = all_jobs.get(&activity.job_id).map_or(false, |job| job.frequency.is_some());
+                let assignments = used_jobs.entry(activity.job_id.clone()).or_default();
+
+                // NOTE periodic job is served once per visit in different tours
+                let asgn_idx = match assignments.iter().position(|asgn| asgn.tour_info == tour_info) {
+                    Some(asgn_idx) => asgn_idx,
+                    None if assignments.is_empty() || is_periodic => {
+                        assignments.push(new_assignment(tour_info));
+                        assignments.len() - 1
+                    }
+                    None => return Err(format!("job served in multiple tours: '{}'", activity.job_id)),
+                };
+                let asgn = &mut assignments[asgn_idx];
 
                 match activity.activity_type.as_str() {
                     "pickup" => asgn.pickups.push(idx),
@@ -90,28 +98,31 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
             })
     })?;
 
-    used_jobs.iter().try_for_each(|(id, asgn)| {
-        // TODO validate whether each job task is served once
-        let job = all_jobs.get(id).ok_or_else(|| format!("Cannot find job with id {}", id))?;
-        let expected_tasks = job.pickups.as_ref().map_or(0, |p| p.len())
-            + job.deliveries.as_ref().map_or(0, |d| d.len())
-            + job.services.as_ref().map_or(0, |s| s.len())
-            + job.replacements.as_ref().map_or(0, |r| r.len());
-        let assigned_tasks = asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
-
-        if expected_tasks != assigned_tasks {
-            return Err(format!(
-                "not all tasks served for '{}', expected: {}, assigned: {}",
-                id, expected_tasks, assigned_tasks
-            ));
-        }
+    used_jobs.iter().flat_map(|(id, assignments)| assignments.iter().map(move |asgn| (id, asgn))).try_for_each(
+        |(id, asgn)| {
+            // TODO validate whether each job task is served once
+            let job = all_jobs.get(id).ok_or_else(|| format!("Cannot find job with id {}", id))?;
+            let expected_tasks = job.pickups.as_ref().map_or(0, |p| p.len())
+                + job.deliveries.as_ref().map_or(0, |d| d.len())
+                + job.services.as_ref().map_or(0, |s| s.len())
+                + job.replacements.as_ref().map_or(0, |r| r.len());
+            let assigned_tasks =
+                asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
+
+            if expected_tasks != assigned_tasks {
+                return Err(format!(
+                    "not all tasks served for '{}', expected: {}, assigned: {}",
+                    id, expected_tasks, assigned_tasks
+                ));
+            }
 
-        if !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
-            return Err(format!("found pickup after delivery for '{}'", id));
-        }
+            if !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
+                return Err(format!("found pickup after delivery for '{}'", id));
+            }
 
-        Ok(())
-    })?;
+            Ok(())
+        },
+    )?;
 
     let all_unassigned_jobs = ctx
         .solution
@@ -368,3 +379,110 @@ fn check_groups(ctx: &CheckerContext) -> Result<(), String> {
         Err(format!("job groups are not respected: '{}'", err_info))
     }
 }
+
+/// Checks that jobs with cargo on board are served only by vehicle shift they are loaded on.
+fn check_onboard_jobs(ctx: &CheckerContext) -> Result<(), String> {
+    let onboard = ctx
+        .problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter().map(move |vehicle_id| (vehicle_id, vehicle)))
+        .flat_map(|(vehicle_id, vehicle)| {
+            vehicle.shifts.iter().enumerate().flat_map(move |(shift_index, shift)| {
+                shift.onboard.iter().flatten().map(move |job_id| (job_id.clone(), (vehicle_id.clone(), shift_index)))
+            })
+        })
+        .collect::<HashMap<_, _>>();
+
+    let violations = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| {
+                    onboard.get(&activity.job_id).map_or(false, |(vehicle_id, shift_index)| {
+                        *vehicle_id != tour.vehicle_id || *shift_index != tour.shift_index
+                    })
+                })
+                .map(|activity| activity.job_id.clone())
+        })
+        .collect::<HashSet<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        let err_info = violations.into_iter().collect::<Vec<_>>().join(",");
+        Err(format!("on board jobs are served by another vehicle shift: '{}'", err_info))
+    }
+}
+
+/// Checks that periodic jobs are visited expected amount of times respecting allowed days and minimum gap.
+fn check_periodic_jobs(ctx: &CheckerContext) -> Result<(), String> {
+    let get_day = |time: &str| (parse_time(time) / 86400.).floor().max(0.) as usize;
+
+    let horizon_day = ctx
+        .problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| get_day(&shift.start.earliest))
+        .min()
+        .unwrap_or(0);
+
+    let visit_days = ctx.solution.tours.iter().try_fold(HashMap::<String, Vec<usize>>::new(), |mut acc, tour| {
+        let shift = ctx
+            .get_vehicle(&tour.vehicle_id)?
+            .shifts
+            .get(tour.shift_index)
+            .ok_or_else(|| format!("cannot find shift {} for vehicle '{}'", tour.shift_index, tour.vehicle_id))?;
+        let day = get_day(&shift.start.earliest).saturating_sub(horizon_day);
+
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .filter(|activity| ctx.job_map.get(&activity.job_id).map_or(false, |job| job.frequency.is_some()))
+            .map(|activity| activity.job_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .for_each(|job_id| acc.entry(job_id).or_default().push(day));
+
+        Ok::<_, String>(acc)
+    })?;
+
+    visit_days.into_iter().try_for_each(|(job_id, mut days)| {
+        let frequency = ctx
+            .job_map
+            .get(&job_id)
+            .and_then(|job| job.frequency.as_ref())
+            .ok_or_else(|| format!("cannot find frequency for job '{}'", job_id))?;
+
+        if days.len() != frequency.visits {
+            return Err(format!(
+                "periodic job '{}' is visited {} times, expected: {}",
+                job_id,
+                days.len(),
+                frequency.visits
+            ));
+        }
+
+        if let Some(day) = days
+            .iter()
+            .find(|day| frequency.allowed_days.as_ref().map_or(false, |allowed_days| !allowed_days.contains(day)))
+        {
+            return Err(format!("periodic job '{}' is visited on not allowed day: {}", job_id, day));
+        }
+
+        let min_gap_days = frequency.min_gap_days.unwrap_or(1);
+        days.sort_unstable();
+        if days.windows(2).any(|pair| pair[1] - pair[0] < min_gap_days) {
+            return Err(format!("periodic job '{}' visits violate minimum gap of {} days", job_id, min_gap_days));
+        }
+
+        Ok(())
+    })
+}
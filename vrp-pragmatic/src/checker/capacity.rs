@@ -18,10 +18,11 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String>
     context.solution.tours.iter().try_for_each(|tour| {
         let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
         let intervals = get_intervals(context, tour);
+        let initial_load = get_onboard_load(context, tour)?;
 
         intervals
             .iter()
-            .try_fold::<_, _, Result<_, String>>(MultiDimLoad::default(), |acc, interval| {
+            .try_fold::<_, _, Result<_, String>>(initial_load, |acc, interval| {
                 let (start_delivery, end_pickup) = get_activities_from_interval(context, tour, interval.as_slice())
                     .try_fold::<_, _, Result<_, String>>(
                     (acc, MultiDimLoad::default()),
@@ -170,8 +171,10 @@ fn get_demand(
         activity,
         activity_type,
         |job, task| {
-            let is_dynamic = job.pickups.as_ref().map_or(false, |p| !p.is_empty())
-                && job.deliveries.as_ref().map_or(false, |p| !p.is_empty());
+            // NOTE on board job's delivery unloads vehicle's initial load
+            let is_dynamic = (job.pickups.as_ref().map_or(false, |p| !p.is_empty())
+                && job.deliveries.as_ref().map_or(false, |p| !p.is_empty()))
+                || is_onboard_job(context, job.id.as_str());
             let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
 
             (is_dynamic, demand)
@@ -191,6 +194,34 @@ fn get_demand(
     Ok((demand_type, demand))
 }
 
+/// Gets load of on board jobs which is loaded at tour start.
+fn get_onboard_load(context: &CheckerContext, tour: &Tour) -> Result<MultiDimLoad, String> {
+    let shift = context
+        .get_vehicle(&tour.vehicle_id)?
+        .shifts
+        .get(tour.shift_index)
+        .ok_or_else(|| format!("cannot find shift {} for tour '{}'", tour.shift_index, tour.vehicle_id))?;
+
+    Ok(shift
+        .onboard
+        .iter()
+        .flatten()
+        .filter_map(|job_id| context.get_job_by_id(job_id))
+        .flat_map(|job| job.deliveries.iter().flatten())
+        .filter_map(|task| task.demand.clone())
+        .fold(MultiDimLoad::default(), |acc, demand| acc + MultiDimLoad::new(demand)))
+}
+
+fn is_onboard_job(context: &CheckerContext, job_id: &str) -> bool {
+    context
+        .problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .any(|shift| shift.onboard.iter().flatten().any(|onboard_id| onboard_id == job_id))
+}
+
 fn get_intervals<'a>(context: &CheckerContext, tour: &'a Tour) -> Vec<Vec<(usize, (&'a Stop, &'a Stop))>> {
     let legs = tour
         .stops
@@ -7,19 +7,28 @@ use crate::utils::combine_error_results;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_shift_time(context),
+        check_fleet_limits(context),
+        check_vehicle_access(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
 /// * max shift time
 /// * max distance
+/// * tour size
+/// * min shift time and min tour size of used tour
+/// * max waiting time per tour and per stop
+/// * backhaul
 fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
 
         if let Some(ref limits) = vehicle.limits {
             if let Some(max_distance) = limits.max_distance {
-                if tour.statistic.distance as f64 > max_distance {
+                if tour.statistic.distance > max_distance {
                     return Err(format!(
                         "max distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         max_distance, tour.statistic.distance, tour.vehicle_id, tour.shift_index
@@ -28,7 +37,7 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
             }
 
             if let Some(shift_time) = limits.shift_time {
-                if tour.statistic.duration as f64 > shift_time {
+                if tour.statistic.duration > shift_time {
                     return Err(format!(
                         "shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         shift_time, tour.statistic.duration, tour.vehicle_id, tour.shift_index
@@ -36,18 +45,74 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
                 }
             }
 
-            if let Some(tour_size_limit) = limits.tour_size {
+            if let Some(min_shift_time) = limits.min_shift_time {
+                if tour.statistic.duration < min_shift_time {
+                    return Err(format!(
+                        "min shift time limit violation, expected: not less than {}, got: {}, vehicle id '{}', shift index: {}",
+                        min_shift_time, tour.statistic.duration, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_waiting_time) = limits.max_waiting_time.as_ref().and_then(|limits| limits.per_tour) {
+                if tour.statistic.times.waiting > max_waiting_time {
+                    return Err(format!(
+                        "max waiting time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_waiting_time, tour.statistic.times.waiting, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_waiting_time) = limits.max_waiting_time.as_ref().and_then(|limits| limits.per_stop) {
+                check_stop_waiting_time(context, tour, max_waiting_time)?;
+            }
+
+            if limits.tour_size.is_some() || limits.min_tour_size.is_some() {
                 let shift = context.get_vehicle_shift(tour)?;
 
                 let extra_activities = if shift.end.is_some() { 2 } else { 1 };
                 let tour_activities = tour.stops.iter().flat_map(|stop| stop.activities()).count();
                 let tour_activities = if tour_activities > extra_activities { tour_activities - extra_activities } else { 0 };
 
-                if tour_activities > tour_size_limit {
+                if let Some(tour_size_limit) = limits.tour_size {
+                    if tour_activities > tour_size_limit {
+                        return Err(format!(
+                            "tour size limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                            tour_size_limit, tour_activities, tour.vehicle_id, tour.shift_index
+                        ));
+                    }
+                }
+
+                if let Some(min_tour_size_limit) = limits.min_tour_size {
+                    if tour_activities < min_tour_size_limit {
+                        return Err(format!(
+                            "min tour size limit violation, expected: not less than {}, got: {}, vehicle id '{}', shift index: {}",
+                            min_tour_size_limit, tour_activities, tour.vehicle_id, tour.shift_index
+                        ));
+                    }
+                }
+            }
+
+            if limits.backhaul.unwrap_or(false) {
+                let is_static_job = |job_id: &str| {
+                    context.job_map.get(job_id).map_or(false, |job| {
+                        job.pickups.as_ref().map_or(true, |p| p.is_empty())
+                            || job.deliveries.as_ref().map_or(true, |d| d.is_empty())
+                    })
+                };
+                let has_delivery_after_pickup = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities())
+                    .filter(|activity| is_static_job(&activity.job_id))
+                    .skip_while(|activity| activity.activity_type != "pickup")
+                    .any(|activity| activity.activity_type == "delivery");
+
+                if has_delivery_after_pickup {
                     return Err(format!(
-                        "tour size limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
-                        tour_size_limit, tour_activities, tour.vehicle_id, tour.shift_index
-                    ))
+                        "backhaul violation, delivery is served after pickup, vehicle id '{}', shift index: {}",
+                        tour.vehicle_id, tour.shift_index
+                    ));
                 }
             }
         }
@@ -86,3 +151,116 @@ fn check_shift_time(context: &CheckerContext) -> Result<(), String> {
         }
     })
 }
+
+/// Checks that waiting time at each job activity does not exceed the limit. As activity time includes
+/// waiting and service time, waiting is estimated using max duration of job places at activity location.
+fn check_stop_waiting_time(context: &CheckerContext, tour: &Tour, max_waiting_time: f64) -> Result<(), String> {
+    tour.stops.iter().try_for_each(|stop| {
+        stop.activities().iter().try_for_each(|activity| {
+            let job = if let Some(job) = context.job_map.get(&activity.job_id) { job } else { return Ok(()) };
+            let location = context.get_activity_location(stop, activity);
+
+            let duration = get_job_tasks(job)
+                .flat_map(|task| task.places.iter())
+                .filter(|place| location.as_ref().map_or(false, |location| place.location == *location))
+                .map(|place| place.duration)
+                .fold(0., f64::max);
+
+            let time = context.get_activity_time(stop, activity);
+            let waiting = time.end - time.start - duration;
+
+            if waiting > max_waiting_time && !context.is_same_time(max_waiting_time, waiting) {
+                Err(format!(
+                    "max waiting time per stop limit violation for job '{}', expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                    activity.job_id, max_waiting_time, waiting, tour.vehicle_id, tour.shift_index
+                ))
+            } else {
+                Ok(())
+            }
+        })
+    })
+}
+
+/// Check that fleet limits are not violated:
+/// * max tours
+/// * min tours, unless fleet usage violation is reported
+fn check_fleet_limits(context: &CheckerContext) -> Result<(), String> {
+    context.problem.fleet.limits.iter().flat_map(|limits| limits.iter()).try_for_each(|limit| {
+        let is_applicable = |type_id: &str| {
+            limit.type_id.as_ref().map_or(false, |limit_type_id| limit_type_id == type_id)
+                || limit.tag.as_ref().map_or(false, |tag| {
+                    context
+                        .problem
+                        .fleet
+                        .vehicles
+                        .iter()
+                        .filter(|vehicle| vehicle.type_id == type_id)
+                        .filter_map(|vehicle| vehicle.tags.as_ref())
+                        .any(|tags| tags.contains(tag))
+                })
+        };
+        let actual_tours = context.solution.tours.iter().filter(|tour| is_applicable(&tour.type_id)).count();
+        let (type_id, tag) = (limit.type_id.clone().unwrap_or_default(), limit.tag.clone().unwrap_or_default());
+
+        if let Some(max_tours) = limit.max_tours {
+            if actual_tours > max_tours {
+                return Err(format!(
+                    "fleet max tours limit violation, expected: not more than {}, got: {}, type id: '{}', tag: '{}'",
+                    max_tours, actual_tours, type_id, tag
+                ));
+            }
+        }
+
+        if let Some(min_tours) = limit.min_tours {
+            let is_reported = context.solution.violations.iter().flat_map(|violations| violations.iter()).any(|v| {
+                matches!(v, Violation::FleetUsage { type_id, tag, actual_tours: reported_tours, .. }
+                    if *type_id == limit.type_id && *tag == limit.tag && *reported_tours == actual_tours)
+            });
+
+            if actual_tours < min_tours && !is_reported {
+                return Err(format!(
+                    "fleet min tours limit violation is not reported, expected: at least {}, got: {}, type id: '{}', tag: '{}'",
+                    min_tours, actual_tours, type_id, tag
+                ));
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Checks that vehicle physical attributes do not exceed access limits of visited job places.
+fn check_vehicle_access(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let attributes = if let Some(attributes) = vehicle.attributes.as_ref() { attributes } else { return Ok(()) };
+
+        let is_violated = |limit: Option<f64>, value: Option<f64>| limit.zip(value).map_or(false, |(l, v)| v > l);
+
+        tour.stops.iter().filter_map(|stop| stop.as_point()).try_for_each(|stop| {
+            stop.activities.iter().try_for_each(|activity| {
+                let job = if let Some(job) = context.job_map.get(&activity.job_id) { job } else { return Ok(()) };
+                let location = activity.location.as_ref().unwrap_or(&stop.location);
+
+                let is_accessible = get_job_tasks(job)
+                    .flat_map(|task| task.places.iter())
+                    .filter(|place| place.location == *location)
+                    .filter_map(|place| place.access.as_ref())
+                    .all(|access| {
+                        !is_violated(access.max_weight, attributes.weight)
+                            && !is_violated(access.max_height, attributes.height)
+                            && !is_violated(access.max_length, attributes.length)
+                    });
+
+                if is_accessible {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "vehicle access violation for job '{}' at location {}, vehicle id '{}', shift index: {}",
+                        activity.job_id, location, tour.vehicle_id, tour.shift_index
+                    ))
+                }
+            })
+        })
+    })
+}
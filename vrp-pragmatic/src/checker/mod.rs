@@ -32,8 +32,12 @@ pub struct CheckerContext {
     profile_index: HashMap<String, usize>,
     core_problem: Arc<CoreProblem>,
     clustering: Option<ClusterConfig>,
+    precision: Precision,
 }
 
+/// A tolerance used to compare times with float precision.
+const FLOAT_TIME_TOLERANCE: f64 = 1E-2;
+
 /// Represents all possible activity types.
 enum ActivityType {
     Terminal,
@@ -51,9 +55,12 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<String>> {
+        // NOTE coord index should be created from the original problem to match routing matrix
+        let coord_index = CoordIndex::new(&problem);
+        let problem = remove_onboard_pickups(problem);
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
         let clustering = core_problem.extras.get_cluster_config().cloned();
-        let coord_index = CoordIndex::new(&problem);
+        let precision = problem.precision.clone().unwrap_or_default();
         let profile_index = if matrices.is_none() {
             HashMap::new()
         } else {
@@ -62,7 +69,17 @@ impl CheckerContext {
                 .map_err(|err| vec![err])?
         };
 
-        Ok(Self { problem, matrices, solution, job_map, coord_index, profile_index, core_problem, clustering })
+        Ok(Self {
+            problem,
+            matrices,
+            solution,
+            job_map,
+            coord_index,
+            profile_index,
+            core_problem,
+            clustering,
+            precision,
+        })
     }
 
     /// Performs solution check.
@@ -99,7 +116,7 @@ impl CheckerContext {
             .fleet
             .vehicles
             .iter()
-            .find(|v| v.vehicle_ids.contains(&vehicle_id.to_string()))
+            .find(|v| has_vehicle_id(v, vehicle_id))
             .ok_or_else(|| format!("cannot find vehicle with id '{}'", vehicle_id))
     }
 
@@ -111,7 +128,7 @@ impl CheckerContext {
             .cloned()
             .ok_or(format!("cannot get matrix for '{}' profile", profile.matrix))?;
 
-        Ok(Profile { index, scale: profile.scale.unwrap_or(1.) })
+        Ok(Profile { index, scale: profile.scale.unwrap_or(1.), buffer: get_profile_buffer(profile) })
     }
 
     /// Gets activity operation time range in seconds since Unix epoch.
@@ -296,23 +313,23 @@ impl CheckerContext {
                                         (stop_location, b_distance, b_duration)
                                     }
                                     (VisitPolicy::OpenContinuation, _) | (VisitPolicy::ClosedContinuation, true) => {
-                                        (curr_location, 0_i64, 0_i64)
+                                        (curr_location, 0., 0.)
                                     }
                                 };
 
                                 // NOTE parking correction
-                                let f_duration = if f_duration == 0 { parking as f64 } else { f_duration as f64 };
+                                let f_duration = if f_duration == 0. { parking } else { f_duration };
 
                                 Ok(Some(DomainCommute {
                                     forward: DomainCommuteInfo {
                                         location: prev_location,
-                                        distance: f_distance as f64,
+                                        distance: f_distance,
                                         duration: f_duration,
                                     },
                                     backward: DomainCommuteInfo {
                                         location: b_location,
-                                        distance: b_distance as f64,
-                                        duration: b_duration as f64,
+                                        distance: b_distance,
+                                        duration: b_duration,
                                     },
                                 }))
                             }
@@ -366,7 +383,7 @@ impl CheckerContext {
             .ok_or_else(|| format!("cannot find coordinate in coord index: {:?}", location))
     }
 
-    fn get_matrix_data(&self, profile: &Profile, from_idx: usize, to_idx: usize) -> Result<(i64, i64), String> {
+    fn get_matrix_data(&self, profile: &Profile, from_idx: usize, to_idx: usize) -> Result<(f64, f64), String> {
         let matrices = get_matrices(&self.matrices)?;
         let matrix =
             matrices.get(profile.index).ok_or_else(|| format!("cannot find matrix with index {}", profile.index))?;
@@ -374,12 +391,33 @@ impl CheckerContext {
         let matrix_size = get_matrix_size(matrices.as_slice());
         let matrix_idx = from_idx * matrix_size + to_idx;
 
-        let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
-        let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
-        let duration = (duration as f64 * profile.scale) as i64;
+        let distance = get_matrix_value(matrix_idx, matrix, Matrix::get_distance)?;
+        let duration = get_matrix_value(matrix_idx, matrix, Matrix::get_travel_time)?;
+        let duration = duration * profile.scale;
+        let duration = self.to_measure(duration + profile.get_buffer(duration));
 
         Ok((distance, duration))
     }
+
+    /// Converts duration or distance to the value expected in solution: fractional part is kept only
+    /// with float precision.
+    fn to_measure(&self, value: f64) -> f64 {
+        match self.precision {
+            Precision::Integer => value.trunc(),
+            Precision::Float => value,
+        }
+    }
+
+    /// Compares expected and actual times: integer values can differ by one due to truncation, float values
+    /// can differ only due to millisecond formatting.
+    fn is_same_time(&self, expected: f64, actual: f64) -> bool {
+        let tolerance = match self.precision {
+            Precision::Integer => 1.,
+            Precision::Float => FLOAT_TIME_TOLERANCE,
+        };
+
+        (expected - actual).abs() <= tolerance
+    }
 }
 
 fn job_task_size(tasks: &Option<Vec<JobTask>>) -> usize {
@@ -420,11 +458,25 @@ fn get_matrix_size(matrices: &[Matrix]) -> usize {
     (matrices.first().unwrap().travel_times.len() as f64).sqrt().round() as usize
 }
 
-fn get_matrix_value(idx: usize, matrix_values: &[i64]) -> Result<i64, String> {
-    matrix_values
-        .get(idx)
+fn get_matrix_value(idx: usize, matrix: &Matrix, get_value: fn(&Matrix, usize) -> Option<f64>) -> Result<f64, String> {
+    get_value(matrix, idx)
+        .ok_or_else(|| format!("attempt to get value out of bounds: {} vs {}", idx, matrix.travel_times.len()))
+}
+
+/// Removes pickups of jobs which cargo is already on board as they are not expected in solution.
+fn remove_onboard_pickups(mut problem: Problem) -> Problem {
+    let onboard_ids = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .flat_map(|shift| shift.onboard.iter().flatten())
         .cloned()
-        .ok_or_else(|| format!("attempt to get value out of bounds: {} vs {}", idx, matrix_values.len()))
+        .collect::<HashSet<_>>();
+
+    problem.plan.jobs.iter_mut().filter(|job| onboard_ids.contains(&job.id)).for_each(|job| job.pickups = None);
+
+    problem
 }
 
 fn get_matrices(matrices: &Option<Vec<Matrix>>) -> Result<&Vec<Matrix>, String> {
@@ -3,12 +3,22 @@
 mod routing_test;
 
 use super::*;
-use crate::format_time;
+use crate::extensions::VehicleTie;
+use crate::format::solution::activity_matcher::{try_match_point_job, JobInfo};
+use crate::format::{get_coord_index, get_job_index};
 use crate::utils::combine_error_results;
+use crate::{format_time_with_precision, parse_time_with_precision};
+use vrp_core::models::common::Schedule as CoreSchedule;
+use vrp_core::models::problem::get_setup_time;
+use vrp_core::models::solution::{Activity as CoreActivity, Route, Tour as CoreTour};
 
 /// Checks that matrix routing information is used properly.
 pub fn check_routing(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_routing_rules(context)])
+    combine_error_results(&[
+        check_routing_rules(context),
+        check_time_window_slack(context),
+        check_stop_parking(context),
+    ])
 }
 
 fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
@@ -19,10 +29,12 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
 
     context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
         let profile = context.get_vehicle_profile(&tour.vehicle_id)?;
-        let time_offset =
-            parse_time(&tour.stops.first().ok_or_else(|| "empty tour".to_string())?.schedule().departure) as i64;
+        let time_offset = parse_time_with_precision(
+            &tour.stops.first().ok_or_else(|| "empty tour".to_string())?.schedule().departure,
+            &context.precision,
+        );
 
-        let get_matrix_data = |from: &PointStop, to: &PointStop| -> Result<(i64, i64), String> {
+        let get_matrix_data = |from: &PointStop, to: &PointStop| -> Result<(f64, f64), String> {
             let from_idx = context.get_location_index(&from.location)?;
             let to_idx = context.get_location_index(&to.location)?;
             context.get_matrix_data(&profile, from_idx, to_idx)
@@ -30,7 +42,7 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
 
         //let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
         let (departure_time, total_distance) = tour.stops.windows(2).enumerate().try_fold::<_, _, Result<_, String>>(
-            (time_offset, 0),
+            (time_offset, 0.),
             |(arrival_time, total_distance), (leg_idx, stops)| {
                 let (from, to) = match stops {
                     [from, to] => (from, to),
@@ -44,7 +56,7 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
                     }
                     (_, Stop::Transit(transit)) => {
                         let duration = parse_time(&transit.time.departure) - parse_time(&transit.time.arrival);
-                        (0_i64, duration as i64, total_distance)
+                        (0., context.to_measure(duration), total_distance)
                     }
                     (Stop::Transit(_), Stop::Point(to)) => {
                         assert!(leg_idx > 0);
@@ -62,46 +74,153 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
                 let arrival_time = arrival_time + duration;
                 let total_distance = total_distance + distance;
 
-                check_stop_statistic(
-                    arrival_time,
-                    total_distance,
-                    to.schedule(),
-                    to_distance,
-                    leg_idx + 1,
-                    tour,
-                    skip_distance_check,
-                )?;
+                let statistic =
+                    StopStatistic { arrival_time, total_distance, schedule: to.schedule(), distance: to_distance };
+                check_stop_statistic(context, statistic, leg_idx + 1, tour, skip_distance_check)?;
 
-                Ok((parse_time(&to.schedule().departure) as i64, to_distance))
+                Ok((parse_time_with_precision(&to.schedule().departure, &context.precision), to_distance))
             },
         )?;
 
-        check_tour_statistic(departure_time, total_distance, time_offset, tour, skip_distance_check)
+        check_tour_statistic(context, departure_time, total_distance, time_offset, tour, skip_distance_check)
     })?;
 
     check_solution_statistic(&context.solution)
 }
 
+fn check_time_window_slack(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let slack = vehicle.profile.buffer.as_ref().and_then(|buffer| buffer.slack).unwrap_or(0.);
+
+        if slack <= 0. {
+            return Ok(());
+        }
+
+        tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity))).try_for_each(
+            |(stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let location = context.get_activity_location(stop, activity);
+                let arrival = context.get_activity_time(stop, activity).start;
+
+                let has_slack = context.visit_job(
+                    activity,
+                    &activity_type,
+                    |_, task| {
+                        task.places.iter().filter(|place| location.as_ref() == Some(&place.location)).all(|place| {
+                            place.times.as_ref().map_or(true, |times| {
+                                times
+                                    .iter()
+                                    .map(|tw| parse_time_window(tw))
+                                    .any(|tw| arrival <= tw.end && arrival.max(tw.start) <= tw.end - slack)
+                            })
+                        })
+                    },
+                    || true,
+                )?;
+
+                if has_slack {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "time window slack violation for '{}' in tour '{}', expected: not less than {}",
+                        activity.job_id, tour.vehicle_id, slack
+                    ))
+                }
+            },
+        )
+    })
+}
+
+fn check_stop_parking(context: &CheckerContext) -> Result<(), String> {
+    let job_index = get_job_index(&context.core_problem);
+    let coord_index = get_coord_index(&context.core_problem);
+
+    context.solution.tours.iter().try_for_each(|tour| {
+        let actor = context
+            .core_problem
+            .fleet
+            .actors
+            .iter()
+            .find(|actor| {
+                actor.vehicle.dimens.get_vehicle_type() == Some(&tour.type_id)
+                    && actor.vehicle.dimens.get_shift_index() == Some(tour.shift_index)
+            })
+            .ok_or_else(|| format!("cannot find vehicle type '{}' for tour {}", tour.type_id, tour.vehicle_id))?;
+        let route = Route { actor: actor.clone(), tour: CoreTour::new(actor) };
+
+        tour.stops.iter().filter_map(|stop| stop.as_point()).enumerate().try_fold(
+            None,
+            |prev_location: Option<usize>, (stop_idx, stop)| {
+                // NOTE setup time is applied when vehicle arrives to a job from a different location
+                let expected = prev_location
+                    .zip(stop.activities.first())
+                    .and_then(|(prev_location, activity)| {
+                        let JobInfo(_, single, place, time) =
+                            try_match_point_job(tour, stop, activity, job_index, coord_index).ok().flatten()?;
+                        let activity = CoreActivity {
+                            place,
+                            schedule: CoreSchedule::new(time.start, time.end),
+                            job: Some(single),
+                            commute: None,
+                        };
+
+                        Some(get_setup_time(context.core_problem.activity.as_ref(), &route, prev_location, &activity))
+                    })
+                    .unwrap_or(0.);
+                let actual =
+                    stop.parking.as_ref().map_or(0., |parking| parse_time(&parking.end) - parse_time(&parking.start));
+
+                // NOTE clustered stop's parking includes also time spent to park within the cluster
+                let is_valid = if context.clustering.is_some() {
+                    actual >= expected || context.is_same_time(expected, actual)
+                } else {
+                    context.is_same_time(expected, actual)
+                };
+
+                if is_valid {
+                    context.get_location_index(&stop.location).map(Some)
+                } else {
+                    Err(format!(
+                        "parking time mismatch for {} stop in the tour: {}, expected: '{}', got: '{}'",
+                        stop_idx, tour.vehicle_id, expected, actual
+                    ))
+                }
+            },
+        )?;
+
+        Ok(())
+    })
+}
+
+/// Keeps stop arrival time and distance calculated using routing data together with reported ones.
+struct StopStatistic<'a> {
+    arrival_time: f64,
+    total_distance: f64,
+    schedule: &'a Schedule,
+    distance: f64,
+}
+
 fn check_stop_statistic(
-    arrival_time: i64,
-    total_distance: i64,
-    schedule: &Schedule,
-    distance: i64,
+    context: &CheckerContext,
+    statistic: StopStatistic,
     stop_idx: usize,
     tour: &Tour,
     skip_distance_check: bool,
 ) -> Result<(), String> {
-    if (arrival_time - parse_time(&schedule.arrival) as i64).abs() > 1 {
+    let StopStatistic { arrival_time, total_distance, schedule, distance } = statistic;
+
+    if !context.is_same_time(arrival_time, parse_time_with_precision(&schedule.arrival, &context.precision)) {
         return Err(format!(
             "arrival time mismatch for {} stop in the tour: {}, expected: '{}', got: '{}'",
             stop_idx,
             tour.vehicle_id,
-            format_time(arrival_time as f64),
+            format_time_with_precision(arrival_time, &context.precision),
             schedule.arrival
         ));
     }
 
-    if !skip_distance_check && (total_distance - distance).abs() > 1 {
+    if !skip_distance_check && !is_same_measure(total_distance, distance) {
         return Err(format!(
             "distance mismatch for {} stop in the tour: {}, expected: '{}', got: '{}'",
             stop_idx, tour.vehicle_id, total_distance, distance,
@@ -112,38 +231,38 @@ fn check_stop_statistic(
 }
 
 fn check_tour_statistic(
-    departure_time: i64,
-    total_distance: i64,
-    time_offset: i64,
+    context: &CheckerContext,
+    departure_time: f64,
+    total_distance: f64,
+    time_offset: f64,
     tour: &Tour,
     skip_distance_check: bool,
 ) -> Result<(), String> {
-    if !skip_distance_check && (total_distance - tour.statistic.distance).abs() > 1 {
+    if !skip_distance_check && !is_same_measure(total_distance, tour.statistic.distance) {
         return Err(format!(
             "distance mismatch for tour statistic: {}, expected: '{}', got: '{}'",
             tour.vehicle_id, total_distance, tour.statistic.distance,
         ));
     }
 
-    let dispatch_at_start_correction =
-        tour.stops
-            .first()
-            .and_then(|stop| stop.activities().get(1))
-            .and_then(|activity| {
-                if activity.activity_type == "dispatch" {
-                    Some(
-                        activity.time.as_ref().map_or(0, |interval| {
-                            parse_time(&interval.end) as i64 - parse_time(&interval.start) as i64
-                        }),
-                    )
-                } else {
-                    None
-                }
-            })
-            .unwrap_or(0);
+    let dispatch_at_start_correction = tour
+        .stops
+        .first()
+        .and_then(|stop| stop.activities().get(1))
+        .and_then(|activity| {
+            if activity.activity_type == "dispatch" {
+                Some(activity.time.as_ref().map_or(0., |interval| {
+                    parse_time_with_precision(&interval.end, &context.precision)
+                        - parse_time_with_precision(&interval.start, &context.precision)
+                }))
+            } else {
+                None
+            }
+        })
+        .unwrap_or(0.);
 
     let total_duration = departure_time - time_offset + dispatch_at_start_correction;
-    if (total_duration - tour.statistic.duration).abs() > 1 {
+    if !is_same_measure(total_duration, tour.statistic.duration) {
         return Err(format!(
             "duration mismatch for tour statistic: {}, expected: '{}', got: '{}'",
             tour.vehicle_id, total_duration, tour.statistic.duration,
@@ -164,6 +283,12 @@ fn check_solution_statistic(solution: &Solution) -> Result<(), String> {
     }
 }
 
+/// Compares expected and reported durations or distances: reported values are integers, so they can differ
+/// by one due to truncation or rounding.
+fn is_same_measure(expected: f64, actual: f64) -> bool {
+    (expected - actual).abs() <= 1.
+}
+
 /// A workaround method for hre format output where distance is not defined.
 fn skip_distance_check(solution: &Solution) -> bool {
     let skip_distance_check = solution
@@ -171,7 +296,7 @@ fn skip_distance_check(solution: &Solution) -> bool {
         .iter()
         .flat_map(|tour| tour.stops.iter())
         .filter_map(|stop| stop.as_point())
-        .all(|stop| stop.distance == 0);
+        .all(|stop| stop.distance == 0.);
 
     if skip_distance_check {
         // TODO use logging lib instead of println
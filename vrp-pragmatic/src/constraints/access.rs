@@ -0,0 +1,188 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/access_test.rs"]
+mod access_test;
+
+use crate::extensions::VehicleTie;
+use hashbrown::HashMap;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::Location;
+use vrp_core::models::problem::{get_job_locations, Job, Single, Vehicle};
+
+/// Physical attributes of a vehicle.
+#[derive(Clone, Debug, Default)]
+pub struct VehicleAttributes {
+    /// Vehicle weight.
+    pub weight: Option<f64>,
+    /// Vehicle height.
+    pub height: Option<f64>,
+    /// Vehicle length.
+    pub length: Option<f64>,
+}
+
+/// Access limits of a location: max physical attributes of a vehicle allowed to visit it.
+#[derive(Clone, Debug, Default)]
+pub struct AccessLimits {
+    /// Max allowed vehicle weight.
+    pub max_weight: Option<f64>,
+    /// Max allowed vehicle height.
+    pub max_height: Option<f64>,
+    /// Max allowed vehicle length.
+    pub max_length: Option<f64>,
+}
+
+impl AccessLimits {
+    /// Checks whether vehicle with given attributes is allowed. Unknown attribute is not restricted.
+    pub fn allows(&self, attributes: &VehicleAttributes) -> bool {
+        let is_allowed = |limit: Option<f64>, value: Option<f64>| match (limit, value) {
+            (Some(limit), Some(value)) => value <= limit,
+            _ => true,
+        };
+
+        is_allowed(self.max_weight, attributes.weight)
+            && is_allowed(self.max_height, attributes.height)
+            && is_allowed(self.max_length, attributes.length)
+    }
+
+    /// Combines two limits keeping the most restrictive values.
+    pub fn combine(&self, other: &AccessLimits) -> AccessLimits {
+        let combine = |left: Option<f64>, right: Option<f64>| match (left, right) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (left, right) => left.or(right),
+        };
+
+        AccessLimits {
+            max_weight: combine(self.max_weight, other.max_weight),
+            max_height: combine(self.max_height, other.max_height),
+            max_length: combine(self.max_length, other.max_length),
+        }
+    }
+
+    /// Checks whether these limits are not more restrictive than other ones.
+    fn is_within(&self, other: &AccessLimits) -> bool {
+        let is_within = |limit: Option<f64>, other: Option<f64>| match (limit, other) {
+            (Some(limit), Some(other)) => other <= limit,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+
+        is_within(self.max_weight, other.max_weight)
+            && is_within(self.max_height, other.max_height)
+            && is_within(self.max_length, other.max_length)
+    }
+}
+
+/// A vehicle access module which allows vehicles to visit only locations which access limits
+/// are not violated by vehicle physical attributes.
+pub struct AccessModule {
+    code: i32,
+    limits: Arc<HashMap<Location, AccessLimits>>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl AccessModule {
+    /// Creates a new instance of `AccessModule` using location access limits.
+    pub fn new(limits: HashMap<Location, AccessLimits>, code: i32) -> Self {
+        let limits = Arc::new(limits);
+
+        Self {
+            code,
+            limits: limits.clone(),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(AccessHardRouteConstraint { code, limits: limits.clone() })),
+                ConstraintVariant::HardActivity(Arc::new(AccessHardActivityConstraint { code, limits })),
+            ],
+            keys: vec![],
+        }
+    }
+
+    fn get_job_limits(&self, job: &Job) -> AccessLimits {
+        get_job_locations(job)
+            .flatten()
+            .filter_map(|location| self.limits.get(&location))
+            .fold(AccessLimits::default(), |acc, limits| acc.combine(limits))
+    }
+}
+
+impl ConstraintModule for AccessModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        // NOTE merged job should not be more restrictive than source one
+        if self.get_job_limits(&candidate).is_within(&self.get_job_limits(&source)) {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct AccessHardRouteConstraint {
+    code: i32,
+    limits: Arc<HashMap<Location, AccessLimits>>,
+}
+
+impl HardRouteConstraint for AccessHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let vehicle = ctx.route.actor.vehicle.as_ref();
+
+        // NOTE single job is accessible if at least one of its alternative places is accessible
+        let is_accessible = |single: &Single| {
+            single.places.iter().any(|place| place.location.map_or(true, |l| is_allowed(&self.limits, vehicle, l)))
+        };
+
+        let is_accessible = match job {
+            Job::Single(single) => is_accessible(single),
+            Job::Multi(multi) => multi.jobs.iter().all(|single| is_accessible(single)),
+        };
+
+        if is_accessible {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct AccessHardActivityConstraint {
+    code: i32,
+    limits: Arc<HashMap<Location, AccessLimits>>,
+}
+
+impl HardActivityConstraint for AccessHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let vehicle = route_ctx.route.actor.vehicle.as_ref();
+
+        if is_allowed(&self.limits, vehicle, activity_ctx.target.place.location) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+fn is_allowed(limits: &HashMap<Location, AccessLimits>, vehicle: &Vehicle, location: Location) -> bool {
+    match (limits.get(&location), vehicle.dimens.get_vehicle_attributes()) {
+        (Some(limits), Some(attributes)) => limits.allows(attributes),
+        _ => true,
+    }
+}
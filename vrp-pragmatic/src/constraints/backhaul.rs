@@ -0,0 +1,142 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/backhaul_test.rs"]
+mod backhaul_test;
+
+use crate::extensions::{JobTie, VehicleTie};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::problem::{Job, Multi, Single};
+use vrp_core::models::solution::Activity;
+
+/// A backhaul module provides the way to serve all deliveries (linehauls) before any pickup
+/// (backhaul) in the tour of vehicle with backhaul policy. Only jobs with static demand are
+/// considered: pickup and delivery jobs are not affected.
+pub struct BackhaulModule {
+    constraints: Vec<ConstraintVariant>,
+    state_key: i32,
+    keys: Vec<i32>,
+}
+
+impl BackhaulModule {
+    /// Creates a new instance of `BackhaulModule`.
+    pub fn new(code: i32, state_key: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(BackhaulHardActivityConstraint {
+                code,
+                state_key,
+            }))],
+            state_key,
+            keys: vec![state_key],
+        }
+    }
+}
+
+impl ConstraintModule for BackhaulModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if !is_backhaul_route(route_ctx) {
+            return;
+        }
+
+        let (first_backhaul, last_linehaul) = route_ctx.route.tour.all_activities().enumerate().fold(
+            (None, None),
+            |(first_backhaul, last_linehaul), (idx, activity)| match get_haul_type(activity) {
+                Some(HaulType::Backhaul) => (first_backhaul.or(Some(idx)), last_linehaul),
+                Some(HaulType::Linehaul) => (first_backhaul, Some(idx)),
+                None => (first_backhaul, last_linehaul),
+            },
+        );
+
+        route_ctx.state_mut().put_route_state::<HaulIndices>(self.state_key, (first_backhaul, last_linehaul));
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Keeps index of the first backhaul and the last linehaul activities in the tour.
+type HaulIndices = (Option<usize>, Option<usize>);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HaulType {
+    Linehaul,
+    Backhaul,
+}
+
+struct BackhaulHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+}
+
+impl HardActivityConstraint for BackhaulHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        if !is_backhaul_route(route_ctx) {
+            return None;
+        }
+
+        let (first_backhaul, last_linehaul) =
+            route_ctx.state.get_route_state::<HaulIndices>(self.state_key).cloned().unwrap_or((None, None));
+
+        match get_haul_type(activity_ctx.target) {
+            // NOTE linehaul cannot be inserted after any backhaul, so there is no need to check further
+            Some(HaulType::Linehaul) if first_backhaul.map_or(false, |idx| idx <= activity_ctx.index) => {
+                Some(ActivityConstraintViolation { code: self.code, stopped: true })
+            }
+            Some(HaulType::Backhaul) if last_linehaul.map_or(false, |idx| idx > activity_ctx.index) => {
+                Some(ActivityConstraintViolation { code: self.code, stopped: false })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_backhaul_route(route_ctx: &RouteContext) -> bool {
+    route_ctx.route.actor.vehicle.dimens.get_backhaul().unwrap_or(false)
+}
+
+fn get_haul_type(activity: &Activity) -> Option<HaulType> {
+    let single = activity.job.as_ref()?;
+
+    if is_dynamic_demand(single) {
+        return None;
+    }
+
+    match single.dimens.get_job_type().map(|job_type| job_type.as_str()) {
+        Some("delivery") => Some(HaulType::Linehaul),
+        Some("pickup") => Some(HaulType::Backhaul),
+        _ => None,
+    }
+}
+
+fn is_dynamic_demand(single: &Single) -> bool {
+    Multi::roots(single).map_or(false, |multi| {
+        let has_type =
+            |job_type: &str| multi.jobs.iter().any(|job| job.dimens.get_job_type().map_or(false, |t| t == job_type));
+
+        has_type("pickup") && has_type("delivery")
+    })
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/consistency_test.rs"]
+mod consistency_test;
+
+use crate::extensions::{JobTie, VehicleTie};
+use hashbrown::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use vrp_core::models::problem::{Job, TargetConstraint, TargetObjective};
+use vrp_core::rosomaxa::prelude::Objective;
+use vrp_core::solver::objectives::get_route_penalty;
+
+/// A consistency module provides the way to serve jobs with the same consistency key by the same
+/// vehicle. Unlike group, it does not force jobs to be in the same tour: jobs can be served in different
+/// shifts, and amount of distinct vehicles per key is minimized by the objective.
+pub struct ConsistencyModule {
+    merge_code: i32,
+    constraints: Vec<ConstraintVariant>,
+    state_key: i32,
+    keys: Vec<i32>,
+}
+
+impl ConsistencyModule {
+    /// Creates _(constraint, objective)_  type pair which provides logic to minimize amount of
+    /// distinct vehicles serving jobs with the same consistency key.
+    pub fn new_minimized(merge_code: i32, state_key: i32) -> (TargetConstraint, TargetObjective) {
+        let constraint = Self {
+            merge_code,
+            constraints: vec![ConstraintVariant::SoftRoute(Arc::new(ConsistencySoftRouteConstraint { state_key }))],
+            state_key,
+            keys: vec![state_key],
+        };
+
+        (Arc::new(constraint), Arc::new(ConsistencyObjective {}))
+    }
+}
+
+impl ConstraintModule for ConsistencyModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if let Some(consistency) = job.dimens().get_job_consistency() {
+            let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+
+            let mut consistencies = get_consistencies(route_ctx);
+            consistencies.insert(consistency.clone());
+
+            route_ctx.state_mut().put_route_state(self.state_key, consistencies);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let consistencies = get_consistencies(route_ctx);
+            route_ctx.state_mut().put_route_state(self.state_key, consistencies);
+        });
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (source.dimens().get_job_consistency(), candidate.dimens().get_job_consistency()) {
+            (None, None) => Ok(source),
+            (Some(s_consistency), Some(c_consistency)) if s_consistency == c_consistency => Ok(source),
+            _ => Err(self.merge_code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct ConsistencySoftRouteConstraint {
+    state_key: i32,
+}
+
+impl SoftRouteConstraint for ConsistencySoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        let vehicle_id = route_ctx.route.actor.vehicle.dimens.get_vehicle_id();
+
+        job.dimens()
+            .get_job_consistency()
+            .map(|consistency| {
+                let vehicle_ids = solution_ctx
+                    .routes
+                    .iter()
+                    .filter(|route_ctx| {
+                        route_ctx
+                            .state
+                            .get_route_state::<HashSet<String>>(self.state_key)
+                            .map_or(false, |consistencies| consistencies.contains(consistency))
+                    })
+                    .filter_map(|route_ctx| route_ctx.route.actor.vehicle.dimens.get_vehicle_id())
+                    .collect::<HashSet<_>>();
+
+                let is_new_vehicle = vehicle_id.map_or(true, |vehicle_id| !vehicle_ids.contains(vehicle_id));
+
+                if !vehicle_ids.is_empty() && is_new_vehicle {
+                    get_route_penalty(route_ctx)
+                } else {
+                    0.
+                }
+            })
+            .unwrap_or(0.)
+    }
+}
+
+struct ConsistencyObjective {}
+
+impl Objective for ConsistencyObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        get_inconsistency(solution.solution.routes.as_slice()) as f64
+    }
+}
+
+/// Returns total amount of extra vehicles serving jobs with the same consistency key.
+fn get_inconsistency(routes: &[RouteContext]) -> usize {
+    routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let vehicle_id = route_ctx.route.actor.vehicle.dimens.get_vehicle_id();
+            get_consistencies(route_ctx).into_iter().map(move |consistency| (consistency, vehicle_id))
+        })
+        .fold(HashMap::<_, HashSet<_>>::new(), |mut acc, (consistency, vehicle_id)| {
+            acc.entry(consistency).or_default().insert(vehicle_id);
+            acc
+        })
+        .values()
+        .map(|vehicle_ids| vehicle_ids.len().saturating_sub(1))
+        .sum()
+}
+
+fn get_consistencies(route_ctx: &RouteContext) -> HashSet<String> {
+    route_ctx.route.tour.jobs().filter_map(|job| job.dimens().get_job_consistency().cloned()).collect()
+}
@@ -0,0 +1,210 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/fleet_limits_test.rs"]
+mod fleet_limits_test;
+
+use crate::extensions::VehicleTie;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use vrp_core::models::common::Cost;
+use vrp_core::models::problem::{Job, TargetObjective, Vehicle};
+use vrp_core::rosomaxa::prelude::Objective;
+use vrp_core::solver::objectives::DEFAULT_ROUTE_PENALTY;
+
+/// Specifies vehicles affected by fleet limit.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FleetLimitTarget {
+    /// Vehicles of the given type.
+    VehicleType(String),
+    /// Vehicles with the given tag.
+    VehicleTag(String),
+}
+
+/// Specifies a limit on amount of used tours for a group of vehicles.
+#[derive(Clone, Debug)]
+pub struct FleetLimit {
+    /// Vehicles affected by the limit.
+    pub target: FleetLimitTarget,
+    /// Min amount of used tours.
+    pub min_tours: Option<usize>,
+    /// Max amount of used tours.
+    pub max_tours: Option<usize>,
+}
+
+impl FleetLimit {
+    /// Checks whether limit is applicable for given vehicle.
+    pub fn is_applicable(&self, vehicle: &Vehicle) -> bool {
+        match &self.target {
+            FleetLimitTarget::VehicleType(type_id) => {
+                vehicle.dimens.get_vehicle_type().map_or(false, |vehicle_type| vehicle_type == type_id)
+            }
+            FleetLimitTarget::VehicleTag(tag) => {
+                vehicle.dimens.get_vehicle_tags().map_or(false, |tags| tags.contains(tag))
+            }
+        }
+    }
+
+    /// Returns amount of used tours (routes with at least one job) affected by the limit.
+    pub fn get_used_tours(&self, routes: &[RouteContext]) -> usize {
+        routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route.tour.has_jobs())
+            .filter(|route_ctx| self.is_applicable(route_ctx.route.actor.vehicle.as_ref()))
+            .count()
+    }
+
+    /// Returns amount of tours missing to reach min tours limit.
+    pub fn get_shortage(&self, routes: &[RouteContext]) -> usize {
+        self.min_tours.map_or(0, |min_tours| min_tours.saturating_sub(self.get_used_tours(routes)))
+    }
+}
+
+/// A fleet limits module provides the way to limit amount of used tours per vehicle type or tag:
+/// max limit is enforced by hard constraint, min limit is promoted by soft constraint which rewards
+/// opening a new tour and by the objective which counts missing tours.
+pub struct FleetLimitsModule {
+    constraints: Vec<ConstraintVariant>,
+    limits: Arc<Vec<FleetLimit>>,
+    state_key: i32,
+    keys: Vec<i32>,
+}
+
+impl FleetLimitsModule {
+    /// Creates a new instance of `FleetLimitsModule`.
+    pub fn new(limits: Arc<Vec<FleetLimit>>, code: i32, state_key: i32) -> Self {
+        Self {
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(FleetLimitsHardRouteConstraint {
+                    limits: limits.clone(),
+                    code,
+                    state_key,
+                })),
+                ConstraintVariant::SoftRoute(Arc::new(FleetLimitsSoftRouteConstraint {
+                    limits: limits.clone(),
+                    state_key,
+                })),
+            ],
+            limits,
+            state_key,
+            keys: vec![state_key],
+        }
+    }
+
+    /// Creates an objective which minimizes total amount of tours missing to reach min tours limits.
+    pub fn new_objective(limits: Arc<Vec<FleetLimit>>) -> TargetObjective {
+        Arc::new(FleetLimitsObjective { limits })
+    }
+
+    fn update_used_tours(&self, solution_ctx: &mut SolutionContext) {
+        let used_tours =
+            self.limits.iter().map(|limit| limit.get_used_tours(solution_ctx.routes.as_slice())).collect::<Vec<_>>();
+
+        solution_ctx.state.insert(self.state_key, Arc::new(used_tours));
+    }
+}
+
+impl ConstraintModule for FleetLimitsModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.update_used_tours(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_used_tours(solution_ctx);
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct FleetLimitsHardRouteConstraint {
+    limits: Arc<Vec<FleetLimit>>,
+    code: i32,
+    state_key: i32,
+}
+
+impl HardRouteConstraint for FleetLimitsHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        _: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        if route_ctx.route.tour.has_jobs() {
+            return None;
+        }
+
+        let is_max_reached = get_applicable_limits(self.limits.as_slice(), solution_ctx, route_ctx, self.state_key)
+            .any(|(limit, used_tours)| limit.max_tours.map_or(false, |max_tours| used_tours >= max_tours));
+
+        if is_max_reached {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+struct FleetLimitsSoftRouteConstraint {
+    limits: Arc<Vec<FleetLimit>>,
+    state_key: i32,
+}
+
+impl SoftRouteConstraint for FleetLimitsSoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, _: &Job) -> Cost {
+        if route_ctx.route.tour.has_jobs() {
+            return 0.;
+        }
+
+        let is_min_not_reached = get_applicable_limits(self.limits.as_slice(), solution_ctx, route_ctx, self.state_key)
+            .any(|(limit, used_tours)| limit.min_tours.map_or(false, |min_tours| used_tours < min_tours));
+
+        if is_min_not_reached {
+            -DEFAULT_ROUTE_PENALTY
+        } else {
+            0.
+        }
+    }
+}
+
+struct FleetLimitsObjective {
+    limits: Arc<Vec<FleetLimit>>,
+}
+
+impl Objective for FleetLimitsObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.limits.iter().map(|limit| limit.get_shortage(solution.solution.routes.as_slice())).sum::<usize>() as f64
+    }
+}
+
+fn get_applicable_limits<'a>(
+    limits: &'a [FleetLimit],
+    solution_ctx: &'a SolutionContext,
+    route_ctx: &'a RouteContext,
+    state_key: i32,
+) -> impl Iterator<Item = (&'a FleetLimit, usize)> + 'a {
+    let used_tours = solution_ctx.state.get(&state_key).and_then(|s| s.downcast_ref::<Vec<usize>>());
+
+    limits.iter().enumerate().filter(move |(_, limit)| limit.is_applicable(route_ctx.route.actor.vehicle.as_ref())).map(
+        move |(idx, limit)| {
+            let used_tours = used_tours
+                .and_then(|used_tours| used_tours.get(idx).cloned())
+                .unwrap_or_else(|| limit.get_used_tours(solution_ctx.routes.as_slice()));
+
+            (limit, used_tours)
+        },
+    )
+}
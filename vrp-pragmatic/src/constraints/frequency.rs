@@ -0,0 +1,172 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/frequency_test.rs"]
+mod frequency_test;
+
+use crate::extensions::JobTie;
+use hashbrown::HashSet;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext, UnassignmentInfo};
+use vrp_core::models::common::Timestamp;
+use vrp_core::models::problem::Job;
+
+const SECONDS_PER_DAY: f64 = 86400.;
+
+/// Specifies visit frequency settings shared by all occurrences of the same periodic job.
+#[derive(Clone, Debug)]
+pub struct JobFrequency {
+    /// A key which identifies occurrences of the same job.
+    pub key: String,
+    /// A minimum difference in days between two visits.
+    pub min_gap_days: usize,
+    /// Day indices when visits are allowed.
+    pub allowed_days: Option<HashSet<usize>>,
+}
+
+/// A frequency module provides the way to visit the same job several times within multi-day planning
+/// horizon: each visit is represented by a separate job occurrence which is served on a vehicle shift
+/// day. Occurrences are either assigned all together, respecting allowed days and a minimum gap
+/// between visits, or not assigned at all.
+pub struct FrequencyModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    state_key: i32,
+    keys: Vec<i32>,
+}
+
+impl FrequencyModule {
+    /// Creates a new instance of `FrequencyModule`. Day indices are counted from `horizon_start`
+    /// timestamp which is expected to be a start of the first day of planning horizon.
+    pub fn new(horizon_start: Timestamp, code: i32, state_key: i32) -> Self {
+        Self {
+            code,
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(FrequencyHardRouteConstraint {
+                horizon_start,
+                code,
+                state_key,
+            }))],
+            state_key,
+            keys: vec![state_key],
+        }
+    }
+
+    /// Removes assigned occurrences of the jobs which have at least one occurrence unassigned.
+    fn remove_incomplete_visits(&self, solution_ctx: &mut SolutionContext) {
+        // NOTE jobs which are still in required list can be assigned later
+        let incomplete = solution_ctx
+            .unassigned
+            .keys()
+            .filter(|job| !solution_ctx.required.contains(job))
+            .filter_map(|job| job.dimens().get_job_frequency().map(|frequency| frequency.key.clone()))
+            .collect::<HashSet<_>>();
+
+        if incomplete.is_empty() {
+            return;
+        }
+
+        let jobs_to_remove = solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+            .filter(|job| !solution_ctx.locked.contains(job))
+            .filter(|job| {
+                job.dimens().get_job_frequency().map_or(false, |frequency| incomplete.contains(&frequency.key))
+            })
+            .collect::<Vec<_>>();
+
+        jobs_to_remove.into_iter().for_each(|job| {
+            solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.route.tour.contains(&job)).for_each(
+                |route_ctx| {
+                    route_ctx.route_mut().tour.remove(&job);
+                },
+            );
+            solution_ctx.unassigned.insert(job, UnassignmentInfo::Simple(self.code));
+        });
+    }
+}
+
+impl ConstraintModule for FrequencyModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if let Some(frequency) = job.dimens().get_job_frequency() {
+            let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+
+            let mut keys = get_frequency_keys(route_ctx);
+            keys.insert(frequency.key.clone());
+
+            route_ctx.state_mut().put_route_state(self.state_key, keys);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.remove_incomplete_visits(solution_ctx);
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let keys = get_frequency_keys(route_ctx);
+            route_ctx.state_mut().put_route_state(self.state_key, keys);
+        });
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        match (source.dimens().get_job_frequency(), candidate.dimens().get_job_frequency()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct FrequencyHardRouteConstraint {
+    horizon_start: Timestamp,
+    code: i32,
+    state_key: i32,
+}
+
+impl HardRouteConstraint for FrequencyHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        job.dimens().get_job_frequency().and_then(|frequency| {
+            let day = get_route_day(route_ctx, self.horizon_start);
+
+            let is_allowed_day = frequency.allowed_days.as_ref().map_or(true, |days| days.contains(&day));
+
+            let has_close_visit = solution_ctx
+                .routes
+                .iter()
+                .filter(|other| {
+                    other
+                        .state
+                        .get_route_state::<HashSet<String>>(self.state_key)
+                        .map_or(false, |keys| keys.contains(&frequency.key))
+                })
+                .any(|other| get_route_day(other, self.horizon_start).abs_diff(day) < frequency.min_gap_days);
+
+            if is_allowed_day && !has_close_visit {
+                None
+            } else {
+                Some(RouteConstraintViolation { code: self.code })
+            }
+        })
+    }
+}
+
+fn get_route_day(route_ctx: &RouteContext, horizon_start: Timestamp) -> usize {
+    ((route_ctx.route.actor.detail.time.start - horizon_start) / SECONDS_PER_DAY).floor().max(0.) as usize
+}
+
+fn get_frequency_keys(route_ctx: &RouteContext) -> HashSet<String> {
+    route_ctx.route.tour.jobs().filter_map(|job| job.dimens().get_job_frequency().map(|f| f.key.clone())).collect()
+}
@@ -25,6 +25,21 @@ pub const AREA_ORDER_KEY: i32 = 1005;
 /// A key which tracks reload resource consumption state.
 pub const RELOAD_RESOURCE_KEY: i32 = 1006;
 
+/// A key which tracks plan changes state.
+pub const PLAN_CHANGES_KEY: i32 = 1007;
+
+/// A key which tracks job consistency state.
+pub const CONSISTENCY_KEY: i32 = 1008;
+
+/// A key which tracks job frequency state.
+pub const FREQUENCY_KEY: i32 = 1009;
+
+/// A key which tracks backhaul state.
+pub const BACKHAUL_KEY: i32 = 1010;
+
+/// A key which tracks fleet limits state.
+pub const FLEET_LIMITS_KEY: i32 = 1011;
+
 fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
 where
     F: Fn(&Arc<Single>) -> bool,
@@ -52,18 +67,33 @@ fn is_single_belongs_to_route(ctx: &RouteContext, single: &Arc<Single>) -> bool
     is_correct_vehicle(&ctx.route, vehicle_id, shift_index)
 }
 
+mod access;
+pub use self::access::{AccessLimits, AccessModule, VehicleAttributes};
+
 mod areas;
 pub use self::areas::AreaModule;
 
+mod backhaul;
+pub use self::backhaul::BackhaulModule;
+
 mod breaks;
 pub use self::breaks::{BreakModule, BreakPolicy};
 
 mod compatibility;
 pub use self::compatibility::CompatibilityModule;
 
+mod consistency;
+pub use self::consistency::ConsistencyModule;
+
 mod dispatch;
 pub use self::dispatch::DispatchModule;
 
+mod fleet_limits;
+pub use self::fleet_limits::{FleetLimit, FleetLimitTarget, FleetLimitsModule};
+
+mod frequency;
+pub use self::frequency::{FrequencyModule, JobFrequency};
+
 mod groups;
 pub use self::groups::GroupModule;
 
@@ -68,7 +68,7 @@ impl CoordIndex {
     pub fn add(&mut self, location: &Location) {
         if self.direct_index.get(location).is_none() {
             let value = match location {
-                Location::Coordinate { lat: _, lng: _ } => self.direct_index.len(),
+                Location::Coordinate { lat: _, lng: _ } | Location::Point { x: _, y: _ } => self.direct_index.len(),
                 Location::Reference { index } => *index,
             };
 
@@ -99,12 +99,16 @@ impl CoordIndex {
         self.reverse_index.keys().max().cloned()
     }
 
-    /// Returns types of locations in form (has_coordinates, has_indices).
-    pub fn get_used_types(&self) -> (bool, bool) {
-        self.direct_index.iter().fold((false, false), |(has_coordinates, has_indices), (location, _)| match location {
-            Location::Coordinate { lat: _, lng: _ } => (true, has_indices),
-            Location::Reference { index: _ } => (has_coordinates, true),
-        })
+    /// Returns types of locations in form (has_coordinates, has_points, has_indices).
+    pub fn get_used_types(&self) -> (bool, bool, bool) {
+        self.direct_index.iter().fold(
+            (false, false, false),
+            |(has_coordinates, has_points, has_indices), (location, _)| match location {
+                Location::Coordinate { lat: _, lng: _ } => (true, has_points, has_indices),
+                Location::Point { x: _, y: _ } => (has_coordinates, true, has_indices),
+                Location::Reference { index: _ } => (has_coordinates, has_points, true),
+            },
+        )
     }
 }
 
@@ -116,6 +120,9 @@ impl PartialEq for Location {
             (Location::Coordinate { lat: l_lat, lng: l_lng }, Location::Coordinate { lat: r_lat, lng: r_lng }) => {
                 (l_lat - r_lat).abs() < f64::EPSILON && (l_lng - r_lng).abs() < f64::EPSILON
             }
+            (Location::Point { x: l_x, y: l_y }, Location::Point { x: r_x, y: r_y }) => {
+                (l_x - r_x).abs() < f64::EPSILON && (l_y - r_y).abs() < f64::EPSILON
+            }
             (Location::Reference { index: left }, Location::Reference { index: right }) => left == right,
             _ => false,
         }
@@ -129,6 +136,10 @@ impl Hash for Location {
                 state.write_u64(lat.to_bits());
                 state.write_u64(lng.to_bits());
             }
+            Location::Point { x, y } => {
+                state.write_u64(x.to_bits());
+                state.write_u64(y.to_bits());
+            }
             Location::Reference { index } => {
                 state.write_usize(*index);
             }
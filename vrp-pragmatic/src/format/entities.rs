@@ -1,8 +1,8 @@
 //! Specifies different entities as extension points on Dimensions type.
 
-use crate::constraints::{BreakPolicy, JobSkills};
+use crate::constraints::{BreakPolicy, JobFrequency, JobSkills, VehicleAttributes};
 use hashbrown::{HashMap, HashSet};
-use vrp_core::models::common::{Dimensions, ValueDimension};
+use vrp_core::models::common::{Dimensions, Duration, Timestamp, ValueDimension};
 
 /// Specifies vehicle entity.
 pub trait VehicleTie {
@@ -26,6 +26,11 @@ pub trait VehicleTie {
     /// Sets vehicle's skills set.
     fn set_vehicle_skills(&mut self, skills: HashSet<String>) -> &mut Self;
 
+    /// Gets vehicle's tags.
+    fn get_vehicle_tags(&self) -> Option<&HashSet<String>>;
+    /// Sets vehicle's tags.
+    fn set_vehicle_tags(&mut self, tags: HashSet<String>) -> &mut Self;
+
     /// Gets vehicle's area.
     fn get_areas(&self) -> Option<&HashMap<String, (usize, f64)>>;
     /// Sets vehicle's area.
@@ -35,6 +40,21 @@ pub trait VehicleTie {
     fn get_tour_size(&self) -> Option<usize>;
     /// Sets vehicle's tour size.
     fn set_tour_size(&mut self, tour_size: usize) -> &mut Self;
+
+    /// Gets vehicle's min tour size.
+    fn get_min_tour_size(&self) -> Option<usize>;
+    /// Sets vehicle's min tour size.
+    fn set_min_tour_size(&mut self, min_tour_size: usize) -> &mut Self;
+
+    /// Gets vehicle's backhaul policy.
+    fn get_backhaul(&self) -> Option<bool>;
+    /// Sets vehicle's backhaul policy.
+    fn set_backhaul(&mut self, backhaul: bool) -> &mut Self;
+
+    /// Gets vehicle's physical attributes.
+    fn get_vehicle_attributes(&self) -> Option<&VehicleAttributes>;
+    /// Sets vehicle's physical attributes.
+    fn set_vehicle_attributes(&mut self, attributes: VehicleAttributes) -> &mut Self;
 }
 
 impl VehicleTie for Dimensions {
@@ -74,6 +94,15 @@ impl VehicleTie for Dimensions {
         self
     }
 
+    fn get_vehicle_tags(&self) -> Option<&HashSet<String>> {
+        self.get_value("vehicle_tags")
+    }
+
+    fn set_vehicle_tags(&mut self, tags: HashSet<String>) -> &mut Self {
+        self.set_value("vehicle_tags", tags);
+        self
+    }
+
     fn get_areas(&self) -> Option<&HashMap<String, (usize, f64)>> {
         self.get_value("areas")
     }
@@ -91,6 +120,33 @@ impl VehicleTie for Dimensions {
         self.set_value("tour_size", tour_size);
         self
     }
+
+    fn get_min_tour_size(&self) -> Option<usize> {
+        self.get_value("min_tour_size").cloned()
+    }
+
+    fn set_min_tour_size(&mut self, min_tour_size: usize) -> &mut Self {
+        self.set_value("min_tour_size", min_tour_size);
+        self
+    }
+
+    fn get_backhaul(&self) -> Option<bool> {
+        self.get_value("backhaul").cloned()
+    }
+
+    fn set_backhaul(&mut self, backhaul: bool) -> &mut Self {
+        self.set_value("backhaul", backhaul);
+        self
+    }
+
+    fn get_vehicle_attributes(&self) -> Option<&VehicleAttributes> {
+        self.get_value("vehicle_attributes")
+    }
+
+    fn set_vehicle_attributes(&mut self, attributes: VehicleAttributes) -> &mut Self {
+        self.set_value("vehicle_attributes", attributes);
+        self
+    }
 }
 
 /// Specifies job entity.
@@ -110,6 +166,12 @@ pub trait JobTie {
     /// Sets job place tags.
     fn set_place_tags(&mut self, tags: Option<Vec<(usize, String)>>) -> &mut Self;
 
+    /// Gets job place parkings: a pair of place index and parking (stop setup) time. Only jobs from the plan
+    /// have this property set, even when it is empty.
+    fn get_place_parkings(&self) -> Option<&Vec<(usize, Duration)>>;
+    /// Sets job place parkings.
+    fn set_place_parkings(&mut self, parkings: Option<Vec<(usize, Duration)>>) -> &mut Self;
+
     /// Gets job order.
     fn get_job_order(&self) -> Option<i32>;
     /// Sets job order.
@@ -120,6 +182,16 @@ pub trait JobTie {
     /// Sets job value.
     fn set_job_value(&mut self, value: Option<f64>) -> &mut Self;
 
+    /// Gets time dependent job value as a list of (time, value) points.
+    fn get_job_value_curve(&self) -> Option<&Vec<(Timestamp, f64)>>;
+    /// Sets time dependent job value.
+    fn set_job_value_curve(&mut self, curve: Option<Vec<(Timestamp, f64)>>) -> &mut Self;
+
+    /// Gets job priority.
+    fn get_job_priority(&self) -> Option<usize>;
+    /// Sets job priority.
+    fn set_job_priority(&mut self, priority: Option<usize>) -> &mut Self;
+
     /// Gets job group.
     fn get_job_group(&self) -> Option<&String>;
     /// Sets job group.
@@ -130,6 +202,16 @@ pub trait JobTie {
     /// Sets job compatibility.
     fn set_job_compatibility(&mut self, compatibility: Option<String>) -> &mut Self;
 
+    /// Gets job consistency key.
+    fn get_job_consistency(&self) -> Option<&String>;
+    /// Sets job consistency key.
+    fn set_job_consistency(&mut self, consistency: Option<String>) -> &mut Self;
+
+    /// Gets job visit frequency.
+    fn get_job_frequency(&self) -> Option<&JobFrequency>;
+    /// Sets job visit frequency.
+    fn set_job_frequency(&mut self, frequency: Option<JobFrequency>) -> &mut Self;
+
     /// Gets job (activity) type.
     fn get_job_type(&self) -> Option<&String>;
     /// Sets job (activity) type
@@ -174,6 +256,20 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_place_parkings(&self) -> Option<&Vec<(usize, Duration)>> {
+        self.get_value("job_parkings")
+    }
+
+    fn set_place_parkings(&mut self, parkings: Option<Vec<(usize, Duration)>>) -> &mut Self {
+        if let Some(parkings) = parkings {
+            self.set_value("job_parkings", parkings);
+        } else {
+            self.remove("job_parkings");
+        }
+
+        self
+    }
+
     fn get_job_order(&self) -> Option<i32> {
         self.get_value("job_order").cloned()
     }
@@ -202,6 +298,34 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_value_curve(&self) -> Option<&Vec<(Timestamp, f64)>> {
+        self.get_value("job_value_curve")
+    }
+
+    fn set_job_value_curve(&mut self, curve: Option<Vec<(Timestamp, f64)>>) -> &mut Self {
+        if let Some(curve) = curve {
+            self.set_value("job_value_curve", curve);
+        } else {
+            self.remove("job_value_curve");
+        }
+
+        self
+    }
+
+    fn get_job_priority(&self) -> Option<usize> {
+        self.get_value("job_priority").cloned()
+    }
+
+    fn set_job_priority(&mut self, priority: Option<usize>) -> &mut Self {
+        if let Some(priority) = priority {
+            self.set_value("job_priority", priority);
+        } else {
+            self.remove("job_priority");
+        }
+
+        self
+    }
+
     fn get_job_group(&self) -> Option<&String> {
         self.get_value("job_group")
     }
@@ -230,6 +354,34 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_consistency(&self) -> Option<&String> {
+        self.get_value("job_consistency")
+    }
+
+    fn set_job_consistency(&mut self, consistency: Option<String>) -> &mut Self {
+        if let Some(consistency) = consistency {
+            self.set_value("job_consistency", consistency);
+        } else {
+            self.remove("job_consistency");
+        }
+
+        self
+    }
+
+    fn get_job_frequency(&self) -> Option<&JobFrequency> {
+        self.get_value("job_frequency")
+    }
+
+    fn set_job_frequency(&mut self, frequency: Option<JobFrequency>) -> &mut Self {
+        if let Some(frequency) = frequency {
+            self.set_value("job_frequency", frequency);
+        } else {
+            self.remove("job_frequency");
+        }
+
+        self
+    }
+
     fn get_job_type(&self) -> Option<&String> {
         self.get_value("job_type")
     }
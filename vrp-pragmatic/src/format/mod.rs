@@ -4,6 +4,8 @@
 
 extern crate serde_json;
 
+use crate::constraints::FleetLimit;
+use crate::format::problem::Precision;
 use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -30,6 +32,13 @@ pub enum Location {
         /// Longitude.
         lng: f64,
     },
+    /// A location type represented by planar coordinates, e.g. in meters on a warehouse floor.
+    Point {
+        /// X coordinate.
+        x: f64,
+        /// Y coordinate.
+        y: f64,
+    },
     /// A location type represented by index reference in routing matrix.
     Reference {
         /// An index in routing matrix.
@@ -43,6 +52,11 @@ impl Location {
         Self::Coordinate { lat, lng }
     }
 
+    /// Creates a new `[Location]` as planar point.
+    pub fn new_point(x: f64, y: f64) -> Self {
+        Self::Point { x, y }
+    }
+
     /// Creates a new `[Location]` as index reference.
     pub fn new_reference(index: usize) -> Self {
         Self::Reference { index }
@@ -61,6 +75,7 @@ impl fmt::Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Location::Coordinate { lat, lng } => write!(f, "lat={}, lng={}", lat, lng),
+            Location::Point { x, y } => write!(f, "x={}, y={}", x, y),
             Location::Reference { index } => write!(f, "index={}", index),
         }
     }
@@ -135,6 +150,13 @@ const TOUR_ORDER_CONSTRAINT_CODE: i32 = 12;
 const GROUP_CONSTRAINT_CODE: i32 = 13;
 const COMPATIBILITY_CONSTRAINT_CODE: i32 = 14;
 const RELOAD_RESOURCE_CONSTRAINT_CODE: i32 = 15;
+const FREQUENCY_CONSTRAINT_CODE: i32 = 16;
+const BACKHAUL_CONSTRAINT_CODE: i32 = 17;
+const FLEET_LIMITS_CONSTRAINT_CODE: i32 = 18;
+const MAX_WAITING_TIME_CONSTRAINT_CODE: i32 = 19;
+const VEHICLE_ACCESS_CONSTRAINT_CODE: i32 = 20;
+const MIN_TOUR_SIZE_CONSTRAINT_CODE: i32 = 21;
+const MIN_SHIFT_TIME_CONSTRAINT_CODE: i32 = 22;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
@@ -153,6 +175,16 @@ pub fn get_coord_index(problem: &CoreProblem) -> &CoordIndex {
     problem.extras.get("coord_index").and_then(|s| s.downcast_ref::<CoordIndex>()).expect("cannot get coord index!")
 }
 
+/// Gets numeric precision used by the problem, default is integer.
+pub fn get_precision(problem: &CoreProblem) -> Precision {
+    problem.extras.get("precision").and_then(|s| s.downcast_ref::<Precision>()).cloned().unwrap_or_default()
+}
+
+/// Gets fleet limits if they are defined.
+pub fn get_fleet_limits(problem: &CoreProblem) -> Option<&Vec<FleetLimit>> {
+    problem.extras.get("fleet_limits").and_then(|s| s.downcast_ref::<Vec<FleetLimit>>())
+}
+
 /// Gets reserved times index.
 pub fn get_reserved_times_index(problem: &CoreProblem) -> &ReservedTimesIndex {
     problem
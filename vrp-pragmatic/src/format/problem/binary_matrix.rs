@@ -0,0 +1,217 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/binary_matrix_test.rs"]
+mod binary_matrix_test;
+
+use super::{split_matrix_values, Matrix, TimeInterpolation};
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::io::{BufWriter, Error, ErrorKind, Read, Write};
+
+const MAGIC: &[u8; 4] = b"VRPM";
+const VERSION: u8 = 1;
+
+const COMPRESSED_FLAG: u8 = 1;
+const ERROR_CODES_FLAG: u8 = 1 << 1;
+const COSTS_FLAG: u8 = 1 << 2;
+const STEP_INTERPOLATION_FLAG: u8 = 1 << 3;
+const LINEAR_INTERPOLATION_FLAG: u8 = 1 << 4;
+const FRACTIONAL_VALUES_FLAG: u8 = 1 << 5;
+const COMPACT_STORAGE_FLAG: u8 = 1 << 6;
+
+const CHUNK_SIZE: usize = 4096;
+
+/// Serializes routing matrix in binary format, optionally compressing its payload.
+///
+/// The format starts with a header: `VRPM` signature, format version, flags, size as amount of unique
+/// locations, profile and timestamp as length prefixed UTF-8 strings. Flags specify whether payload is compressed,
+/// which optional data is present, which time interpolation is used and whether compact storage is requested. The header is followed by travel times,
+/// distances and, optionally, error codes stored as little-endian `u32` and, optionally, costs stored as
+/// little-endian `f32`. When travel times or distances have fractional part, they are stored as little-endian `f32`.
+pub fn serialize_matrix_binary<W: Write>(writer: BufWriter<W>, matrix: &Matrix, compress: bool) -> Result<(), Error> {
+    let length = matrix.travel_times.len();
+    let size = (length as f64).sqrt().round() as usize;
+
+    if size * size != length || matrix.distances.len() != length {
+        return Err(invalid_input("travel times and distances should be square matrices of the same size"));
+    }
+
+    if matrix.error_codes.as_ref().map_or(false, |codes| codes.len() != length)
+        || matrix.costs.as_ref().map_or(false, |costs| costs.len() != length)
+    {
+        return Err(invalid_input("error codes and costs should have the same size as travel times"));
+    }
+
+    let has_fractions = matrix.fractions.is_some();
+
+    let flags = (if compress { COMPRESSED_FLAG } else { 0 })
+        | (if has_fractions { FRACTIONAL_VALUES_FLAG } else { 0 })
+        | (if matrix.error_codes.is_some() { ERROR_CODES_FLAG } else { 0 })
+        | (if matrix.costs.is_some() { COSTS_FLAG } else { 0 })
+        | (if matrix.compact.unwrap_or(false) { COMPACT_STORAGE_FLAG } else { 0 })
+        | match matrix.interpolation {
+            Some(TimeInterpolation::Step) => STEP_INTERPOLATION_FLAG,
+            Some(TimeInterpolation::Linear) => LINEAR_INTERPOLATION_FLAG,
+            None => 0,
+        };
+
+    let mut writer = writer;
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION, flags])?;
+    writer.write_all(&(u32::try_from(size).map_err(|_| invalid_input("too many locations"))?).to_le_bytes())?;
+    write_string(&mut writer, matrix.profile.as_deref())?;
+    write_string(&mut writer, matrix.timestamp.as_deref())?;
+
+    if compress {
+        let mut encoder = ZlibEncoder::new(writer, Compression::default());
+        write_payload(&mut encoder, matrix)?;
+        encoder.finish()?.flush()
+    } else {
+        write_payload(&mut writer, matrix)?;
+        writer.flush()
+    }
+}
+
+/// Checks whether data starts with binary matrix signature.
+pub(crate) fn is_binary_matrix(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+/// Deserializes routing matrix from binary format.
+pub(crate) fn deserialize_matrix_binary<R: Read>(reader: R) -> Result<Matrix, Error> {
+    let mut reader = reader;
+
+    let mut header = [0_u8; 10];
+    reader.read_exact(&mut header)?;
+
+    if !is_binary_matrix(&header) {
+        return Err(invalid_data("unknown signature"));
+    }
+
+    if header[4] != VERSION {
+        return Err(invalid_data(format!("unsupported version: {}", header[4]).as_str()));
+    }
+
+    let flags = header[5];
+    let interpolation = match (flags & STEP_INTERPOLATION_FLAG != 0, flags & LINEAR_INTERPOLATION_FLAG != 0) {
+        (true, true) => return Err(invalid_data("ambiguous interpolation")),
+        (true, false) => Some(TimeInterpolation::Step),
+        (false, true) => Some(TimeInterpolation::Linear),
+        (false, false) => None,
+    };
+    let compact = if flags & COMPACT_STORAGE_FLAG != 0 { Some(true) } else { None };
+    let size = u32::from_le_bytes([header[6], header[7], header[8], header[9]]) as usize;
+    let length = size.checked_mul(size).ok_or_else(|| invalid_data("too many locations"))?;
+
+    let profile = read_string(&mut reader)?;
+    let timestamp = read_string(&mut reader)?;
+
+    let mut reader: Box<dyn Read> =
+        if flags & COMPRESSED_FLAG != 0 { Box::new(ZlibDecoder::new(reader)) } else { Box::new(reader) };
+
+    let value_fn: fn([u8; 4]) -> f64 = if flags & FRACTIONAL_VALUES_FLAG != 0 {
+        |bytes| f32::from_le_bytes(bytes) as f64
+    } else {
+        |bytes| u32::from_le_bytes(bytes) as f64
+    };
+    let travel_times = read_values(&mut reader, length, value_fn)?;
+    let distances = read_values(&mut reader, length, value_fn)?;
+    let error_codes = if flags & ERROR_CODES_FLAG != 0 {
+        Some(read_values(&mut reader, length, |bytes| u32::from_le_bytes(bytes) as i64)?)
+    } else {
+        None
+    };
+    let costs = if flags & COSTS_FLAG != 0 {
+        Some(read_values(&mut reader, length, |bytes| f32::from_le_bytes(bytes) as f64)?)
+    } else {
+        None
+    };
+
+    let (travel_times, distances, fractions) = split_matrix_values(travel_times, distances);
+
+    Ok(Matrix { profile, timestamp, travel_times, distances, error_codes, costs, interpolation, compact, fractions })
+}
+
+fn write_payload<W: Write>(writer: &mut W, matrix: &Matrix) -> Result<(), Error> {
+    match matrix.fractions.as_ref() {
+        Some(fractions) => {
+            [fractions.travel_times.as_slice(), fractions.distances.as_slice()].iter().try_for_each(|values| {
+                if let Some(value) = values.iter().find(|value| !value.is_finite() || **value < 0.) {
+                    return Err(invalid_input(format!("value '{}' cannot be stored as f32", value).as_str()));
+                }
+                write_floats(writer, values)
+            })?
+        }
+        None => [matrix.travel_times.as_slice(), matrix.distances.as_slice()]
+            .iter()
+            .try_for_each(|values| write_integers(writer, values.iter().cloned()))?,
+    }
+
+    if let Some(error_codes) = &matrix.error_codes {
+        write_integers(writer, error_codes.iter().cloned())?;
+    }
+
+    if let Some(costs) = &matrix.costs {
+        write_floats(writer, costs.as_slice())?;
+    }
+
+    Ok(())
+}
+
+fn write_floats<W: Write>(writer: &mut W, values: &[f64]) -> Result<(), Error> {
+    values.iter().try_for_each(|&value| writer.write_all(&(value as f32).to_le_bytes()))
+}
+
+fn write_integers<W: Write>(writer: &mut W, mut values: impl Iterator<Item = i64>) -> Result<(), Error> {
+    values.try_for_each(|value| {
+        let value = u32::try_from(value)
+            .map_err(|_| invalid_input(format!("value '{}' cannot be stored as u32", value).as_str()))?;
+
+        writer.write_all(&value.to_le_bytes())
+    })
+}
+
+fn write_string<W: Write>(writer: &mut W, value: Option<&str>) -> Result<(), Error> {
+    let bytes = value.unwrap_or_default().as_bytes();
+    let length = u16::try_from(bytes.len()).map_err(|_| invalid_input("string is too long"))?;
+
+    writer.write_all(&length.to_le_bytes())?;
+    writer.write_all(bytes)
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Result<Option<String>, Error> {
+    let mut length = [0_u8; 2];
+    reader.read_exact(&mut length)?;
+
+    let mut bytes = vec![0_u8; u16::from_le_bytes(length) as usize];
+    reader.read_exact(bytes.as_mut_slice())?;
+
+    if bytes.is_empty() {
+        Ok(None)
+    } else {
+        String::from_utf8(bytes).map(Some).map_err(|err| invalid_data(err.to_string().as_str()))
+    }
+}
+
+fn read_values<T, R: Read>(reader: &mut R, length: usize, map_fn: fn([u8; 4]) -> T) -> Result<Vec<T>, Error> {
+    // NOTE do not trust size from header to preallocate memory
+    let mut values = Vec::with_capacity(length.min(CHUNK_SIZE * CHUNK_SIZE));
+    let mut buffer = vec![0_u8; 4 * CHUNK_SIZE];
+
+    while values.len() < length {
+        let bytes = &mut buffer[..4 * (length - values.len()).min(CHUNK_SIZE)];
+        reader.read_exact(bytes)?;
+
+        values.extend(bytes.chunks_exact(4).map(|chunk| map_fn([chunk[0], chunk[1], chunk[2], chunk[3]])));
+    }
+
+    Ok(values)
+}
+
+fn invalid_input(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidInput, message)
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
@@ -51,7 +51,7 @@ fn get_profile(api_problem: &ApiProblem, profile: &VehicleProfile) -> Result<Pro
         .cloned()
         .ok_or_else(|| format!("cannot find matrix profile: {}", profile.matrix))?;
 
-    Ok(Profile { index: profile_index, scale: profile.scale.unwrap_or(1.) })
+    Ok(Profile { index: profile_index, scale: profile.scale.unwrap_or(1.), buffer: get_profile_buffer(profile) })
 }
 
 fn get_builder_policy() -> BuilderPolicy {
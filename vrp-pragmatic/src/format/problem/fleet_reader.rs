@@ -2,10 +2,11 @@
 #[path = "../../../tests/unit/format/problem/fleet_reader_test.rs"]
 mod fleet_reader_test;
 
+use crate::constraints::{FleetLimit, FleetLimitTarget, VehicleAttributes};
 use crate::extensions::{create_typed_actor_groups, VehicleTie};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
+use crate::format::problem::{get_profile_buffer, Matrix, TimeInterpolation};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
@@ -51,29 +52,29 @@ pub(crate) fn create_transport_costs(
             (profile, matrix.timestamp.clone(), matrix)
         })
         .map(|(profile, timestamp, matrix)| {
-            let (durations, distances) = if let Some(error_codes) = &matrix.error_codes {
-                let capacity = matrix.distances.len();
-
-                let mut durations: Vec<Duration> = Vec::with_capacity(capacity);
-                let mut distances: Vec<Distance> = Vec::with_capacity(capacity);
-                for (i, error) in error_codes.iter().enumerate() {
-                    if *error > 0 {
-                        durations.push(-1.);
-                        distances.push(-1.);
-                    } else {
-                        durations.push(*matrix.travel_times.get(i).unwrap() as f64);
-                        distances.push(*matrix.distances.get(i).unwrap() as f64);
-                    }
-                }
-                (durations, distances)
-            } else {
-                (
-                    matrix.travel_times.iter().map(|d| *d as f64).collect(),
-                    matrix.distances.iter().map(|d| *d as f64).collect(),
-                )
+            let error_codes = matrix.error_codes.as_ref();
+            let (durations, distances) = match matrix.fractions.as_ref() {
+                Some(fractions) => (fractions.travel_times.clone(), fractions.distances.clone()),
+                None => (
+                    matrix.travel_times.iter().map(|&d| d as f64).collect(),
+                    matrix.distances.iter().map(|&d| d as f64).collect(),
+                ),
             };
 
-            MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances)
+            MatrixData {
+                interpolation: match matrix.interpolation {
+                    Some(TimeInterpolation::Step) => MatrixInterpolation::Step,
+                    Some(TimeInterpolation::Linear) | None => MatrixInterpolation::Linear,
+                },
+                costs: matrix.costs.clone(),
+                storage: if matrix.compact.unwrap_or(false) { MatrixStorage::Compact } else { MatrixStorage::Full },
+                ..MatrixData::new(
+                    profile,
+                    timestamp.map(|t| parse_time(&t)),
+                    get_matrix_values(durations, error_codes),
+                    get_matrix_values(distances, error_codes),
+                )
+            }
         })
         .collect::<Vec<_>>();
 
@@ -85,6 +86,18 @@ pub(crate) fn create_transport_costs(
     create_matrix_transport_cost(matrix_data)
 }
 
+/// Returns matrix values marking unreachable locations with negative value.
+fn get_matrix_values(values: Vec<f64>, error_codes: Option<&Vec<i64>>) -> Vec<f64> {
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(idx, value)| match error_codes.and_then(|codes| codes.get(idx)) {
+            Some(&code) if code > 0 => -1.,
+            _ => value,
+        })
+        .collect()
+}
+
 pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> Fleet {
     let profile_indices = get_profile_index_map(api_problem);
     let area_index = api_problem
@@ -93,6 +106,7 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         .iter()
         .flat_map(|areas| areas.iter().map(|area| (&area.id, area)))
         .collect::<HashMap<_, _>>();
+    let job_index = api_problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
@@ -105,9 +119,12 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         };
 
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
-        let profile = Profile::new(index, vehicle.profile.scale);
+        let profile =
+            Profile { buffer: get_profile_buffer(&vehicle.profile), ..Profile::new(index, vehicle.profile.scale) };
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let min_tour_size = vehicle.limits.as_ref().and_then(|l| l.min_tour_size);
+        let backhaul = vehicle.limits.as_ref().and_then(|l| l.backhaul);
         let mut area_jobs = vehicle.limits.as_ref().and_then(|l| l.areas.as_ref()).map({
             let area_index = &area_index;
             move |areas| {
@@ -155,6 +172,16 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 }),
             }];
 
+            // NOTE cargo of on board jobs is loaded at start
+            let initial_load = shift.onboard.as_ref().filter(|onboard| !onboard.is_empty()).map(|onboard| {
+                onboard
+                    .iter()
+                    .filter_map(|job_id| job_index.get(job_id.as_str()))
+                    .flat_map(|job| job.deliveries.iter().flatten())
+                    .filter_map(|task| task.demand.clone())
+                    .fold(MultiDimLoad::default(), |acc, demand| acc + MultiDimLoad::new(demand))
+            });
+
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
                 let mut dimens: Dimensions = Default::default();
 
@@ -171,16 +198,44 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(min_tour_size) = min_tour_size {
+                    dimens.set_min_tour_size(min_tour_size);
+                }
+
+                if let Some(backhaul) = backhaul {
+                    dimens.set_backhaul(backhaul);
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
                     dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
                 }
 
+                if let Some(initial_load) = initial_load {
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_initial_load(initial_load);
+                    } else {
+                        dimens.set_initial_load(SingleDimLoad::new(initial_load.load[0]));
+                    }
+                }
+
                 if let Some(skills) = vehicle.skills.as_ref() {
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
 
+                if let Some(tags) = vehicle.tags.as_ref() {
+                    dimens.set_vehicle_tags(tags.iter().cloned().collect::<HashSet<_>>());
+                }
+
+                if let Some(attributes) = vehicle.attributes.as_ref() {
+                    dimens.set_vehicle_attributes(VehicleAttributes {
+                        weight: attributes.weight,
+                        height: attributes.height,
+                        length: attributes.length,
+                    });
+                }
+
                 vehicles.push(Arc::new(Vehicle {
                     profile: profile.clone(),
                     costs: costs.clone(),
@@ -203,5 +258,55 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         details: vec![],
     })];
 
-    Fleet::new(drivers, vehicles, Box::new(|actors| create_typed_actor_groups(actors)))
+    let fleet = Fleet::new(drivers, vehicles, Box::new(|actors| create_typed_actor_groups(actors)));
+
+    let unlimited_types = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter(|vehicle| vehicle.is_unlimited.unwrap_or(false))
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<HashSet<_>>();
+
+    if unlimited_types.is_empty() {
+        fleet
+    } else {
+        // NOTE each used vehicle serves at least one job, so there is no need in more vehicles of the same group
+        let max_size = api_problem.plan.jobs.len();
+
+        fleet.with_vehicle_factory(
+            Arc::new(move |vehicle, index| {
+                let type_id = vehicle.dimens.get_vehicle_type().filter(|type_id| unlimited_types.contains(*type_id))?;
+
+                let mut dimens = vehicle.dimens.clone();
+                dimens.set_vehicle_id(format!("{}_{}", type_id, index));
+
+                Some(Vehicle {
+                    profile: vehicle.profile.clone(),
+                    costs: vehicle.costs.clone(),
+                    dimens,
+                    details: vehicle.details.clone(),
+                })
+            }),
+            max_size,
+        )
+    }
+}
+
+pub(crate) fn read_fleet_limits(api_problem: &ApiProblem) -> Vec<FleetLimit> {
+    api_problem
+        .fleet
+        .limits
+        .iter()
+        .flat_map(|limits| limits.iter())
+        .filter_map(|limit| {
+            let target = match (&limit.type_id, &limit.tag) {
+                (Some(type_id), None) => FleetLimitTarget::VehicleType(type_id.clone()),
+                (None, Some(tag)) => FleetLimitTarget::VehicleTag(tag.clone()),
+                _ => return None,
+            };
+
+            Some(FleetLimit { target, min_tours: limit.min_tours, max_tours: limit.max_tours })
+        })
+        .collect()
 }
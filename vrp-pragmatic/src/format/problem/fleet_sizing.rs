@@ -0,0 +1,114 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/fleet_sizing_test.rs"]
+mod fleet_sizing_test;
+
+use super::*;
+use crate::extensions::VehicleTie;
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::models::Solution as CoreSolution;
+
+/// Specifies usage of vehicle type in the fleet sizing solution.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetSizingSummary {
+    /// Vehicle type id.
+    pub type_id: String,
+    /// Amount of used vehicles.
+    pub vehicles: usize,
+    /// Amount of used tours.
+    pub tours: usize,
+    /// Total fixed (acquisition) cost of used tours.
+    pub fixed_cost: f64,
+}
+
+/// Creates a problem to find out which fleet mix is needed to serve the plan:
+/// * each vehicle type is marked as unlimited and gets a single `{typeId}_1` vehicle id instead of
+///   listed ones. New vehicles are added on demand when all vehicles of the type are in use
+/// * if no objectives are specified, the solver minimizes unassigned jobs and then total cost, so vehicle
+///   type's fixed cost should be set to its acquisition cost
+///
+/// Relations and vehicle specific shift properties (breaks, reloads, dispatch and on board jobs) are not
+/// supported as they refer to concrete vehicle ids. Use `fleet.limits` to restrict amount of vehicles of
+/// specific type.
+pub fn create_fleet_sizing_problem(problem: &Problem) -> Result<Problem, String> {
+    if problem.plan.relations.as_ref().map_or(false, |relations| !relations.is_empty()) {
+        return Err("fleet sizing does not support relations".to_string());
+    }
+
+    let shifts = problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).collect::<Vec<_>>();
+    let has_feature = |is_used: fn(&VehicleShift) -> bool| shifts.iter().any(|shift| is_used(shift));
+
+    if has_feature(|shift| shift.onboard.as_ref().map_or(false, |onboard| !onboard.is_empty())) {
+        return Err("fleet sizing does not support on board jobs".to_string());
+    }
+
+    if has_feature(|shift| shift.breaks.as_ref().map_or(false, |breaks| !breaks.is_empty())) {
+        return Err("fleet sizing does not support breaks".to_string());
+    }
+
+    if has_feature(|shift| shift.reloads.as_ref().map_or(false, |reloads| !reloads.is_empty())) {
+        return Err("fleet sizing does not support reloads".to_string());
+    }
+
+    if has_feature(|shift| shift.dispatch.as_ref().map_or(false, |dispatch| !dispatch.is_empty())) {
+        return Err("fleet sizing does not support dispatch".to_string());
+    }
+
+    Ok(Problem {
+        fleet: Fleet {
+            vehicles: problem
+                .fleet
+                .vehicles
+                .iter()
+                .map(|vehicle| VehicleType {
+                    vehicle_ids: vec![format!("{}_1", vehicle.type_id)],
+                    is_unlimited: Some(true),
+                    ..vehicle.clone()
+                })
+                .collect(),
+            ..problem.fleet.clone()
+        },
+        objectives: problem.objectives.clone().or_else(|| {
+            Some(vec![vec![Objective::MinimizeUnassignedJobs { breaks: None }], vec![Objective::MinimizeCost]])
+        }),
+        ..problem.clone()
+    })
+}
+
+/// Returns usage of each vehicle type in the solution of fleet sizing problem.
+pub fn get_fleet_sizing_summary(problem: &CoreProblem, solution: &CoreSolution) -> Vec<FleetSizingSummary> {
+    let mut type_ids = problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.dimens.get_vehicle_type())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    type_ids.sort();
+
+    let usage = solution.routes.iter().fold(HashMap::<_, (HashSet<_>, usize, f64)>::new(), |mut acc, route| {
+        let vehicle = route.actor.vehicle.as_ref();
+        if let Some(type_id) = vehicle.dimens.get_vehicle_type() {
+            let entry = acc.entry(type_id).or_default();
+            entry.0.insert(vehicle.dimens.get_vehicle_id());
+            entry.1 += 1;
+            entry.2 += vehicle.costs.fixed;
+        }
+
+        acc
+    });
+
+    type_ids
+        .into_iter()
+        .map(|type_id| {
+            let (vehicles, tours, fixed_cost) = usage
+                .get(type_id)
+                .map_or((0, 0, 0.), |(vehicle_ids, tours, fixed_cost)| (vehicle_ids.len(), *tours, *fixed_cost));
+
+            FleetSizingSummary { type_id: type_id.clone(), vehicles, tours, fixed_cost }
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use crate::constraints::{BreakPolicy, JobSkills as ConstraintJobSkills};
+use crate::constraints::{BreakPolicy, JobFrequency as ConstraintJobFrequency, JobSkills as ConstraintJobSkills};
 use crate::extensions::{BreakTie, JobTie, VehicleTie};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{parse_time_window, ApiProblem, ProblemProperties};
@@ -7,11 +7,11 @@ use crate::format::problem::*;
 use crate::format::{JobIndex, Location};
 use crate::parse_time;
 use crate::utils::VariableJobPermutation;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
+use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost, DEFAULT_MAX_NEIGHBORS};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
 use vrp_core::prelude::*;
 
@@ -36,20 +36,27 @@ pub(crate) fn read_jobs_with_extra_locks(
     jobs.extend(conditional_jobs);
     locks.extend(conditional_locks);
 
-    (Jobs::new(fleet, jobs, transport), locks)
+    let max_neighbors = api_problem.plan.max_neighbors.unwrap_or(DEFAULT_MAX_NEIGHBORS);
+
+    (Jobs::new_with_max_neighbors(fleet, jobs, transport, max_neighbors), locks)
 }
 
 pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
-    if api_problem.plan.relations.as_ref().map_or(true, |r| r.is_empty()) {
+    let onboard_relations = get_onboard_relations(api_problem);
+
+    if api_problem.plan.relations.as_ref().map_or(true, |r| r.is_empty()) && onboard_relations.is_empty() {
         return vec![];
     }
 
-    let relations = api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
-        let shift_index = r.shift_index.unwrap_or(0);
-        acc.entry((r.vehicle_id.clone(), shift_index)).or_insert_with(Vec::new).push(r.clone());
+    let relations = api_problem.plan.relations.iter().flatten().cloned().chain(onboard_relations.into_iter()).fold(
+        HashMap::new(),
+        |mut acc, r| {
+            let shift_index = r.shift_index.unwrap_or(0);
+            acc.entry((r.vehicle_id.clone(), shift_index)).or_insert_with(Vec::new).push(r);
 
-        acc
-    });
+            acc
+        },
+    );
 
     relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
         let condition = create_condition(vehicle_id.clone(), shift_index);
@@ -111,6 +118,8 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let onboard_ids = get_onboard_job_ids(api_problem);
+    let location_parking = get_location_parking(api_problem, coord_index);
 
     let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
         let absent = (empty(), empty());
@@ -131,16 +140,34 @@ fn read_required_jobs(
             .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
             .collect();
 
-        get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let mut single =
+            get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+
+        let parkings = single
+            .places
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, place)| {
+                place.location.and_then(|location| location_parking.get(&location)).map(|parking| (idx, *parking))
+            })
+            .collect();
+        single.dimens.set_place_parkings(Some(parkings));
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
-        let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
+        // NOTE pickups of on board jobs are already done: their cargo is a part of vehicle's initial load,
+        // so deliveries have dynamic demand which only unloads it
+        let is_onboard = onboard_ids.contains(job.id.as_str());
+        let job_pickups = if is_onboard { None } else { job.pickups.as_ref() };
+
+        let pickups = job_pickups.map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
-        let is_static_demand = pickups == 0 || deliveries == 0;
+        let is_static_demand = !is_onboard && (pickups == 0 || deliveries == 0);
 
-        let singles =
-            job.pickups
+        let get_singles = || {
+            job_pickups
                 .iter()
                 .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
                 .chain(job.deliveries.iter().flat_map(|tasks| {
@@ -156,19 +183,26 @@ fn read_required_jobs(
                         .iter()
                         .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "service", false))),
                 )
-                .collect::<Vec<_>>();
+                .collect::<Vec<_>>()
+        };
 
-        assert!(!singles.is_empty());
+        // NOTE periodic job is represented by separate occurrence per visit, job index keeps the first one
+        let occurrences = job.frequency.as_ref().map_or(1, |frequency| frequency.visits.max(1));
 
-        let problem_job = if singles.len() > 1 {
-            let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
-            get_multi_job(job, singles, deliveries_start_index, random)
-        } else {
-            get_single_job(job, singles.into_iter().next().unwrap())
-        };
+        (0..occurrences).for_each(|_| {
+            let singles = get_singles();
 
-        job_index.insert(job.id.clone(), problem_job.clone());
-        jobs.push(problem_job);
+            assert!(!singles.is_empty());
+
+            let problem_job = if singles.len() > 1 {
+                get_multi_job(job, singles, pickups, random)
+            } else {
+                get_single_job(job, singles.into_iter().next().unwrap())
+            };
+
+            job_index.entry(job.id.clone()).or_insert_with(|| problem_job.clone());
+            jobs.push(problem_job);
+        });
     });
 
     (jobs, vec![])
@@ -412,8 +446,12 @@ fn get_single_job(job: &ApiJob, single: Single) -> Job {
         .dimens
         .set_job_id(job.id.clone())
         .set_job_value(job.value)
+        .set_job_value_curve(get_value_curve(job))
+        .set_job_priority(job.priority)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
+        .set_job_consistency(job.consistency_key.clone())
+        .set_job_frequency(get_frequency(job))
         .set_job_skills(get_skills(&job.skills));
 
     Job::Single(Arc::new(single))
@@ -429,8 +467,12 @@ fn get_multi_job(
     dimens
         .set_job_id(job.id.clone())
         .set_job_value(job.value)
+        .set_job_value_curve(get_value_curve(job))
+        .set_job_priority(job.priority)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
+        .set_job_consistency(job.consistency_key.clone())
+        .set_job_frequency(get_frequency(job))
         .set_job_skills(get_skills(&job.skills));
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
@@ -454,6 +496,57 @@ fn get_multi_job(
     Job::Multi(multi)
 }
 
+/// Gets ids of jobs which cargo is already on board of some vehicle.
+fn get_onboard_job_ids(api_problem: &ApiProblem) -> HashSet<&str> {
+    api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .flat_map(|shift| shift.onboard.iter().flatten())
+        .map(|job_id| job_id.as_str())
+        .collect()
+}
+
+/// Creates relations which keep on board jobs within vehicle shift they are loaded on.
+fn get_onboard_relations(api_problem: &ApiProblem) -> Vec<Relation> {
+    let delivery_counts = api_problem
+        .plan
+        .jobs
+        .iter()
+        .map(|job| (job.id.as_str(), job.deliveries.as_ref().map_or(0, |d| d.len())))
+        .collect::<HashMap<_, _>>();
+
+    api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.vehicle_ids.first().map(|vehicle_id| (vehicle_id, vehicle)))
+        .flat_map(|(vehicle_id, vehicle)| {
+            vehicle.shifts.iter().enumerate().filter_map(move |(shift_index, shift)| {
+                shift
+                    .onboard
+                    .as_ref()
+                    .filter(|onboard| !onboard.is_empty())
+                    .map(|onboard| (vehicle_id, shift_index, onboard))
+            })
+        })
+        .map(|(vehicle_id, shift_index, onboard)| Relation {
+            type_field: RelationType::Any,
+            // NOTE each delivery of multi job has to be referenced in the relation
+            jobs: onboard
+                .iter()
+                .flat_map(|job_id| {
+                    let count = delivery_counts.get(job_id.as_str()).cloned().unwrap_or(0);
+                    std::iter::repeat(job_id.clone()).take(count)
+                })
+                .collect(),
+            vehicle_id: vehicle_id.clone(),
+            shift_index: Some(shift_index),
+        })
+        .collect()
+}
+
 fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor) -> bool + Sync + Send> {
     Arc::new(move |actor: &Actor| {
         *actor.vehicle.dimens.get_vehicle_id().unwrap() == vehicle_id
@@ -469,6 +562,18 @@ fn get_skills(skills: &Option<FormatJobSkills>) -> Option<ConstraintJobSkills> {
     })
 }
 
+fn get_frequency(job: &ApiJob) -> Option<ConstraintJobFrequency> {
+    job.frequency.as_ref().map(|frequency| ConstraintJobFrequency {
+        key: job.id.clone(),
+        min_gap_days: frequency.min_gap_days.unwrap_or(1),
+        allowed_days: frequency.allowed_days.as_ref().map(|days| days.iter().cloned().collect()),
+    })
+}
+
+fn get_value_curve(job: &ApiJob) -> Option<Vec<(Timestamp, f64)>> {
+    job.value_curve.as_ref().map(|curve| curve.iter().map(|point| (parse_time(&point.time), point.value)).collect())
+}
+
 fn empty() -> MultiDimLoad {
     MultiDimLoad::default()
 }
@@ -1,13 +1,105 @@
 //! Specifies logic to read problem and routing matrix from json input.
 //!
 
+use crate::format::CoordIndex;
+use hashbrown::HashMap;
+use vrp_core::models::common::TravelBuffer as DomainTravelBuffer;
+
 mod model;
 pub use self::model::*;
 
+mod binary_matrix;
+pub use self::binary_matrix::serialize_matrix_binary;
+
+mod fleet_sizing;
+pub use self::fleet_sizing::{create_fleet_sizing_problem, get_fleet_sizing_summary, FleetSizingSummary};
+
+mod road_network;
+pub use self::road_network::{deserialize_road_graph, RoadEdge, RoadGraph, RoadNetwork, RoadNode};
+
 mod reader;
-pub use self::reader::create_approx_matrices;
+pub use self::reader::create_road_network_matrices;
 pub use self::reader::PragmaticProblem;
+pub use self::reader::{create_approx_matrices, create_approx_matrices_with_distances};
+
+/// Keeps fractional routing matrix values only when the problem uses float precision.
+pub fn apply_matrix_precision(problem: &Problem, matrix: Matrix) -> Matrix {
+    match problem.precision {
+        Some(Precision::Float) => matrix,
+        _ => Matrix { fractions: None, ..matrix },
+    }
+}
+
+/// Returns routing matrices which belong to the vehicle profile with given name.
+pub fn get_profile_matrices<'a>(problem: &Problem, matrices: &'a [Matrix], profile: &str) -> Vec<&'a Matrix> {
+    let profile_index = self::reader::get_profile_index_map(problem).get(profile).cloned();
+
+    matrices
+        .iter()
+        .enumerate()
+        .filter(|(idx, matrix)| match &matrix.profile {
+            Some(name) => name == profile,
+            None => profile_index == Some(*idx),
+        })
+        .map(|(_, matrix)| matrix)
+        .collect()
+}
+
+/// Checks whether a leg between two matrix indices is marked by error code in any of given routing matrices.
+pub fn is_unreachable_leg(matrices: &[&Matrix], from: usize, to: usize) -> bool {
+    matrices.iter().any(|matrix| {
+        let size = (matrix.travel_times.len() as f64).sqrt().round() as usize;
+        matches!(matrix.error_codes.as_ref().and_then(|codes| codes.get(from * size + to)), Some(&code) if code > 0)
+    })
+}
+
+pub(crate) fn get_profile_buffer(profile: &VehicleProfile) -> Option<DomainTravelBuffer> {
+    profile.buffer.as_ref().map(|buffer| DomainTravelBuffer {
+        percentage: buffer.percentage.unwrap_or(0.),
+        fixed: buffer.fixed.unwrap_or(0.),
+        slack: buffer.slack.unwrap_or(0.),
+    })
+}
 
 pub(crate) fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
     job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).chain(job.replacements.iter()).flatten()
 }
+
+/// Checks whether activity type corresponds to one of job tasks.
+pub(crate) fn is_job_task_activity(activity_type: &str) -> bool {
+    matches!(activity_type, "pickup" | "delivery" | "service" | "replacement")
+}
+
+/// Gets parking (stop setup) time per location index of job places.
+pub(crate) fn get_location_parking(problem: &Problem, coord_index: &CoordIndex) -> HashMap<usize, f64> {
+    problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(get_job_tasks)
+        .flat_map(|task| task.places.iter())
+        .filter_map(|place| place.parking.zip(coord_index.get_by_loc(&place.location)))
+        .fold(HashMap::new(), |mut acc, (parking, location)| {
+            // NOTE use the longest parking when different values are specified for the same location
+            let entry = acc.entry(location).or_insert(parking);
+            *entry = entry.max(parking);
+            acc
+        })
+}
+
+/// Checks whether vehicle with given id belongs to the vehicle type: it is either listed in type's
+/// vehicle ids or created on demand for unlimited type.
+pub(crate) fn has_vehicle_id(vehicle: &VehicleType, vehicle_id: &str) -> bool {
+    vehicle.vehicle_ids.iter().any(|id| id == vehicle_id) || is_generated_vehicle_id(vehicle, vehicle_id)
+}
+
+/// Checks whether vehicle id has `{typeId}_{index}` form used for vehicles created on demand for unlimited
+/// type. Such vehicles get indices after the type's listed vehicles.
+pub(crate) fn is_generated_vehicle_id(vehicle: &VehicleType, vehicle_id: &str) -> bool {
+    vehicle.is_unlimited.unwrap_or(false)
+        && vehicle_id
+            .strip_prefix(vehicle.type_id.as_str())
+            .and_then(|suffix| suffix.strip_prefix('_'))
+            .and_then(|index| index.parse::<usize>().ok())
+            .map_or(false, |index| index > vehicle.vehicle_ids.len())
+}
@@ -4,9 +4,12 @@ mod model_test;
 
 extern crate serde_json;
 
+use super::binary_matrix::{deserialize_matrix_binary, is_binary_matrix};
 use crate::format::{FormatError, Location};
-use serde::{Deserialize, Serialize};
-use std::io::{BufReader, BufWriter, Error, Read, Write};
+use serde::de::{SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
 
 // region Plan
 
@@ -69,6 +72,10 @@ pub struct JobPlace {
     pub location: Location,
     /// A job place duration (service time).
     pub duration: f64,
+    /// A parking (stop setup) time which is spent once when vehicle arrives at the place location
+    /// from a different location. Jobs served consecutively at the same location share it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parking: Option<f64>,
     /// A list of job place time windows with time specified in RFC3339 format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
@@ -76,6 +83,24 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// Access restrictions of the place location, e.g. max vehicle weight or height allowed on site.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access: Option<PlaceAccess>,
+}
+
+/// Specifies max physical attributes of a vehicle which is allowed to access job place location.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceAccess {
+    /// Max allowed vehicle weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_weight: Option<f64>,
+    /// Max allowed vehicle height.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_height: Option<f64>,
+    /// Max allowed vehicle length.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<f64>,
 }
 
 /// Specifies a job task.
@@ -91,6 +116,23 @@ pub struct JobTask {
     pub order: Option<i32>,
 }
 
+/// Specifies a job visit frequency over multi-day planning horizon.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobFrequency {
+    /// Amount of visits required within planning horizon.
+    pub visits: usize,
+    /// A minimum difference in days between two visits. Default is 1 which means that visits
+    /// should happen on different days.
+    #[serde(rename = "minGapDays")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_gap_days: Option<usize>,
+    /// A list of day indices (starting from 0 for the earliest vehicle shift day) when visits
+    /// are allowed. If omitted, any day is allowed.
+    #[serde(rename = "allowedDays")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_days: Option<Vec<usize>>,
+}
+
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
 /// which follows these rules:
 /// * all of them should be completed or none of them.
@@ -124,6 +166,16 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<f64>,
 
+    /// Time dependent job value defined as a piecewise-linear function of service time. Overrides
+    /// static job value when specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_curve: Option<Vec<JobValuePoint>>,
+
+    /// Job priority tier: lower value means higher priority, default is 1. Used by
+    /// `minimize-unassigned-by-priority` objective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<usize>,
+
     /// Job group: jobs of the same group are assigned to the same tour or unassigned.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<String>,
@@ -131,6 +183,25 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// A consistency key (e.g. customer id): jobs with the same key are preferred to be served by the same vehicle.
+    #[serde(rename = "consistencyKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consistency_key: Option<String>,
+
+    /// A visit frequency: if specified, the job is visited given amount of times on different days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<JobFrequency>,
+}
+
+/// A point of time dependent job value function.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobValuePoint {
+    /// A service time in RFC3339 format.
+    pub time: String,
+
+    /// A job value when job is served at given time.
+    pub value: f64,
 }
 
 // region Clustering
@@ -238,6 +309,11 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// Specifies max amount of closest neighbors kept per job. Default is 256.
+    #[serde(rename = "maxNeighbors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_neighbors: Option<usize>,
 }
 
 // endregion
@@ -313,6 +389,11 @@ pub struct VehicleShift {
     /// unloaded during single tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reloads: Option<Vec<VehicleReload>>,
+
+    /// Ids of jobs which cargo is already on board at shift start: their pickups are considered
+    /// as done, so only deliveries are planned and assigned to this vehicle shift.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub onboard: Option<Vec<String>>,
 }
 
 /// Specifies a dispatch place where vehicle can load cargo and start the tour.
@@ -382,12 +463,45 @@ pub struct VehicleLimits {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
 
+    /// Min amount of job activities in used tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_tour_size: Option<usize>,
+
+    /// Min time of used shift/tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_shift_time: Option<f64>,
+
+    /// Max waiting time per stop and/or accumulated per tour.
+    /// No waiting time restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_waiting_time: Option<WaitingTimeLimits>,
+
+    /// Specifies whether all deliveries (linehauls) should be served before any pickup (backhaul)
+    /// in the tour. Pickup and delivery jobs are not affected. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backhaul: Option<bool>,
+
     /// Specifies a list of area ids where vehicle can serve jobs.
     /// No area restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub areas: Option<Vec<Vec<AreaLimit>>>,
 }
 
+/// Specifies waiting time limits.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitingTimeLimits {
+    /// Max waiting time at a single stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_stop: Option<f64>,
+
+    /// Max waiting time accumulated per tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_tour: Option<f64>,
+}
+
 /// An area limit.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -491,9 +605,43 @@ pub struct VehicleType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
 
+    /// Vehicle tags which can be used to group vehicle types in fleet limits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// A default parking (stop setup) time applied once per stop with job activities.
+    /// Job place's parking takes precedence when specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parking: Option<f64>,
+
+    /// Vehicle physical attributes used to check access to job place locations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<VehicleAttributes>,
+
+    /// If set to true, a new vehicle of this type with `{typeId}_{index}` id is created each time
+    /// when all vehicles of the type are in use. Vehicle specific shift properties, such as breaks,
+    /// reloads, dispatch or on board jobs, are not supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_unlimited: Option<bool>,
+}
+
+/// Specifies vehicle physical attributes. Units are not fixed, but they have to match units
+/// used in job place access limits.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleAttributes {
+    /// Vehicle weight.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    /// Vehicle height.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<f64>,
+    /// Vehicle length.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length: Option<f64>,
 }
 
 /// Specifies a vehicle profile.
@@ -506,10 +654,41 @@ pub struct VehicleProfile {
     /// Default value is 1.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scale: Option<f64>,
+
+    /// Travel time buffers which make a schedule robust against small delays.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer: Option<TravelBuffer>,
+}
+
+/// Specifies travel time buffers.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TravelBuffer {
+    /// A percentage of leg travel duration added to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<f64>,
+
+    /// A fixed duration (in seconds) added to each leg.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<f64>,
+
+    /// A minimum slack (in seconds) between job activity's service start and its time window end.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slack: Option<f64>,
+}
+
+/// Specifies approximation speed at specific hour of day.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct HourlySpeed {
+    /// Hour of day in range [0, 24).
+    pub hour: f64,
+
+    /// Speed (meters per second).
+    pub speed: f64,
 }
 
 /// Specifies routing matrix profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MatrixProfile {
     /// Profile name.
     pub name: String,
@@ -518,6 +697,29 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// Approximation speed curve by hour of day. Used only when routing matrix is not specified.
+    /// When set, time dependent matrices are created for each day of the planning horizon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed_curve: Option<Vec<HourlySpeed>>,
+
+    /// A ratio of road distance to straight line distance used by approximation. Default value is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub circuity: Option<f64>,
+
+    /// A distance metric used by approximation for planar points. Default is euclidean.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric: Option<DistanceMetric>,
+}
+
+/// Specifies distance metric between planar points.
+#[derive(Clone, Deserialize, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DistanceMetric {
+    /// A straight line distance.
+    Euclidean,
+    /// A sum of absolute differences of coordinates, e.g. for grid-like aisles.
+    Manhattan,
 }
 
 /// Specifies vehicle resource type.
@@ -546,6 +748,32 @@ pub struct Fleet {
     /// Specifies vehicle resources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<Vec<VehicleResource>>,
+
+    /// Specifies limits on amount of used tours per vehicle type or tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<Vec<FleetLimit>>,
+}
+
+/// Specifies a limit on amount of tours used by vehicles of specific type or with specific tag.
+/// Exactly one of `type_id` or `tag` should be set.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetLimit {
+    /// Vehicle type id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_id: Option<String>,
+
+    /// Vehicle tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Max amount of used tours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tours: Option<usize>,
+
+    /// Min amount of used tours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_tours: Option<usize>,
 }
 
 // endregion
@@ -568,6 +796,10 @@ pub enum Objective {
     #[serde(rename(deserialize = "minimize-duration", serialize = "minimize-duration"))]
     MinimizeDuration,
 
+    /// An objective to minimize total waiting time.
+    #[serde(rename(deserialize = "minimize-waiting", serialize = "minimize-waiting"))]
+    MinimizeWaiting,
+
     /// An objective to minimize total tour amount.
     #[serde(rename(deserialize = "minimize-tours", serialize = "minimize-tours"))]
     MinimizeTours,
@@ -599,6 +831,16 @@ pub enum Objective {
         breaks: Option<f64>,
     },
 
+    /// An objective to minimize amount of unassigned jobs per priority tier: unassigned jobs of
+    /// higher priority are minimized first.
+    #[serde(rename(deserialize = "minimize-unassigned-by-priority", serialize = "minimize-unassigned-by-priority"))]
+    MinimizeUnassignedJobsByPriority {
+        /// A skipped break weight to increase/decrease break is importance.
+        /// Default is 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        breaks: Option<f64>,
+    },
+
     /// An objective to minimize sum of arrival times from all routes.
     #[serde(rename(deserialize = "minimize-arrival-time", serialize = "minimize-arrival-time"))]
     MinimizeArrivalTime,
@@ -659,6 +901,20 @@ pub enum Objective {
         #[serde(rename = "isValuePreferred")]
         is_value_preferred: Option<bool>,
     },
+
+    /// An objective to minimize amount of distinct vehicles serving jobs with the same consistency key.
+    #[serde(rename(deserialize = "minimize-inconsistency", serialize = "minimize-inconsistency"))]
+    MinimizeInconsistency,
+
+    /// An objective to minimize changes of the plan compared to a reference one: a previous solution
+    /// which is supplied together with the problem.
+    #[serde(rename(deserialize = "minimize-plan-changes", serialize = "minimize-plan-changes"))]
+    MinimizePlanChanges {
+        /// If the property is set to true, then changes in job sequence are counted too.
+        #[serde(rename = "isSequenceAware")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        is_sequence_aware: Option<bool>,
+    },
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -687,11 +943,27 @@ pub struct Problem {
     /// Specifies objective function hierarchy.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub objectives: Option<Vec<Vec<Objective>>>,
+
+    /// Specifies numeric precision of routing data and solution values. Default is integer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precision: Option<Precision>,
 }
 
-/// A routing matrix.
-#[derive(Clone, Deserialize, Debug, Serialize)]
+/// Specifies numeric precision of durations and distances.
+#[derive(Clone, Default, Deserialize, Debug, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
+pub enum Precision {
+    /// Durations and distances are whole seconds and meters.
+    #[default]
+    Integer,
+    /// Durations and distances can have fractional part: matrices accept floats, solution reports
+    /// times with sub-second precision while accumulated distances and durations are rounded.
+    Float,
+}
+
+/// A routing matrix.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(from = "MatrixRepr")]
 pub struct Matrix {
     /// A name of profile.
     pub profile: Option<String>,
@@ -700,15 +972,208 @@ pub struct Matrix {
     pub timestamp: Option<String>,
 
     /// Travel distances (used to be in seconds).
-    #[serde(alias = "durations")]
     pub travel_times: Vec<i64>,
 
     /// Travel durations (use to be in meters).
     pub distances: Vec<i64>,
 
     /// Error codes to mark unreachable locations.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// Extra travel costs, e.g. tolls or road pricing, added to the cost of each leg.
+    pub costs: Option<Vec<f64>>,
+
+    /// An interpolation between matrices of the same profile with different timestamps. Default is linear.
+    pub interpolation: Option<TimeInterpolation>,
+
+    /// Specifies whether values are stored internally as 32 bit floats to reduce memory usage at cost
+    /// of precision. Default is false.
+    pub compact: Option<bool>,
+
+    /// Travel times and distances with fractional part, they are used instead of integer ones only with
+    /// float precision. In json, they are kept within `travelTimes` and `distances`.
+    pub fractions: Option<MatrixFractions>,
+}
+
+/// Keeps routing matrix values with fractional part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixFractions {
+    /// Travel times.
+    pub travel_times: Vec<f64>,
+
+    /// Travel distances.
+    pub distances: Vec<f64>,
+}
+
+impl Matrix {
+    /// Returns travel time at given index, a fractional value is preferred when present.
+    pub fn get_travel_time(&self, idx: usize) -> Option<f64> {
+        self.fractions
+            .as_ref()
+            .map_or_else(|| self.travel_times.get(idx).map(|&value| value as f64), |f| f.travel_times.get(idx).cloned())
+    }
+
+    /// Returns distance at given index, a fractional value is preferred when present.
+    pub fn get_distance(&self, idx: usize) -> Option<f64> {
+        self.fractions
+            .as_ref()
+            .map_or_else(|| self.distances.get(idx).map(|&value| value as f64), |f| f.distances.get(idx).cloned())
+    }
+}
+
+/// Splits travel times and distances into integer values and, when some of them have fractional part,
+/// fractional values.
+pub fn split_matrix_values(
+    travel_times: Vec<f64>,
+    distances: Vec<f64>,
+) -> (Vec<i64>, Vec<i64>, Option<MatrixFractions>) {
+    let to_integers = |values: &[f64]| values.iter().map(|value| value.round() as i64).collect::<Vec<_>>();
+    let has_fractions = travel_times.iter().chain(distances.iter()).any(|value| value.fract() != 0.);
+
+    let (integer_times, integer_distances) = (to_integers(&travel_times), to_integers(&distances));
+    let fractions = if has_fractions { Some(MatrixFractions { travel_times, distances }) } else { None };
+
+    (integer_times, integer_distances, fractions)
+}
+
+/// A json representation of the routing matrix which accepts fractional travel times and distances.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MatrixRepr {
+    profile: Option<String>,
+    timestamp: Option<String>,
+    #[serde(alias = "durations")]
+    travel_times: MatrixValues,
+    distances: MatrixValues,
+    error_codes: Option<Vec<i64>>,
+    costs: Option<Vec<f64>>,
+    interpolation: Option<TimeInterpolation>,
+    compact: Option<bool>,
+}
+
+impl From<MatrixRepr> for Matrix {
+    fn from(repr: MatrixRepr) -> Self {
+        let (travel_times, distances, fractions) = split_matrix_values(repr.travel_times.0, repr.distances.0);
+
+        Matrix {
+            profile: repr.profile,
+            timestamp: repr.timestamp,
+            travel_times,
+            distances,
+            error_codes: repr.error_codes,
+            costs: repr.costs,
+            interpolation: repr.interpolation,
+            compact: repr.compact,
+            fractions,
+        }
+    }
+}
+
+impl Serialize for Matrix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct MatrixReprRef<'a> {
+            profile: &'a Option<String>,
+            timestamp: &'a Option<String>,
+            travel_times: MatrixValuesRef<'a>,
+            distances: MatrixValuesRef<'a>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            error_codes: Option<&'a Vec<i64>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            costs: Option<&'a Vec<f64>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            interpolation: Option<&'a TimeInterpolation>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            compact: Option<bool>,
+        }
+
+        let (travel_times, distances) = match self.fractions.as_ref() {
+            Some(fractions) => {
+                (MatrixValuesRef::Fractions(&fractions.travel_times), MatrixValuesRef::Fractions(&fractions.distances))
+            }
+            None => (MatrixValuesRef::Integers(&self.travel_times), MatrixValuesRef::Integers(&self.distances)),
+        };
+
+        MatrixReprRef {
+            profile: &self.profile,
+            timestamp: &self.timestamp,
+            travel_times,
+            distances,
+            error_codes: self.error_codes.as_ref(),
+            costs: self.costs.as_ref(),
+            interpolation: self.interpolation.as_ref(),
+            compact: self.compact,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Matrix values which can be either integers or floats in json.
+struct MatrixValues(Vec<f64>);
+
+impl<'de> Deserialize<'de> for MatrixValues {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ValuesVisitor;
+
+        impl<'de> Visitor<'de> for ValuesVisitor {
+            type Value = MatrixValues;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a list of numbers")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut values = Vec::new();
+                while let Some(value) = seq.next_element::<f64>()? {
+                    values.push(value);
+                }
+
+                Ok(MatrixValues(values))
+            }
+        }
+
+        deserializer.deserialize_seq(ValuesVisitor)
+    }
+}
+
+/// Matrix values to be serialized: integers or, when matrix has fractional values, floats.
+enum MatrixValuesRef<'a> {
+    Integers(&'a [i64]),
+    Fractions(&'a [f64]),
+}
+
+impl Serialize for MatrixValuesRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum Measure {
+            Integer(i64),
+            Float(f64),
+        }
+
+        match self {
+            Self::Integers(values) => values.serialize(serializer),
+            // NOTE keep values without fractional part as integers, so they preserve their original representation
+            Self::Fractions(values) => serializer.collect_seq(values.iter().map(|&value| {
+                if value.fract() == 0. && value.abs() < i64::MAX as f64 {
+                    Measure::Integer(value as i64)
+                } else {
+                    Measure::Float(value)
+                }
+            })),
+        }
+    }
+}
+
+/// Specifies how routing data is interpolated between matrices with different timestamps.
+#[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimeInterpolation {
+    /// Values of the latest matrix with timestamp not after departure time are used.
+    Step,
+    /// Values are interpolated linearly between two adjacent matrices.
+    Linear,
 }
 
 // endregion
@@ -724,8 +1189,41 @@ pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Vec
     })
 }
 
-/// Deserializes routing matrix in json format from `BufReader`.
+/// Deserializes routing matrix in json or binary format from `BufReader`.
 pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, Vec<FormatError>> {
+    let mut reader = reader;
+
+    // NOTE a single read can return less bytes than signature has, so keep reading until it is complete
+    let mut signature = [0_u8; 4];
+    let mut length = 0;
+    while length < signature.len() {
+        match reader.read(&mut signature[length..]) {
+            Ok(0) => break,
+            Ok(read) => length += read,
+            Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+            Err(err) => {
+                return Err(vec![FormatError::new(
+                    "E0001".to_string(),
+                    "cannot deserialize matrix".to_string(),
+                    format!("cannot read input: '{}'", err),
+                )])
+            }
+        }
+    }
+
+    let is_binary = is_binary_matrix(&signature[..length]);
+    let reader = (&signature[..length]).chain(reader);
+
+    if is_binary {
+        return deserialize_matrix_binary(reader).map_err(|err| {
+            vec![FormatError::new(
+                "E0001".to_string(),
+                "cannot deserialize matrix".to_string(),
+                format!("check input binary matrix: '{}'", err),
+            )]
+        });
+    }
+
     serde_json::from_reader(reader).map_err(|err| {
         vec![FormatError::new(
             "E0001".to_string(),
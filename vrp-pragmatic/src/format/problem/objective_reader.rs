@@ -2,27 +2,33 @@
 #[path = "../../../tests/unit/format/problem/objective_reader_test.rs"]
 mod objective_reader_test;
 
-use crate::constraints::{AreaModule, TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
+use crate::constraints::*;
 use crate::extensions::{JobTie, VehicleTie};
-use crate::format::problem::reader::{ApiProblem, ProblemProperties};
+use crate::format::problem::reader::fleet_reader::read_fleet_limits;
+use crate::format::problem::reader::{ApiProblem, ApiSolution, ProblemProperties};
 use crate::format::problem::BalanceOptions;
 use crate::format::problem::Objective::TourOrder as FormatTourOrder;
 use crate::format::problem::Objective::*;
 use crate::format::{AREA_CONSTRAINT_CODE, TOUR_ORDER_CONSTRAINT_CODE};
+use crate::validation::is_reserved_job_id;
+use hashbrown::HashMap;
 use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterDimension;
 use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule};
-use vrp_core::models::common::{MultiDimLoad, SingleDimLoad};
+use vrp_core::models::common::{MultiDimLoad, SingleDimLoad, Timestamp};
 use vrp_core::models::problem::Job;
 use vrp_core::models::problem::{ProblemObjective, Single, TargetConstraint, TargetObjective};
 use vrp_core::solver::objectives::MinimizeArrivalTime as CoreMinimizeArrivalTime;
 use vrp_core::solver::objectives::TourOrder as CoreTourOrder;
 use vrp_core::solver::objectives::*;
 
+const DEFAULT_JOB_PRIORITY: usize = 1;
+
 pub fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
     props: &ProblemProperties,
+    reference: Option<&ApiSolution>,
 ) -> Arc<ProblemObjective> {
     Arc::new(match &api_problem.objectives {
         Some(objectives) => ProblemObjective::new(
@@ -34,6 +40,7 @@ pub fn create_objective(
                         MinimizeCost => core_objectives.push(TotalCost::minimize()),
                         MinimizeDistance => core_objectives.push(TotalDistance::minimize()),
                         MinimizeDuration => core_objectives.push(TotalDuration::minimize()),
+                        MinimizeWaiting => core_objectives.push(TotalWaiting::minimize()),
                         MinimizeTours => {
                             constraint.add_module(Arc::new(FleetUsageConstraintModule::new_minimized()));
                             core_objectives.push(Arc::new(TotalRoutes::new_minimized()))
@@ -57,6 +64,12 @@ pub fn create_objective(
                                 core_objectives.push(Arc::new(get_unassigned_objective(1.)))
                             }
                         }
+                        MinimizeUnassignedJobsByPriority { breaks } => {
+                            let (module, objective) =
+                                get_prioritized_unassigned_objective(api_problem, breaks.unwrap_or(1.));
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
                         MinimizeArrivalTime => {
                             constraint.add_module(Arc::new(FleetUsageConstraintModule::new_earliest()));
                             core_objectives.push(Arc::new(CoreMinimizeArrivalTime::default()))
@@ -97,17 +110,32 @@ pub fn create_objective(
                             constraint.add_module(module);
                             objectives.into_iter().for_each(|objective| core_objectives.push(objective));
                         }
+                        MinimizeInconsistency => {
+                            let (module, objective) = ConsistencyModule::new_minimized(-1, CONSISTENCY_KEY);
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizePlanChanges { is_sequence_aware } => {
+                            let (module, objective) = get_plan_changes(reference, is_sequence_aware.unwrap_or(false));
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
                     });
                     core_objectives
                 })
                 .collect(),
         ),
         None => {
-            let mut objectives: Vec<Vec<TargetObjective>> = vec![
-                vec![Arc::new(get_unassigned_objective(1.))],
-                vec![Arc::new(TotalRoutes::default())],
-                vec![TotalCost::minimize()],
-            ];
+            let unassigned_objective: TargetObjective = if props.has_priorities {
+                let (module, objective) = get_prioritized_unassigned_objective(api_problem, 1.);
+                constraint.add_module(module);
+                objective
+            } else {
+                Arc::new(get_unassigned_objective(1.))
+            };
+
+            let mut objectives: Vec<Vec<TargetObjective>> =
+                vec![vec![unassigned_objective], vec![Arc::new(TotalRoutes::default())], vec![TotalCost::minimize()]];
             constraint.add_module(Arc::new(FleetUsageConstraintModule::new_minimized()));
 
             if let Some(max_value) = props.max_job_value {
@@ -122,6 +150,19 @@ pub fn create_objective(
                 objectives.insert(if props.max_job_value.is_some() { 2 } else { 1 }, vec![order_objective]);
             }
 
+            if props.has_fleet_limits {
+                let limits = read_fleet_limits(api_problem);
+                if limits.iter().any(|limit| limit.min_tours.is_some()) {
+                    objectives.insert(objectives.len() - 2, vec![FleetLimitsModule::new_objective(Arc::new(limits))]);
+                }
+            }
+
+            if props.has_consistency {
+                let (consistency_module, consistency_objective) = ConsistencyModule::new_minimized(-1, CONSISTENCY_KEY);
+                constraint.add_module(consistency_module);
+                objectives.insert(objectives.len() - 2, vec![consistency_objective]);
+            }
+
             ProblemObjective::new(objectives)
         }
     })
@@ -138,13 +179,26 @@ fn get_value(
 ) -> (TargetConstraint, TargetObjective) {
     // NOTE make it negative
     let break_value = -1. * breaks.unwrap_or(100.);
-    TotalValue::maximize(
+    TotalValue::maximize_with_schedule(
         max_value,
         reduction_factor.unwrap_or(0.1),
         Arc::new(move |solution| {
             solution.unassigned.iter().map(|(job, _)| get_unassigned_job_estimate(job, break_value, 0.)).sum()
         }),
-        ValueFn::Left(Arc::new(|job| job.dimens().get_job_value().unwrap_or(0.))),
+        // NOTE time dependent value is estimated by its time averaged value
+        ValueFn::Left(Arc::new(|job| {
+            job.dimens()
+                .get_job_value_curve()
+                .map(|curve| get_value_curve_average(curve.as_slice()))
+                .or_else(|| job.dimens().get_job_value())
+                .unwrap_or(0.)
+        })),
+        Some(ScheduleValue {
+            is_scheduled_func: Arc::new(|job| job.dimens().get_job_value_curve().is_some()),
+            value_func: Arc::new(|job, time| {
+                job.dimens().get_job_value_curve().map_or(0., |curve| get_value_curve_value(curve.as_slice(), time))
+            }),
+        }),
         Arc::new(|job, value| match job {
             Job::Single(single) => {
                 let mut dimens = single.dimens.clone();
@@ -159,6 +213,43 @@ fn get_value(
     )
 }
 
+/// Returns value of piecewise-linear function defined by sorted points at given time.
+fn get_value_curve_value(curve: &[(Timestamp, f64)], time: Timestamp) -> f64 {
+    match (curve.first(), curve.last()) {
+        (Some(&(first_time, first_value)), _) if time <= first_time => first_value,
+        (_, Some(&(last_time, last_value))) if time >= last_time => last_value,
+        _ => curve
+            .windows(2)
+            .find(|pair| time < pair[1].0)
+            .map(|pair| {
+                let ((start_time, start_value), (end_time, end_value)) = (pair[0], pair[1]);
+                start_value + (end_value - start_value) * (time - start_time) / (end_time - start_time)
+            })
+            .unwrap_or(0.),
+    }
+}
+
+/// Returns time averaged value of piecewise-linear function defined by sorted points.
+fn get_value_curve_average(curve: &[(Timestamp, f64)]) -> f64 {
+    match (curve.first(), curve.last()) {
+        (Some(&(first_time, first_value)), Some(&(last_time, _))) => {
+            if last_time > first_time {
+                curve
+                    .windows(2)
+                    .map(|pair| {
+                        let ((start_time, start_value), (end_time, end_value)) = (pair[0], pair[1]);
+                        (start_value + end_value) / 2. * (end_time - start_time)
+                    })
+                    .sum::<f64>()
+                    / (last_time - first_time)
+            } else {
+                first_value
+            }
+        }
+        _ => 0.,
+    }
+}
+
 fn get_order(is_constrained: bool) -> (TargetConstraint, TargetObjective) {
     let order_fn = OrderFn::Left(Arc::new(|single| {
         single
@@ -175,6 +266,45 @@ fn get_order(is_constrained: bool) -> (TargetConstraint, TargetObjective) {
     }
 }
 
+fn get_plan_changes(reference: Option<&ApiSolution>, is_sequence_aware: bool) -> (TargetConstraint, TargetObjective) {
+    let tours = reference.map(|reference| reference.tours.as_slice()).unwrap_or_default();
+
+    let tour_index = tours
+        .iter()
+        .enumerate()
+        .map(|(idx, tour)| ((tour.vehicle_id.clone(), tour.shift_index), idx))
+        .collect::<HashMap<_, _>>();
+
+    // NOTE multi job is listed once per its task: keep position of the first one
+    let job_index = tours.iter().enumerate().fold(HashMap::new(), |mut acc, (idx, tour)| {
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .map(|activity| &activity.job_id)
+            .filter(|job_id| !is_reserved_job_id(job_id))
+            .enumerate()
+            .for_each(|(position, job_id)| {
+                acc.entry(job_id.clone()).or_insert((idx, position));
+            });
+        acc
+    });
+
+    PlanChanges::minimize(
+        Arc::new(move |job| job.dimens().get_job_id().and_then(|job_id| job_index.get(job_id)).cloned()),
+        Arc::new(move |actor| {
+            let dimens = &actor.vehicle.dimens;
+            dimens
+                .get_vehicle_id()
+                .zip(dimens.get_shift_index())
+                .and_then(|(vehicle_id, shift_index)| tour_index.get(&(vehicle_id.clone(), shift_index)))
+                .cloned()
+        }),
+        is_sequence_aware,
+        PLAN_CHANGES_KEY,
+        -1,
+    )
+}
+
 fn get_area(
     max_value: f64,
     break_value: Option<f64>,
@@ -243,6 +373,26 @@ fn get_unassigned_objective(break_value: f64) -> TotalUnassignedJobs {
     TotalUnassignedJobs::new(Arc::new(move |_, job, _| get_unassigned_job_estimate(job, break_value, 1.)))
 }
 
+fn get_prioritized_unassigned_objective(
+    api_problem: &ApiProblem,
+    break_value: f64,
+) -> (TargetConstraint, TargetObjective) {
+    // NOTE jobs without priority and breaks belong to the highest priority tier
+    let priorities = api_problem
+        .plan
+        .jobs
+        .iter()
+        .map(|job| job.priority.unwrap_or(DEFAULT_JOB_PRIORITY))
+        .chain(std::iter::once(DEFAULT_JOB_PRIORITY))
+        .collect::<Vec<_>>();
+
+    PrioritizedUnassignedJobs::new_constrained(
+        priorities.as_slice(),
+        Arc::new(|job| job.dimens().get_job_priority().unwrap_or(DEFAULT_JOB_PRIORITY)),
+        Arc::new(move |_, job, _| get_unassigned_job_estimate(job, break_value, 1.)),
+    )
+}
+
 fn get_unassigned_job_estimate(job: &Job, break_value: f64, default_value: f64) -> f64 {
     if let Some(clusters) = job.dimens().get_cluster() {
         clusters.len() as f64 * default_value
@@ -15,19 +15,19 @@ mod objective_reader;
 mod clustering_reader;
 
 use self::clustering_reader::create_cluster_config;
-use self::fleet_reader::{create_transport_costs, read_fleet};
+pub(crate) use self::fleet_reader::get_profile_index_map;
+use self::fleet_reader::{create_transport_costs, read_fleet, read_fleet_limits};
 use self::job_reader::{read_jobs_with_extra_locks, read_locks};
 use self::objective_reader::create_objective;
 use crate::constraints::*;
-use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost, VehicleTie};
+use crate::extensions::{get_route_modifier, JobTie, OnlyVehicleActivityCost, VehicleTie};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::*;
 use crate::format::*;
-use crate::utils::get_approx_transportation;
+use crate::utils::get_approx_distances;
 use crate::validation::ValidationContext;
-use crate::{get_unique_locations, parse_time};
-use hashbrown::HashSet;
-use std::cmp::Ordering::Equal;
+use crate::{format_time, get_unique_locations, parse_day_start_safe, parse_time, parse_time_safe};
+use hashbrown::HashMap;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
@@ -39,8 +39,11 @@ use vrp_core::rosomaxa::utils::CollectGroupBy;
 use vrp_core::solver::processing::VicinityDimension;
 
 pub type ApiProblem = crate::format::problem::Problem;
+pub type ApiSolution = crate::format::solution::Solution;
 pub type CoreFleet = vrp_core::models::problem::Fleet;
 
+const SECONDS_IN_DAY: f64 = 86400.;
+
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
     /// Reads problem defined in pragmatic format.
@@ -101,6 +104,20 @@ impl PragmaticProblem for ApiProblem {
     }
 }
 
+impl PragmaticProblem for (ApiProblem, &RoadNetwork) {
+    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        let matrices = create_road_network_matrices(&self.0, self.1).map_err(|err| {
+            vec![FormatError::new(
+                "E0002".to_string(),
+                "cannot create transport costs".to_string(),
+                format!("check road network: '{}'", err),
+            )]
+        })?;
+
+        map_to_problem_with_matrices(self.0, matrices)
+    }
+}
+
 impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
         if let Some(matrices) = self.1 {
@@ -111,75 +128,195 @@ impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
     }
 }
 
+/// Reads problem with a reference solution used by `minimize-plan-changes` objective.
+impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>, &ApiSolution) {
+    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        let (problem, matrices, reference) = self;
+        let coord_index = CoordIndex::new(&problem);
+        let matrices = matrices.unwrap_or_else(|| {
+            if coord_index.get_used_types().2 {
+                vec![]
+            } else {
+                create_approx_matrices(&problem)
+            }
+        });
+
+        map_to_problem(problem, matrices, coord_index, Some(reference))
+    }
+}
+
 pub struct ProblemProperties {
     has_multi_dimen_capacity: bool,
     has_breaks: bool,
     has_skills: bool,
+    has_access_limits: bool,
     has_unreachable_locations: bool,
     has_dispatch: bool,
     has_reloads: bool,
     has_order: bool,
     has_group: bool,
     has_compatibility: bool,
+    has_consistency: bool,
+    has_priorities: bool,
+    has_frequency: bool,
     has_tour_size_limits: bool,
+    has_backhaul: bool,
+    has_fleet_limits: bool,
     has_tour_travel_limits: bool,
+    has_waiting_limits: bool,
     max_job_value: Option<f64>,
     max_area_value: Option<f64>,
 }
 
 /// Creates a matrices using approximation.
 pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
+    let locations = get_unique_locations(problem);
+    let mut metric_distances = HashMap::<DistanceMetric, Vec<f64>>::new();
+
+    create_profile_matrices(problem, |profile| {
+        let metric = profile.metric.clone().unwrap_or(DistanceMetric::Euclidean);
+
+        metric_distances.entry(metric).or_insert_with_key(|metric| get_approx_distances(&locations, metric)).clone()
+    })
+}
+
+/// Creates a matrices using approximation with given distances between unique problem locations instead of
+/// the ones defined by profile metric. Profile speed, speed curve and circuity are still applied.
+pub fn create_approx_matrices_with_distances(problem: &ApiProblem, distances: &[f64]) -> Vec<Matrix> {
+    create_profile_matrices(problem, |_| distances.to_vec())
+}
+
+fn create_profile_matrices<F>(problem: &ApiProblem, mut get_distances: F) -> Vec<Matrix>
+where
+    F: FnMut(&MatrixProfile) -> Vec<f64>,
+{
     const DEFAULT_SPEED: f64 = 10.;
-    // get each speed value once
-    let speeds = problem
+    const SECONDS_IN_HOUR: f64 = 3600.;
+
+    let horizon = get_speed_curve_horizon(problem);
+
+    problem
         .fleet
         .profiles
         .iter()
-        .map(|profile| profile.speed.unwrap_or(DEFAULT_SPEED))
-        .map(|speed| speed.to_bits())
-        .collect::<HashSet<u64>>();
-    let speeds = speeds.into_iter().map(f64::from_bits).collect::<Vec<_>>();
+        .flat_map(|profile| {
+            let circuity = profile.circuity.unwrap_or(1.);
+            let distances = get_distances(profile).into_iter().map(|distance| distance * circuity).collect::<Vec<_>>();
+            let create_matrix = |timestamp: Option<f64>, speed: f64| {
+                let (travel_times, distances, fractions) =
+                    split_matrix_values(distances.iter().map(|distance| distance / speed).collect(), distances.clone());
+
+                apply_matrix_precision(
+                    problem,
+                    Matrix {
+                        profile: Some(profile.name.clone()),
+                        timestamp: timestamp.map(format_time),
+                        travel_times,
+                        distances,
+                        error_codes: None,
+                        costs: None,
+                        interpolation: None,
+                        compact: None,
+                        fractions,
+                    },
+                )
+            };
+
+            match (horizon, profile.speed_curve.as_ref()) {
+                (Some((day_start, days)), Some(speed_curve)) => (0..days)
+                    .flat_map(|day| {
+                        speed_curve.iter().map(move |point| {
+                            (day_start + day as f64 * SECONDS_IN_DAY + point.hour * SECONDS_IN_HOUR, point.speed)
+                        })
+                    })
+                    .map(|(timestamp, speed)| create_matrix(Some(timestamp), speed))
+                    .collect::<Vec<_>>(),
+                // NOTE time aware routing requires all profiles to have at least two matrices with timestamps
+                (Some((day_start, days)), None) => {
+                    let speed = profile.speed.unwrap_or(DEFAULT_SPEED);
+                    vec![
+                        create_matrix(Some(day_start), speed),
+                        create_matrix(Some(day_start + days as f64 * SECONDS_IN_DAY), speed),
+                    ]
+                }
+                (None, _) => vec![create_matrix(None, profile.speed.unwrap_or(DEFAULT_SPEED))],
+            }
+        })
+        .collect()
+}
+
+/// Returns start of the first day and amount of days in planning horizon if any profile has speed curve.
+fn get_speed_curve_horizon(problem: &ApiProblem) -> Option<(f64, usize)> {
+    if problem.fleet.profiles.iter().all(|profile| profile.speed_curve.is_none()) {
+        return None;
+    }
+
+    let shifts = problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter());
+
+    let day_start = get_horizon_day_start(problem);
 
+    let horizon_end = shifts
+        .flat_map(|shift| {
+            std::iter::once(&shift.start.earliest)
+                .chain(shift.start.latest.iter())
+                .chain(shift.end.iter().map(|end| &end.latest))
+        })
+        .filter_map(|time| parse_time_safe(time).ok())
+        .max_by(|a, b| compare_floats(*a, *b))
+        .unwrap_or(day_start);
+
+    let days = ((horizon_end - day_start).max(0.) / SECONDS_IN_DAY).floor() as usize + 1;
+
+    Some((day_start, days))
+}
+
+/// Returns start of the day, in its own time offset, of the earliest vehicle shift start.
+fn get_horizon_day_start(problem: &ApiProblem) -> f64 {
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .filter_map(|shift| parse_time_safe(&shift.start.earliest).ok().map(|time| (time, &shift.start.earliest)))
+        .min_by(|(a, _), (b, _)| compare_floats(*a, *b))
+        .and_then(|(_, earliest)| parse_day_start_safe(earliest).ok())
+        .unwrap_or(0.)
+}
+
+/// Creates a matrices using shortest paths on road network.
+pub fn create_road_network_matrices(problem: &ApiProblem, network: &RoadNetwork) -> Result<Vec<Matrix>, String> {
     let locations = get_unique_locations(problem);
-    let approx_data = get_approx_transportation(&locations, speeds.as_slice());
 
     problem
         .fleet
         .profiles
         .iter()
-        .map(move |profile| {
-            let speed = profile.speed.unwrap_or(DEFAULT_SPEED);
-            let idx =
-                speeds.iter().position(|s| compare_floats(*s, speed) == Equal).expect("Cannot find profile speed");
-
-            Matrix {
-                profile: Some(profile.name.clone()),
-                timestamp: None,
-                travel_times: approx_data[idx].0.clone(),
-                distances: approx_data[idx].1.clone(),
-                error_codes: None,
-            }
+        .map(|profile| {
+            network
+                .get_matrix(profile.name.as_str(), locations.as_slice())
+                .map(|matrix| apply_matrix_precision(problem, matrix))
         })
         .collect()
 }
 
 fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
-    let matrices = if coord_index.get_used_types().1 { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+    let matrices = if coord_index.get_used_types().2 { vec![] } else { create_approx_matrices(&problem) };
+    map_to_problem(problem, matrices, coord_index, None)
 }
 
 fn map_to_problem_with_matrices(problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, None)
 }
 
 fn map_to_problem(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
+    reference: Option<&ApiSolution>,
 ) -> Result<Problem, Vec<FormatError>> {
-    ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
+    ValidationContext::new(&api_problem, Some(&matrices), &coord_index).with_reference(reference).validate()?;
 
     let problem_props = get_problem_properties(&api_problem, &matrices);
 
@@ -214,6 +351,7 @@ fn map_to_problem(
                 |(transport, activity)| (Arc::new(transport), Arc::new(activity)),
             )?
     };
+    let activity = create_setup_activity_cost(&api_problem, &coord_index, activity);
 
     // TODO pass random from outside as there might be need to have it initialized with seed
     //      at the moment, this random instance is used only by multi job permutation generator
@@ -231,6 +369,7 @@ fn map_to_problem(
     let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect::<Vec<_>>();
     let mut constraint = create_constraint_pipeline(
         &api_problem,
+        &coord_index,
         &jobs,
         &job_index,
         &fleet,
@@ -240,7 +379,7 @@ fn map_to_problem(
         &locks,
     );
 
-    let objective = create_objective(&api_problem, &mut constraint, &problem_props);
+    let objective = create_objective(&api_problem, &mut constraint, &problem_props, reference);
     let constraint = Arc::new(constraint);
     let extras = Arc::new(
         create_extras(&api_problem, constraint.clone(), &problem_props, job_index, coord_index, reserved_times_index)
@@ -314,9 +453,57 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
         .collect()
 }
 
+fn create_setup_activity_cost(
+    api_problem: &ApiProblem,
+    coord_index: &CoordIndex,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Arc<dyn ActivityCost + Send + Sync> {
+    let vehicle_parking = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.parking.map(|parking| (vehicle.type_id.clone(), parking)))
+        .collect::<HashMap<_, _>>();
+
+    if vehicle_parking.is_empty() && get_location_parking(api_problem, coord_index).is_empty() {
+        return activity;
+    }
+
+    Arc::new(SetupActivityCost::new(
+        activity,
+        Arc::new(move |route, activity| {
+            // NOTE only jobs from the plan have place parkings set, other activities have no setup time
+            activity
+                .job
+                .as_ref()
+                .and_then(|single| single.dimens.get_place_parkings().map(|parkings| (single, parkings)))
+                .map(|(single, parkings)| {
+                    single
+                        .places
+                        .iter()
+                        .position(|place| place.location == Some(activity.place.location))
+                        .and_then(|place_idx| parkings.iter().find(|(idx, _)| *idx == place_idx))
+                        .map(|(_, parking)| *parking)
+                        .or_else(|| {
+                            route
+                                .actor
+                                .vehicle
+                                .dimens
+                                .get_vehicle_type()
+                                .and_then(|type_id| vehicle_parking.get(type_id))
+                                .cloned()
+                        })
+                        .unwrap_or(0.)
+                })
+                .unwrap_or(0.)
+        }),
+    ))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn create_constraint_pipeline(
     api_problem: &ApiProblem,
+    coord_index: &CoordIndex,
     jobs: &Jobs,
     job_index: &JobIndex,
     fleet: &CoreFleet,
@@ -347,6 +534,10 @@ fn create_constraint_pipeline(
         constraint.add_module(Arc::new(BreakModule::new(BREAK_CONSTRAINT_CODE)));
     }
 
+    if props.has_waiting_limits {
+        add_waiting_limit_module(&mut constraint, transport.clone(), activity, api_problem);
+    }
+
     if props.has_compatibility {
         constraint.add_module(Arc::new(CompatibilityModule::new(COMPATIBILITY_CONSTRAINT_CODE, COMPATIBILITY_KEY)));
     }
@@ -359,6 +550,14 @@ fn create_constraint_pipeline(
         constraint.add_module(Arc::new(SkillsModule::new(SKILL_CONSTRAINT_CODE)));
     }
 
+    if props.has_access_limits {
+        add_access_module(&mut constraint, api_problem, coord_index);
+    }
+
+    if props.has_frequency {
+        add_frequency_module(&mut constraint, api_problem);
+    }
+
     if props.has_dispatch {
         constraint.add_module(Arc::new(DispatchModule::new(DISPATCH_CONSTRAINT_CODE)));
     }
@@ -371,6 +570,18 @@ fn create_constraint_pipeline(
         add_tour_size_module(&mut constraint)
     }
 
+    if props.has_backhaul {
+        constraint.add_module(Arc::new(BackhaulModule::new(BACKHAUL_CONSTRAINT_CODE, BACKHAUL_KEY)));
+    }
+
+    if props.has_fleet_limits {
+        constraint.add_module(Arc::new(FleetLimitsModule::new(
+            Arc::new(read_fleet_limits(api_problem)),
+            FLEET_LIMITS_CONSTRAINT_CODE,
+            FLEET_LIMITS_KEY,
+        )));
+    }
+
     constraint
 }
 
@@ -443,10 +654,43 @@ fn add_capacity_with_reload<T: LoadOps + SharedResource>(
     };
 }
 
+fn add_frequency_module(constraint: &mut ConstraintPipeline, api_problem: &ApiProblem) {
+    // NOTE day indices are counted from the start of the day of the earliest vehicle shift in its time offset
+    let horizon_start = get_horizon_day_start(api_problem);
+
+    constraint.add_module(Arc::new(FrequencyModule::new(horizon_start, FREQUENCY_CONSTRAINT_CODE, FREQUENCY_KEY)));
+}
+
+fn add_access_module(constraint: &mut ConstraintPipeline, api_problem: &ApiProblem, coord_index: &CoordIndex) {
+    let limits = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(get_job_tasks)
+        .flat_map(|task| task.places.iter())
+        .filter_map(|place| place.access.as_ref().map(|access| (&place.location, access)))
+        .filter_map(|(location, access)| coord_index.get_by_loc(location).map(|location| (location, access)))
+        .fold(HashMap::<usize, AccessLimits>::new(), |mut acc, (location, access)| {
+            let limits = AccessLimits {
+                max_weight: access.max_weight,
+                max_height: access.max_height,
+                max_length: access.max_length,
+            };
+            // NOTE use the most restrictive limits when different values are specified for the same location
+            let entry = acc.entry(location).or_default();
+            *entry = entry.combine(&limits);
+            acc
+        });
+
+    constraint.add_module(Arc::new(AccessModule::new(limits, VEHICLE_ACCESS_CONSTRAINT_CODE)));
+}
+
 fn add_tour_size_module(constraint: &mut ConstraintPipeline) {
-    constraint.add_module(Arc::new(TourSizeModule::new(
+    constraint.add_module(Arc::new(TourSizeModule::new_with_min_limits(
         Arc::new(|actor| actor.vehicle.dimens.get_tour_size()),
+        Arc::new(|actor| actor.vehicle.dimens.get_min_tour_size()),
         TOUR_SIZE_CONSTRAINT_CODE,
+        MIN_TOUR_SIZE_CONSTRAINT_CODE,
     )));
 }
 
@@ -455,22 +699,29 @@ fn add_tour_limit_module(
     transport: Arc<dyn TransportCost + Send + Sync>,
     api_problem: &ApiProblem,
 ) {
-    let (distances, durations) = api_problem
+    let (distances, durations, min_durations) = api_problem
         .fleet
         .vehicles
         .iter()
         .filter_map(|vehicle| vehicle.limits.as_ref().map(|limits| (vehicle, limits)))
-        .fold((HashMap::new(), HashMap::new()), |(mut distances, mut durations), (vehicle, limits)| {
-            limits.max_distance.iter().for_each(|max_distance| {
-                distances.insert(vehicle.type_id.clone(), *max_distance);
-            });
-
-            limits.shift_time.iter().for_each(|shift_time| {
-                durations.insert(vehicle.type_id.clone(), *shift_time);
-            });
-
-            (distances, durations)
-        });
+        .fold(
+            (HashMap::new(), HashMap::new(), HashMap::new()),
+            |(mut distances, mut durations, mut min_durations), (vehicle, limits)| {
+                limits.max_distance.iter().for_each(|max_distance| {
+                    distances.insert(vehicle.type_id.clone(), *max_distance);
+                });
+
+                limits.shift_time.iter().for_each(|shift_time| {
+                    durations.insert(vehicle.type_id.clone(), *shift_time);
+                });
+
+                limits.min_shift_time.iter().for_each(|min_shift_time| {
+                    min_durations.insert(vehicle.type_id.clone(), *min_shift_time);
+                });
+
+                (distances, durations, min_durations)
+            },
+        );
 
     let get_limit = |limit_map: HashMap<String, f64>| {
         Arc::new(move |actor: &Actor| {
@@ -478,12 +729,54 @@ fn add_tour_limit_module(
         })
     };
 
-    constraint.add_module(Arc::new(TravelLimitModule::new(
+    constraint.add_module(Arc::new(TravelLimitModule::new_with_min_limits(
         transport.clone(),
         get_limit(distances),
         get_limit(durations),
+        get_limit(min_durations),
         DISTANCE_LIMIT_CONSTRAINT_CODE,
         DURATION_LIMIT_CONSTRAINT_CODE,
+        MIN_SHIFT_TIME_CONSTRAINT_CODE,
+    )));
+}
+
+fn add_waiting_limit_module(
+    constraint: &mut ConstraintPipeline,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    api_problem: &ApiProblem,
+) {
+    let (per_stop, per_tour) = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.max_waiting_time.as_ref()).map(|limits| (vehicle, limits))
+        })
+        .fold((HashMap::new(), HashMap::new()), |(mut per_stop, mut per_tour), (vehicle, limits)| {
+            limits.per_stop.iter().for_each(|limit| {
+                per_stop.insert(vehicle.type_id.clone(), *limit);
+            });
+
+            limits.per_tour.iter().for_each(|limit| {
+                per_tour.insert(vehicle.type_id.clone(), *limit);
+            });
+
+            (per_stop, per_tour)
+        });
+
+    let get_limit = |limit_map: HashMap<String, f64>| {
+        Arc::new(move |actor: &Actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limit_map.get(v_type)).cloned()
+        })
+    };
+
+    constraint.add_module(Arc::new(WaitingLimitModule::new(
+        transport,
+        activity,
+        get_limit(per_stop),
+        get_limit(per_tour),
+        MAX_WAITING_TIME_CONSTRAINT_CODE,
     )));
 }
 
@@ -500,11 +793,16 @@ fn create_extras(
     extras.insert("coord_index".to_owned(), coord_index);
     extras.insert("job_index".to_owned(), Arc::new(job_index.clone()));
     extras.insert("reserved_times_index".to_owned(), Arc::new(reserved_times_index));
+    extras.insert("precision".to_owned(), Arc::new(api_problem.precision.clone().unwrap_or_default()));
 
     if props.has_dispatch {
         extras.insert("route_modifier".to_owned(), Arc::new(get_route_modifier(constraint, job_index)));
     }
 
+    if props.has_fleet_limits {
+        extras.insert("fleet_limits".to_owned(), Arc::new(read_fleet_limits(api_problem)));
+    }
+
     if let Some(config) = create_cluster_config(api_problem)? {
         extras.set_cluster_config(config);
     }
@@ -535,11 +833,21 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|shift| shift.breaks.as_ref().map_or(false, |b| !b.is_empty()));
 
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
+    let has_access_limits = api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.attributes.is_some())
+        && api_problem
+            .plan
+            .jobs
+            .iter()
+            .flat_map(get_job_tasks)
+            .flat_map(|task| task.places.iter())
+            .any(|place| place.access.is_some());
     let max_job_value = api_problem
         .plan
         .jobs
         .iter()
-        .filter_map(|job| job.value)
+        .flat_map(|job| {
+            job.value.into_iter().chain(job.value_curve.iter().flat_map(|curve| curve.iter().map(|point| point.value)))
+        })
         .filter(|value| *value > 0.)
         .max_by(|a, b| compare_floats(*a, *b));
 
@@ -575,27 +883,48 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
-    let has_tour_size_limits =
-        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
-
-    let has_tour_travel_limits = api_problem
+    let has_consistency = api_problem.plan.jobs.iter().any(|job| job.consistency_key.is_some());
+    let has_priorities = api_problem.plan.jobs.iter().any(|job| job.priority.is_some());
+    let has_frequency = api_problem.plan.jobs.iter().any(|job| job.frequency.is_some());
+    let has_tour_size_limits = api_problem
         .fleet
         .vehicles
         .iter()
-        .any(|v| v.limits.as_ref().map_or(false, |l| l.shift_time.or(l.max_distance).is_some()));
+        .any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some() || l.min_tour_size.is_some()));
+    let has_backhaul =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.backhaul.unwrap_or(false)));
+    let has_fleet_limits = api_problem.fleet.limits.as_ref().map_or(false, |limits| !limits.is_empty());
+
+    let has_tour_travel_limits =
+        api_problem.fleet.vehicles.iter().any(|v| {
+            v.limits.as_ref().map_or(false, |l| l.shift_time.or(l.max_distance).or(l.min_shift_time).is_some())
+        });
+    let has_waiting_limits = api_problem.fleet.vehicles.iter().any(|v| {
+        v.limits
+            .as_ref()
+            .and_then(|l| l.max_waiting_time.as_ref())
+            .map_or(false, |w| w.per_stop.or(w.per_tour).is_some())
+    });
 
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
         has_skills,
+        has_access_limits,
         has_unreachable_locations,
         has_dispatch,
         has_reloads,
         has_order,
         has_group,
         has_compatibility,
+        has_consistency,
+        has_priorities,
+        has_frequency,
         has_tour_size_limits,
+        has_backhaul,
+        has_fleet_limits,
         has_tour_travel_limits,
+        has_waiting_limits,
         max_job_value,
         max_area_value,
     }
@@ -0,0 +1,280 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/road_network_test.rs"]
+mod road_network_test;
+
+use super::{split_matrix_values, Matrix};
+use crate::format::Location;
+use crate::utils::get_haversine_distance;
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::io::{BufReader, Read};
+use vrp_core::prelude::compare_floats;
+use vrp_core::utils::parallel_collect;
+
+/// A road graph node.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct RoadNode {
+    /// Latitude.
+    pub lat: f64,
+    /// Longitude.
+    pub lng: f64,
+}
+
+/// A directed road graph edge.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct RoadEdge {
+    /// Index of source node.
+    pub from: usize,
+    /// Index of target node.
+    pub to: usize,
+    /// Edge length in meters.
+    pub length: f64,
+    /// Speed (meters per second) per profile name. Edge cannot be used by profiles which are not listed.
+    pub speeds: std::collections::HashMap<String, f64>,
+}
+
+/// A road graph definition.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct RoadGraph {
+    /// Graph nodes, node index is used as its id.
+    pub nodes: Vec<RoadNode>,
+    /// Directed graph edges.
+    pub edges: Vec<RoadEdge>,
+}
+
+/// Deserializes road graph in json format from `BufReader`.
+pub fn deserialize_road_graph<R: Read>(reader: BufReader<R>) -> Result<RoadGraph, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize road graph: '{}'", err))
+}
+
+/// A road network which calculates routing matrices using shortest (fastest) paths on a road graph.
+pub struct RoadNetwork {
+    nodes: Vec<RoadNode>,
+    /// Node indices sorted by latitude, used to snap locations to nearest node.
+    sorted_nodes: Vec<usize>,
+    /// Adjacency list stored as offsets into `targets` and `lengths`.
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    lengths: Vec<f64>,
+    /// Edge durations per profile, infinite when edge cannot be used by the profile.
+    durations: HashMap<String, Vec<f64>>,
+}
+
+impl RoadNetwork {
+    /// Creates a new instance of `RoadNetwork`.
+    pub fn new(graph: RoadGraph) -> Result<Self, String> {
+        let RoadGraph { nodes, mut edges } = graph;
+        let size = nodes.len();
+
+        if let Some(edge) = edges.iter().find(|edge| edge.from >= size || edge.to >= size) {
+            return Err(format!("edge '{}->{}' refers to unknown node", edge.from, edge.to));
+        }
+
+        if let Some(edge) = edges.iter().find(|edge| !edge.length.is_finite() || edge.length < 0.) {
+            return Err(format!("edge '{}->{}' has invalid length: {}", edge.from, edge.to, edge.length));
+        }
+
+        if let Some(edge) =
+            edges.iter().find(|edge| edge.speeds.values().any(|&speed| !speed.is_finite() || speed <= 0.))
+        {
+            return Err(format!("edge '{}->{}' has invalid speed", edge.from, edge.to));
+        }
+
+        edges.sort_by(|a, b| a.from.cmp(&b.from));
+
+        let offsets = (0..=size).map(|node| edges.partition_point(|edge| edge.from < node)).collect();
+        let targets = edges.iter().map(|edge| edge.to).collect();
+        let lengths = edges.iter().map(|edge| edge.length).collect();
+
+        let profiles = edges.iter().flat_map(|edge| edge.speeds.keys()).collect::<HashSet<_>>();
+        let durations = profiles
+            .into_iter()
+            .map(|profile| {
+                let durations = edges
+                    .iter()
+                    .map(|edge| edge.speeds.get(profile).map_or(f64::INFINITY, |speed| edge.length / speed))
+                    .collect();
+
+                (profile.clone(), durations)
+            })
+            .collect();
+
+        let mut sorted_nodes = (0..size).collect::<Vec<_>>();
+        sorted_nodes.sort_by(|&a, &b| compare_floats(nodes[a].lat, nodes[b].lat));
+
+        Ok(Self { nodes, sorted_nodes, offsets, targets, lengths, durations })
+    }
+
+    /// Calculates routing matrix for given profile and locations. Durations and distances are not rounded,
+    /// unreachable locations are marked by error codes.
+    pub fn get_matrix(&self, profile: &str, locations: &[Location]) -> Result<Matrix, String> {
+        let durations =
+            self.durations.get(profile).ok_or_else(|| format!("unknown road graph profile: '{}'", profile))?;
+
+        let location_nodes = locations
+            .iter()
+            .map(|location| match location {
+                Location::Coordinate { lat, lng } => {
+                    self.snap(*lat, *lng).ok_or_else(|| "road graph has no nodes".to_string())
+                }
+                _ => Err("road network routing requires coordinates".to_string()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let unique_nodes = location_nodes.iter().cloned().collect::<HashSet<_>>().into_iter().collect::<Vec<_>>();
+        let source_paths = parallel_collect(&unique_nodes, |&source| {
+            (source, self.get_shortest_paths(source, unique_nodes.as_slice(), durations.as_slice()))
+        })
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        let capacity = locations.len() * locations.len();
+        let mut travel_times = Vec::with_capacity(capacity);
+        let mut distances = Vec::with_capacity(capacity);
+        let mut error_codes = Vec::with_capacity(capacity);
+
+        location_nodes.iter().for_each(|source| {
+            let paths = source_paths.get(source).unwrap();
+            location_nodes.iter().for_each(|target| match paths.get(target) {
+                Some(&(duration, distance)) => {
+                    travel_times.push(duration);
+                    distances.push(distance);
+                    error_codes.push(0);
+                }
+                None => {
+                    travel_times.push(0.);
+                    distances.push(0.);
+                    error_codes.push(1);
+                }
+            });
+        });
+
+        let error_codes = if error_codes.iter().any(|&code| code > 0) { Some(error_codes) } else { None };
+        let (travel_times, distances, fractions) = split_matrix_values(travel_times, distances);
+
+        Ok(Matrix {
+            profile: Some(profile.to_string()),
+            timestamp: None,
+            travel_times,
+            distances,
+            error_codes,
+            costs: None,
+            interpolation: None,
+            compact: None,
+            fractions,
+        })
+    }
+
+    /// Returns index of the graph node nearest to given coordinate.
+    fn snap(&self, lat: f64, lng: f64) -> Option<usize> {
+        // NOTE distance along meridian is a lower bound of haversine distance, so search can be stopped
+        //      once latitude difference alone is bigger than the best distance found so far
+        const MIN_METERS_PER_DEGREE: f64 = 110_000.;
+
+        let location = Location::Coordinate { lat, lng };
+        let start = self.sorted_nodes.partition_point(|&node| self.nodes[node].lat < lat);
+
+        let mut best: Option<(usize, f64)> = None;
+        let mut check_node = |node: usize| {
+            if best.map_or(false, |(_, distance)| (self.nodes[node].lat - lat).abs() * MIN_METERS_PER_DEGREE > distance)
+            {
+                return false;
+            }
+
+            let node_location = Location::Coordinate { lat: self.nodes[node].lat, lng: self.nodes[node].lng };
+            let distance = get_haversine_distance(&location, &node_location);
+            if best.map_or(true, |(_, best_distance)| distance < best_distance) {
+                best = Some((node, distance));
+            }
+
+            true
+        };
+
+        for &node in self.sorted_nodes[start..].iter() {
+            if !check_node(node) {
+                break;
+            }
+        }
+
+        for &node in self.sorted_nodes[..start].iter().rev() {
+            if !check_node(node) {
+                break;
+            }
+        }
+
+        best.map(|(node, _)| node)
+    }
+
+    /// Runs Dijkstra algorithm from source node until all targets are reached. Returns duration and
+    /// distance of the fastest path for each reachable target.
+    fn get_shortest_paths(&self, source: usize, targets: &[usize], durations: &[f64]) -> HashMap<usize, (f64, f64)> {
+        let targets = targets.iter().cloned().collect::<HashSet<_>>();
+        let mut remaining = targets.clone();
+        let mut best = HashMap::<usize, (f64, f64)>::new();
+        let mut settled = HashSet::new();
+        let mut queue = BinaryHeap::new();
+
+        best.insert(source, (0., 0.));
+        queue.push(QueueItem { duration: 0., node: source });
+
+        while let Some(QueueItem { duration, node }) = queue.pop() {
+            if !settled.insert(node) {
+                continue;
+            }
+
+            remaining.remove(&node);
+            if remaining.is_empty() {
+                break;
+            }
+
+            let distance = best.get(&node).unwrap().1;
+
+            (self.offsets[node]..self.offsets[node + 1]).for_each(|edge| {
+                let edge_duration = durations[edge];
+                if edge_duration.is_infinite() {
+                    return;
+                }
+
+                let target = self.targets[edge];
+                let candidate = (duration + edge_duration, distance + self.lengths[edge]);
+
+                if best.get(&target).map_or(true, |&(best_duration, _)| candidate.0 < best_duration) {
+                    best.insert(target, candidate);
+                    queue.push(QueueItem { duration: candidate.0, node: target });
+                }
+            });
+        }
+
+        best.retain(|node, _| targets.contains(node) && settled.contains(node));
+
+        best
+    }
+}
+
+struct QueueItem {
+    duration: f64,
+    node: usize,
+}
+
+impl PartialEq for QueueItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueueItem {}
+
+impl PartialOrd for QueueItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueueItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // NOTE reverse order to make min heap
+        compare_floats(other.duration, self.duration).then_with(|| other.node.cmp(&self.node))
+    }
+}
@@ -355,6 +355,9 @@ fn get_color_inverse(idx: usize) -> String {
 fn get_lng_lat(location: &Location) -> Result<(f64, f64), Error> {
     match location {
         Location::Coordinate { lat, lng } => Ok((*lng, *lat)),
+        Location::Point { x: _, y: _ } => {
+            Err(Error::new(ErrorKind::InvalidData, "geojson cannot be used with planar points"))
+        }
         Location::Reference { index: _ } => {
             Err(Error::new(ErrorKind::InvalidData, "geojson cannot be used with location indices"))
         }
@@ -13,6 +13,9 @@ pub use self::initial_reader::read_init_solution;
 
 mod extensions;
 
+mod reoptimization;
+pub use self::reoptimization::create_reoptimization_problem;
+
 mod writer;
 pub use self::writer::create_solution;
 pub use self::writer::PragmaticSolution;
@@ -46,6 +49,23 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
         RELOAD_RESOURCE_CONSTRAINT_CODE => {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
+        FREQUENCY_CONSTRAINT_CODE => ("FREQUENCY_CONSTRAINT", "cannot be assigned due to visit frequency constraint"),
+        BACKHAUL_CONSTRAINT_CODE => ("BACKHAUL_CONSTRAINT", "cannot be assigned due to backhaul constraint"),
+        FLEET_LIMITS_CONSTRAINT_CODE => {
+            ("FLEET_LIMITS_CONSTRAINT", "cannot be assigned due to fleet limits constraint")
+        }
+        MAX_WAITING_TIME_CONSTRAINT_CODE => {
+            ("MAX_WAITING_TIME_CONSTRAINT", "cannot be assigned due to max waiting time constraint of vehicle")
+        }
+        VEHICLE_ACCESS_CONSTRAINT_CODE => {
+            ("VEHICLE_ACCESS_CONSTRAINT", "cannot be assigned due to access restrictions of job location")
+        }
+        MIN_TOUR_SIZE_CONSTRAINT_CODE => {
+            ("MIN_TOUR_SIZE_CONSTRAINT", "cannot be assigned due to min tour size constraint of vehicle")
+        }
+        MIN_SHIFT_TIME_CONSTRAINT_CODE => {
+            ("MIN_SHIFT_TIME_CONSTRAINT", "cannot be assigned due to min shift time constraint of vehicle")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -67,6 +87,13 @@ fn map_reason_code(reason: &str) -> i32 {
         "GROUP_CONSTRAINT" => GROUP_CONSTRAINT_CODE,
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
+        "FREQUENCY_CONSTRAINT" => FREQUENCY_CONSTRAINT_CODE,
+        "BACKHAUL_CONSTRAINT" => BACKHAUL_CONSTRAINT_CODE,
+        "FLEET_LIMITS_CONSTRAINT" => FLEET_LIMITS_CONSTRAINT_CODE,
+        "MAX_WAITING_TIME_CONSTRAINT" => MAX_WAITING_TIME_CONSTRAINT_CODE,
+        "VEHICLE_ACCESS_CONSTRAINT" => VEHICLE_ACCESS_CONSTRAINT_CODE,
+        "MIN_TOUR_SIZE_CONSTRAINT" => MIN_TOUR_SIZE_CONSTRAINT_CODE,
+        "MIN_SHIFT_TIME_CONSTRAINT" => MIN_SHIFT_TIME_CONSTRAINT_CODE,
         _ => -1,
     }
 }
@@ -1,29 +1,36 @@
+use crate::format::problem::{FleetSizingSummary, Precision};
 use crate::format::{CoordIndex, Location};
-use crate::{format_time, parse_time};
-use serde::{Deserialize, Serialize};
+use crate::{format_time_with_precision, parse_time};
+use serde::{Deserialize, Serialize, Serializer};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 use vrp_core::models::common::{Duration, Timestamp};
 use vrp_core::models::solution::Commute as DomainCommute;
 use vrp_core::models::solution::CommuteInfo as DomainCommuteInfo;
 
 /// Timing statistic.
-#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Eq, Debug)]
+#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Timing {
     /// Driving time.
-    pub driving: i64,
+    #[serde(serialize_with = "serialize_measure")]
+    pub driving: f64,
     /// Serving time.
-    pub serving: i64,
+    #[serde(serialize_with = "serialize_measure")]
+    pub serving: f64,
     /// Waiting time.
-    pub waiting: i64,
+    #[serde(serialize_with = "serialize_measure")]
+    pub waiting: f64,
     /// Break time.
-    #[serde(rename(serialize = "break", deserialize = "break"))]
-    pub break_time: i64,
+    #[serde(rename(serialize = "break", deserialize = "break"), serialize_with = "serialize_measure")]
+    pub break_time: f64,
     /// Commuting time.
-    #[serde(default = "i64::default")]
-    pub commuting: i64,
+    #[serde(default, serialize_with = "serialize_measure")]
+    pub commuting: f64,
     /// Parking time.
-    #[serde(default = "i64::default")]
-    pub parking: i64,
+    #[serde(default, serialize_with = "serialize_measure")]
+    pub parking: f64,
+    /// Travel time buffer.
+    #[serde(default, serialize_with = "serialize_measure")]
+    pub buffer: f64,
 }
 
 /// Represents statistic.
@@ -32,9 +39,11 @@ pub struct Statistic {
     /// Total cost.
     pub cost: f64,
     /// Total distance.
-    pub distance: i64,
+    #[serde(serialize_with = "serialize_measure")]
+    pub distance: f64,
     /// Total duration.
-    pub duration: i64,
+    #[serde(serialize_with = "serialize_measure")]
+    pub duration: f64,
     /// Timing statistic.
     pub times: Timing,
 }
@@ -173,7 +182,8 @@ pub struct PointStop {
     /// Stop schedule.
     pub time: Schedule,
     /// Distance traveled since departure from start.
-    pub distance: i64,
+    #[serde(serialize_with = "serialize_measure")]
+    pub distance: f64,
     /// Vehicle load after departure from this stop.
     pub load: Vec<i32>,
     /// Parking time.
@@ -245,6 +255,21 @@ pub enum Violation {
         /// Index of the shift.
         shift_index: usize,
     },
+
+    /// A fleet usage violation: min amount of used tours is not reached.
+    #[serde(rename(deserialize = "fleetUsage", serialize = "fleetUsage"), rename_all = "camelCase")]
+    FleetUsage {
+        /// A vehicle type id specified by the limit.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        type_id: Option<String>,
+        /// A vehicle tag specified by the limit.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
+        /// Min amount of used tours specified by the limit.
+        min_tours: usize,
+        /// Actual amount of used tours.
+        actual_tours: usize,
+    },
 }
 
 /// Encapsulates different measurements regarding algorithm evaluation.
@@ -302,6 +327,10 @@ pub struct Extras {
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
+
+    /// A usage of vehicle types, reported when the fleet has unlimited vehicle types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fleet: Option<Vec<FleetSizingSummary>>,
 }
 
 /// A VRP solution.
@@ -327,6 +356,15 @@ pub struct Solution {
     pub extras: Option<Extras>,
 }
 
+/// Serializes distance or duration without fractional part as integer, so it keeps integer representation.
+fn serialize_measure<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    if value.fract() == 0. && value.abs() < i64::MAX as f64 {
+        serializer.serialize_i64(*value as i64)
+    } else {
+        serializer.serialize_f64(*value)
+    }
+}
+
 /// Serializes solution into json format.
 pub fn serialize_solution<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, solution).map_err(Error::from)
@@ -346,7 +384,13 @@ impl Interval {
 
 impl Commute {
     /// Creates a new instance of `Commute`.
-    pub fn new(commute: &DomainCommute, start: Timestamp, end: Timestamp, coord_index: &CoordIndex) -> Commute {
+    pub fn new(
+        commute: &DomainCommute,
+        start: Timestamp,
+        end: Timestamp,
+        coord_index: &CoordIndex,
+        precision: &Precision,
+    ) -> Commute {
         let parse_info = |info: &DomainCommuteInfo, time: Timestamp| {
             if info.is_zero_distance() {
                 None
@@ -354,7 +398,10 @@ impl Commute {
                 Some(CommuteInfo {
                     location: coord_index.get_by_idx(info.location).expect("commute info has no location"),
                     distance: info.distance,
-                    time: Interval { start: format_time(time), end: format_time(time + info.duration) },
+                    time: Interval {
+                        start: format_time_with_precision(time, precision),
+                        end: format_time_with_precision(time + info.duration, precision),
+                    },
                 })
             }
         };
@@ -0,0 +1,308 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/reoptimization_test.rs"]
+mod reoptimization_test;
+
+use crate::format::problem::*;
+use crate::format::solution::{Solution, Stop, Tour};
+use crate::format::Location;
+use crate::validation::is_reserved_job_id;
+use crate::{format_time, parse_time_safe};
+use hashbrown::{HashMap, HashSet};
+
+/// Keeps information about executed part of the tour.
+struct FrozenTour {
+    vehicle_id: String,
+    shift_index: usize,
+    departure: f64,
+    activities: Vec<ActivityInfo>,
+}
+
+/// Keeps information about activity served in the tour.
+#[derive(Clone)]
+struct ActivityInfo {
+    job_id: String,
+    activity_type: String,
+    location: Option<Location>,
+    tag: Option<String>,
+    start: f64,
+    end: f64,
+}
+
+/// Creates a problem to re-optimize a previous solution at given time:
+/// * activities started before the time (and activity which vehicle is travelling to) are locked
+///   within strict relations at the beginning of their tours
+/// * not started tasks of partially served multi jobs are kept right after the locked part
+/// * places and time windows of locked jobs are reduced to ones used in the solution
+/// * vehicles are not allowed to start their shifts before the time, unless they have already started
+/// * jobs which are present in the problem, but not in the solution, are planned as usual, so newly
+///   arrived jobs should be added to the problem plan
+///
+/// Vehicle types with more than one vehicle id are split in order to keep departure time of started
+/// vehicles: each started vehicle gets its own vehicle type with `{typeId}_{vehicleId}` id.
+pub fn create_reoptimization_problem(problem: &Problem, solution: &Solution, at: &str) -> Result<Problem, String> {
+    let at = parse_time_safe(at)?;
+    let mut problem = problem.clone();
+
+    let frozen_tours = solution
+        .tours
+        .iter()
+        .map(|tour| get_frozen_tour(&problem, tour, at))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let frozen_activities = frozen_tours.iter().flat_map(|tour| tour.activities.iter()).collect::<Vec<_>>();
+    let frozen_ids = frozen_activities.iter().map(|activity| activity.job_id.clone()).collect::<HashSet<_>>();
+    reduce_job_places(&mut problem, frozen_activities.as_slice());
+
+    let departures = frozen_tours
+        .iter()
+        .map(|tour| ((tour.vehicle_id.clone(), tour.shift_index), tour.departure))
+        .collect::<HashMap<_, _>>();
+
+    let relations = problem
+        .plan
+        .relations
+        .take()
+        .into_iter()
+        .flatten()
+        .map(|relation| Relation {
+            jobs: relation.jobs.into_iter().filter(|job_id| !frozen_ids.contains(job_id)).collect(),
+            ..relation
+        })
+        .filter(|relation| relation.jobs.iter().any(|job_id| !is_reserved_job_id(job_id)))
+        .chain(frozen_tours.into_iter().map(|tour| {
+            Relation {
+                type_field: RelationType::Strict,
+                jobs: std::iter::once("departure".to_string())
+                    .chain(tour.activities.into_iter().map(|activity| activity.job_id))
+                    .collect(),
+                vehicle_id: tour.vehicle_id,
+                shift_index: Some(tour.shift_index),
+            }
+        }))
+        .collect::<Vec<_>>();
+    problem.plan.relations = if relations.is_empty() { None } else { Some(relations) };
+
+    problem.fleet.vehicles = std::mem::take(&mut problem.fleet.vehicles)
+        .into_iter()
+        .flat_map(|vehicle| split_vehicle_type(vehicle, &departures, at))
+        .collect();
+
+    Ok(problem)
+}
+
+fn get_frozen_tour(problem: &Problem, tour: &Tour, at: f64) -> Result<Option<FrozenTour>, String> {
+    let departure = match tour.stops.first() {
+        Some(stop) => parse_time_safe(&stop.schedule().departure)?,
+        None => return Ok(None),
+    };
+
+    if departure >= at {
+        return Ok(None);
+    }
+
+    let has_required_breaks = problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| has_vehicle_id(vehicle, &tour.vehicle_id))
+        .and_then(|vehicle| vehicle.shifts.get(tour.shift_index))
+        .and_then(|shift| shift.breaks.as_ref())
+        .map_or(false, |breaks| breaks.iter().any(|b| matches!(b, VehicleBreak::Required { .. })));
+
+    let activities = tour
+        .stops
+        .iter()
+        .flat_map(|stop| {
+            let schedule = stop.schedule();
+            let stop_location = match stop {
+                Stop::Point(point) => Some(&point.location),
+                Stop::Transit(_) => None,
+            };
+
+            stop.activities().iter().map(move |activity| {
+                let (start, end) = activity
+                    .time
+                    .as_ref()
+                    .map_or((&schedule.arrival, &schedule.departure), |time| (&time.start, &time.end));
+
+                Ok(ActivityInfo {
+                    job_id: activity.job_id.clone(),
+                    activity_type: activity.activity_type.clone(),
+                    location: activity.location.as_ref().or(stop_location).cloned(),
+                    tag: activity.job_tag.clone(),
+                    start: parse_time_safe(start)?,
+                    end: parse_time_safe(end)?,
+                })
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?
+        .into_iter()
+        .filter(|activity| activity.activity_type != "departure")
+        // NOTE required breaks are not jobs, so they cannot be locked
+        .filter(|activity| !(has_required_breaks && activity.activity_type == "break"))
+        .collect::<Vec<_>>();
+
+    let started = activities.iter().take_while(|activity| activity.start < at).count();
+    let last_end = activities[..started].last().map_or(departure, |activity| activity.end);
+    // NOTE vehicle is on the way to the next activity
+    let frozen = if started < activities.len() && last_end < at { started + 1 } else { started };
+
+    let task_counts = problem
+        .plan
+        .jobs
+        .iter()
+        .map(|job| {
+            let count = [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+                .iter()
+                .map(|tasks| tasks.as_ref().map_or(0, |tasks| tasks.len()))
+                .sum::<usize>();
+            (job.id.as_str(), count)
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut frozen_activities = activities[..frozen].to_vec();
+
+    let mut remaining = frozen_activities
+        .iter()
+        .map(|activity| &activity.job_id)
+        .filter(|job_id| !is_reserved_job_id(job_id))
+        .try_fold(HashMap::<String, usize>::new(), |mut acc, job_id| {
+            let count = task_counts
+                .get(job_id.as_str())
+                .ok_or_else(|| format!("cannot find job '{}' from the solution in the problem", job_id))?;
+            let entry = acc.entry(job_id.clone()).or_insert(*count);
+            *entry = entry.saturating_sub(1);
+
+            Ok::<_, String>(acc)
+        })?;
+
+    activities[frozen..].iter().for_each(|activity| {
+        if let Some(count) = remaining.get_mut(&activity.job_id).filter(|count| **count > 0) {
+            *count -= 1;
+            frozen_activities.push(activity.clone());
+        }
+    });
+
+    Ok(Some(FrozenTour {
+        vehicle_id: tour.vehicle_id.clone(),
+        shift_index: tour.shift_index,
+        departure,
+        activities: frozen_activities,
+    }))
+}
+
+/// Keeps only place and time window used by locked activity as relations do not support alternatives.
+fn reduce_job_places(problem: &mut Problem, activities: &[&ActivityInfo]) {
+    let mut used_tasks = HashSet::new();
+    let mut jobs = problem.plan.jobs.iter_mut().map(|job| (job.id.clone(), job)).collect::<HashMap<_, _>>();
+
+    activities.iter().filter(|activity| !is_reserved_job_id(&activity.job_id)).for_each(|activity| {
+        let tasks = match (jobs.get_mut(&activity.job_id), activity.activity_type.as_str()) {
+            (Some(job), "pickup") => job.pickups.as_mut(),
+            (Some(job), "delivery") => job.deliveries.as_mut(),
+            (Some(job), "replacement") => job.replacements.as_mut(),
+            (Some(job), "service") => job.services.as_mut(),
+            _ => None,
+        };
+
+        let is_same_place = |place: &JobPlace| {
+            activity.tag.as_ref().map_or(true, |tag| place.tag.as_ref() == Some(tag))
+                && activity
+                    .location
+                    .as_ref()
+                    .map_or(true, |location| location.to_string() == place.location.to_string())
+        };
+
+        let task = tasks.into_iter().flat_map(|tasks| tasks.iter_mut().enumerate()).find(|(idx, task)| {
+            !used_tasks.contains(&(activity.job_id.clone(), activity.activity_type.clone(), *idx))
+                && task.places.iter().any(is_same_place)
+        });
+
+        if let Some((idx, task)) = task {
+            used_tasks.insert((activity.job_id.clone(), activity.activity_type.clone(), idx));
+
+            let mut place = task.places.iter().find(|place| is_same_place(place)).cloned().unwrap();
+            place.times = place.times.map(|times| {
+                let time = times
+                    .iter()
+                    .find(|time| {
+                        time.first().zip(time.last()).map_or(false, |(start, end)| {
+                            parse_time_safe(start).map_or(false, |start| start <= activity.start)
+                                && parse_time_safe(end).map_or(false, |end| activity.start <= end)
+                        })
+                    })
+                    .or_else(|| times.first())
+                    .cloned();
+
+                time.into_iter().collect()
+            });
+            task.places = vec![place];
+        }
+    });
+}
+
+fn split_vehicle_type(vehicle: VehicleType, departures: &HashMap<(String, usize), f64>, at: f64) -> Vec<VehicleType> {
+    let get_departures = |vehicle_id: &String| {
+        (0..vehicle.shifts.len()).map(|idx| departures.get(&(vehicle_id.clone(), idx)).cloned()).collect::<Vec<_>>()
+    };
+
+    let (started_ids, other_ids): (Vec<_>, Vec<_>) = vehicle
+        .vehicle_ids
+        .iter()
+        .cloned()
+        .partition(|vehicle_id| get_departures(vehicle_id).iter().any(Option::is_some));
+
+    if vehicle.vehicle_ids.len() == 1 || started_ids.is_empty() {
+        let departures = vehicle.vehicle_ids.first().map(get_departures).unwrap_or_default();
+        return vec![VehicleType { shifts: adjust_shifts(&vehicle.shifts, departures.as_slice(), at), ..vehicle }];
+    }
+
+    let other_type = if other_ids.is_empty() {
+        None
+    } else {
+        let shifts = adjust_shifts(&vehicle.shifts, vec![None; vehicle.shifts.len()].as_slice(), at);
+        Some(VehicleType { vehicle_ids: other_ids, shifts, ..vehicle.clone() })
+    };
+
+    started_ids
+        .into_iter()
+        .map(|vehicle_id| VehicleType {
+            type_id: format!("{}_{}", vehicle.type_id, vehicle_id),
+            shifts: adjust_shifts(&vehicle.shifts, get_departures(&vehicle_id).as_slice(), at),
+            vehicle_ids: vec![vehicle_id],
+            ..vehicle.clone()
+        })
+        .chain(other_type.into_iter())
+        .collect()
+}
+
+fn adjust_shifts(shifts: &[VehicleShift], departures: &[Option<f64>], at: f64) -> Vec<VehicleShift> {
+    shifts
+        .iter()
+        .zip(departures.iter())
+        .map(|(shift, departure)| {
+            let mut shift = shift.clone();
+
+            if let Some(departure) = departure {
+                shift.start.earliest = format_time(*departure);
+                shift.start.latest = Some(format_time(*departure));
+            } else {
+                let earliest = parse_time_safe(&shift.start.earliest).unwrap_or(at);
+                let end = shift.end.as_ref().and_then(|end| parse_time_safe(&end.latest).ok()).unwrap_or(f64::MAX);
+                let earliest = earliest.max(at).min(end);
+
+                shift.start.earliest = format_time(earliest);
+                shift.start.latest = shift
+                    .start
+                    .latest
+                    .as_ref()
+                    .map(|latest| format_time(parse_time_safe(latest).unwrap_or(earliest).max(earliest)));
+            }
+
+            shift
+        })
+        .collect()
+}
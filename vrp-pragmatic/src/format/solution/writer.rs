@@ -2,19 +2,23 @@
 #[path = "../../../tests/unit/format/solution/writer_test.rs"]
 mod writer_test;
 
+use crate::constraints::FleetLimitTarget;
 use crate::extensions::{JobTie, VehicleTie};
 use crate::format::coord_index::CoordIndex;
+use crate::format::problem::{get_fleet_sizing_summary, Precision};
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
-use crate::{format_time, parse_time};
+use crate::{format_time_with_precision, parse_time};
+use hashbrown::HashSet;
 use std::cmp::Ordering;
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
 use vrp_core::construction::extensions::route_intervals;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Multi, TravelTime};
+use vrp_core::models::problem::{get_job_locations, get_setup_time, Actor, Job, Multi, TravelTime};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::models::{Problem, Solution};
 use vrp_core::prelude::compare_floats;
@@ -89,29 +93,26 @@ impl Leg {
     fn new(last_detail: Option<(DomainLocation, Timestamp)>, load: Option<MultiDimLoad>, statistic: Statistic) -> Self {
         Self { last_detail, load, statistic }
     }
-
-    fn empty() -> Self {
-        Self { last_detail: None, load: None, statistic: Statistic::default() }
-    }
 }
 
 /// Creates solution.
 pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&TelemetryMetrics>) -> ApiSolution {
     let coord_index = get_coord_index(problem);
     let reserved_times_index = get_reserved_times_index(problem);
+    let precision = get_precision(problem);
 
     let tours = solution
         .routes
         .iter()
-        .map(|r| create_tour(problem, r, coord_index, reserved_times_index))
+        .map(|r| create_tour(problem, r, coord_index, reserved_times_index, &precision))
         .collect::<Vec<Tour>>();
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
-    let unassigned = create_unassigned(solution);
-    let violations = create_violations(solution);
+    let unassigned = create_unassigned(problem, solution, coord_index);
+    let violations = create_violations(problem, solution);
 
-    let extras = create_extras(solution, metrics);
+    let extras = create_extras(problem, solution, metrics);
 
     ApiSolution { statistic, tours, unassigned, violations, extras }
 }
@@ -121,6 +122,7 @@ fn create_tour(
     route: &Route,
     coord_index: &CoordIndex,
     reserved_times_index: &ReservedTimesIndex,
+    precision: &Precision,
 ) -> Tour {
     // TODO reduce complexity
 
@@ -141,7 +143,9 @@ fn create_tour(
 
     let intervals = route_intervals(route, |a| get_activity_type(a).map_or(false, |t| t == "reload"));
 
-    let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
+    let initial_leg = Leg::new(None, get_initial_load(&vehicle.dimens, is_multi_dimen), Statistic::default());
+
+    let mut leg = intervals.into_iter().fold(initial_leg, |leg, (start_idx, end_idx)| {
         let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
             (leg.load.unwrap_or_default(), MultiDimLoad::default()),
             |acc, activity| {
@@ -169,17 +173,17 @@ fn create_tour(
 
             tour.stops.push(Stop::Point(PointStop {
                 location: coord_index.get_by_idx(start.place.location).unwrap(),
-                time: format_schedule(&start.schedule),
+                time: format_schedule(&start.schedule, precision),
                 load: if has_dispatch { vec![0] } else { start_delivery.as_vec() },
-                distance: 0,
+                distance: 0.,
                 activities: vec![ApiActivity {
                     job_id: "departure".to_string(),
                     activity_type: "departure".to_string(),
                     location: None,
                     time: if is_same_location {
                         Some(Interval {
-                            start: format_time(start.schedule.arrival),
-                            end: format_time(start.schedule.departure),
+                            start: format_time_with_precision(start.schedule.arrival, precision),
+                            end: format_time_with_precision(start.schedule.departure, precision),
                         })
                     } else {
                         None
@@ -202,9 +206,8 @@ fn create_tour(
                 let prev_load = if activity_type.is_some() {
                     leg.load.unwrap()
                 } else {
-                    // NOTE arrival must have zero load
-                    let dimen_size = leg.load.unwrap().size;
-                    MultiDimLoad::new(vec![0; dimen_size])
+                    // NOTE arrival has only load which was on board at start and is not delivered
+                    leg.load.unwrap() - end_pickup
                 };
 
                 let activity_type = activity_type.unwrap_or_else(|| "arrival".to_string());
@@ -226,15 +229,17 @@ fn create_tour(
                 let commute = act.commute.clone().unwrap_or_default();
                 let commuting = commute.duration();
 
-                let (driving, transport_cost) = if commute.is_zero_distance() {
+                let (driving, buffer, travel_waiting, transport_cost) = if commute.is_zero_distance() {
                     // NOTE: use original cost traits to adapt time-based costs (except waiting/commuting)
                     let prev_departure = TravelTime::Departure(prev_departure);
                     let duration = transport.duration(route, prev_location, act.place.location, prev_departure);
+                    let buffer = transport.duration_buffer(route, prev_location, act.place.location, prev_departure);
+                    let waiting = transport.duration_waiting(route, prev_location, act.place.location, prev_departure);
                     let transport_cost = transport.cost(route, prev_location, act.place.location, prev_departure);
-                    (duration, transport_cost)
+                    (duration - buffer - waiting, buffer, waiting, transport_cost)
                 } else {
                     // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
-                    (0., commuting * vehicle.costs.per_service_time)
+                    (0., 0., 0., commuting * vehicle.costs.per_service_time)
                 };
 
                 // NOTE two clusters at the same stop location
@@ -244,6 +249,10 @@ fn create_tour(
                         _ => 0.,
                     };
 
+                // NOTE stop setup time is already included into activity's arrival
+                let setup = get_setup_time(problem.activity.as_ref(), route, prev_location, act);
+                let stop_arrival = act.schedule.arrival - setup;
+
                 let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
                 let service_start = activity_arrival.max(act.place.time.start);
                 let waiting = service_start - activity_arrival;
@@ -255,10 +264,12 @@ fn create_tour(
                 let serving_cost = problem.activity.cost(route, act, service_start);
                 let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
 
-                let location_distance =
-                    transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
-                        as i64;
-                let distance = leg.statistic.distance + location_distance - commute.forward.distance as i64;
+                let location_distance = to_measure(
+                    transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure)),
+                    precision,
+                );
+                let distance =
+                    leg.statistic.distance + location_distance - to_measure(commute.forward.distance, precision);
 
                 let is_new_stop = match (act.commute.as_ref(), prev_location == act.place.location) {
                     (Some(commute), false) if commute.is_zero_distance() => true,
@@ -269,13 +280,13 @@ fn create_tour(
                 if is_new_stop {
                     tour.stops.push(Stop::Point(PointStop {
                         location: coord_index.get_by_idx(act.place.location).unwrap(),
-                        time: format_schedule(&act.schedule),
+                        time: format_schedule(&DomainSchedule::new(stop_arrival, act.schedule.departure), precision),
                         load: prev_load.as_vec(),
                         distance,
-                        parking: if parking > 0. {
+                        parking: if parking + setup > 0. {
                             Some(Interval {
-                                start: format_time(act.schedule.arrival),
-                                end: format_time(act.schedule.arrival + parking),
+                                start: format_time_with_precision(stop_arrival, precision),
+                                end: format_time_with_precision(act.schedule.arrival + parking, precision),
                             })
                         } else {
                             None
@@ -292,7 +303,7 @@ fn create_tour(
                     Stop::Transit(_) => unreachable!(),
                 };
 
-                last.time.departure = format_time(act.schedule.departure);
+                last.time.departure = format_time_with_precision(act.schedule.departure, precision);
                 last.load = load.as_vec();
                 last.activities.push(ApiActivity {
                     job_id,
@@ -302,12 +313,14 @@ fn create_tour(
                     } else {
                         Some(coord_index.get_by_idx(act.place.location).unwrap())
                     },
-                    time: Some(Interval { start: format_time(activity_arrival), end: format_time(activity_departure) }),
+                    time: Some(Interval {
+                        start: format_time_with_precision(activity_arrival, precision),
+                        end: format_time_with_precision(activity_departure, precision),
+                    }),
                     job_tag,
-                    commute: act
-                        .commute
-                        .as_ref()
-                        .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    commute: act.commute.as_ref().map(|commute| {
+                        Commute::new(commute, act.schedule.arrival, activity_departure, coord_index, precision)
+                    }),
                 });
 
                 // NOTE detect when vehicle returns after activity to stop point
@@ -326,14 +339,19 @@ fn create_tour(
                     statistic: Statistic {
                         cost: leg.statistic.cost + total_cost,
                         distance,
-                        duration: leg.statistic.duration + act.schedule.departure as i64 - prev_departure as i64,
+                        duration: leg.statistic.duration + to_measure(act.schedule.departure, precision)
+                            - to_measure(prev_departure, precision),
                         times: Timing {
-                            driving: leg.statistic.times.driving + driving as i64,
-                            serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
-                            waiting: leg.statistic.times.waiting + waiting as i64,
-                            break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
-                            commuting: leg.statistic.times.commuting + commuting as i64,
-                            parking: leg.statistic.times.parking + parking as i64,
+                            driving: leg.statistic.times.driving + to_measure(driving, precision),
+                            serving: leg.statistic.times.serving
+                                + (if is_break { 0. } else { to_measure(serving, precision) }),
+                            // NOTE waiting for faster travel is reported as waiting, but it is still priced as driving
+                            waiting: leg.statistic.times.waiting + to_measure(waiting + travel_waiting, precision),
+                            break_time: leg.statistic.times.break_time
+                                + (if is_break { to_measure(serving, precision) } else { 0. }),
+                            commuting: leg.statistic.times.commuting + to_measure(commuting, precision),
+                            parking: leg.statistic.times.parking + to_measure(parking + setup, precision),
+                            buffer: leg.statistic.times.buffer + to_measure(buffer, precision),
                         },
                     },
                     load: Some(load),
@@ -349,12 +367,13 @@ fn create_tour(
     leg.statistic.cost += vehicle.costs.fixed;
     tour.statistic = leg.statistic;
 
-    insert_reserved_times(route, &mut tour, reserved_times_index);
+    insert_reserved_times(route, &mut tour, reserved_times_index, precision);
 
-    // NOTE remove redundant info
+    // NOTE remove redundant info, keep activity time when it differs from stop's one due to parking
     tour.stops
         .iter_mut()
         .filter(|stop| stop.activities().len() == 1)
+        .filter(|stop| stop.as_point().map_or(true, |point| point.parking.is_none()))
         .flat_map(|stop| match stop {
             Stop::Point(point) => point.activities.iter_mut(),
             Stop::Transit(transit) => transit.activities.iter_mut(),
@@ -370,7 +389,12 @@ fn create_tour(
     tour
 }
 
-fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &ReservedTimesIndex) {
+fn insert_reserved_times(
+    route: &Route,
+    tour: &mut Tour,
+    reserved_times_index: &ReservedTimesIndex,
+    precision: &Precision,
+) {
     let shift_time = route
         .tour
         .start()
@@ -415,8 +439,8 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                     leg_idx + 1,
                     Stop::Transit(TransitStop {
                         time: ApiSchedule {
-                            arrival: format_time(reserved_time.start),
-                            departure: format_time(reserved_time.end),
+                            arrival: format_time_with_precision(reserved_time.start, precision),
+                            departure: format_time_with_precision(reserved_time.end, precision),
                         },
                         load,
                         activities: vec![],
@@ -424,7 +448,7 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                 )
             }
 
-            let break_time = reserved_time.duration() as i64;
+            let break_time = to_measure(reserved_time.duration(), precision);
 
             // NOTE insert activity
             tour.stops.iter_mut().for_each(|stop| {
@@ -452,7 +476,7 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                     // TODO costs may not match?
                     let activities = match stop {
                         Stop::Point(point) => {
-                            tour.statistic.cost += break_time as f64 * route.actor.vehicle.costs.per_service_time;
+                            tour.statistic.cost += break_time * route.actor.vehicle.costs.per_service_time;
                             &mut point.activities
                         }
                         Stop::Transit(transit) => {
@@ -468,8 +492,8 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                             activity_type: "break".to_string(),
                             location: None,
                             time: Some(Interval {
-                                start: format_time(reserved_time.start),
-                                end: format_time(reserved_time.end),
+                                start: format_time_with_precision(reserved_time.start, precision),
+                                end: format_time_with_precision(reserved_time.end, precision),
                             }),
                             job_tag: None,
                             commute: None,
@@ -486,7 +510,7 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
 
                             if let Some(overlap) = overlap {
                                 let extra_time = reserved_time.end - overlap.end + overlap.duration();
-                                time.end = format_time(end + extra_time);
+                                time.end = format_time_with_precision(end + extra_time, precision);
                             }
                         }
                     });
@@ -497,8 +521,19 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
         });
 }
 
-fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
-    ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
+fn format_schedule(schedule: &DomainSchedule, precision: &Precision) -> ApiSchedule {
+    ApiSchedule {
+        arrival: format_time_with_precision(schedule.arrival, precision),
+        departure: format_time_with_precision(schedule.departure, precision),
+    }
+}
+
+/// Converts duration or distance to accumulated value: fractional part is kept only with float precision.
+fn to_measure(value: f64, precision: &Precision) -> f64 {
+    match precision {
+        Precision::Integer => value.trunc(),
+        Precision::Float => value,
+    }
 }
 
 fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -> MultiDimLoad {
@@ -507,13 +542,29 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
-fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
-    let create_simple_reasons = |code: i32| {
-        let (code, reason) = map_code_reason(code);
-        vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string(), details: None }]
+fn create_unassigned(problem: &Problem, solution: &Solution, coord_index: &CoordIndex) -> Option<Vec<UnassignedJob>> {
+    let create_description = |job: &Job, code: i32, actors: &[&Arc<Actor>]| {
+        let (_, reason) = map_code_reason(code);
+        if code != REACHABLE_CONSTRAINT_CODE {
+            return reason.to_string();
+        }
+
+        get_unreachable_leg(problem, job, actors)
+            .and_then(|(from, to)| coord_index.get_by_idx(from).zip(coord_index.get_by_idx(to)))
+            .map_or_else(|| reason.to_string(), |(from, to)| format!("{}: from {} to {}", reason, from, to))
     };
 
-    let unassigned = solution
+    let actors = problem.fleet.actors.iter().collect::<Vec<_>>();
+    let create_simple_reasons = |job: &Job, code: i32| {
+        let (code_name, _) = map_code_reason(code);
+        vec![UnassignedJobReason {
+            code: code_name.to_string(),
+            description: create_description(job, code, actors.as_slice()),
+            details: None,
+        }]
+    };
+
+    let mut unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_vehicle_id().is_none())
@@ -521,44 +572,55 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
             let job_id = job.dimens().get_job_id().expect("job id expected").clone();
 
             let reasons = match code {
-                UnassignmentInfo::Simple(code) => create_simple_reasons(*code),
+                UnassignmentInfo::Simple(code) => create_simple_reasons(job, *code),
                 UnassignmentInfo::Detailed(details) if !details.is_empty() => details
                     .iter()
                     .collect_group_by_key(|(_, code)| *code)
                     .into_iter()
                     .map(|(code, group)| {
-                        let (code, reason) = map_code_reason(code);
+                        let (code_name, _) = map_code_reason(code);
                         let mut vehicle_details = group
                             .iter()
                             .map(|(actor, _)| {
                                 let dimens = &actor.vehicle.dimens;
                                 let vehicle_id = dimens.get_vehicle_id().cloned().unwrap();
                                 let shift_index = dimens.get_shift_index().unwrap();
-                                (vehicle_id, shift_index)
+                                ((vehicle_id, shift_index), actor)
                             })
                             .collect::<Vec<_>>();
                         // NOTE sort to have consistent order
-                        vehicle_details.sort();
+                        vehicle_details.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                        let actors = vehicle_details.iter().map(|(_, actor)| *actor).collect::<Vec<_>>();
 
                         UnassignedJobReason {
+                            description: create_description(job, code, actors.as_slice()),
                             details: Some(
                                 vehicle_details
                                     .into_iter()
-                                    .map(|(vehicle_id, shift_index)| UnassignedJobDetail { vehicle_id, shift_index })
+                                    .map(|((vehicle_id, shift_index), _)| UnassignedJobDetail {
+                                        vehicle_id,
+                                        shift_index,
+                                    })
                                     .collect(),
                             ),
-                            code: code.to_string(),
-                            description: reason.to_string(),
+                            code: code_name.to_string(),
                         }
                     })
                     .collect(),
-                _ => create_simple_reasons(0),
+                _ => create_simple_reasons(job, 0),
             };
 
             UnassignedJob { job_id, reasons }
         })
         .collect::<Vec<_>>();
 
+    // NOTE periodic job has an occurrence per visit: keep one entry per job preferring the actual reason
+    let (frequency_code, _) = map_code_reason(FREQUENCY_CONSTRAINT_CODE);
+    unassigned.sort_by_key(|job| job.reasons.iter().all(|reason| reason.code == frequency_code));
+    let mut job_ids = HashSet::new();
+    unassigned.retain(|job| job_ids.insert(job.job_id.clone()));
+
     if unassigned.is_empty() {
         None
     } else {
@@ -566,17 +628,67 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
-fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
-    // NOTE at the moment only break violation is mapped
-    let violations = solution
-        .unassigned
+/// Returns the first leg between actor's start or end and job location which is not reachable.
+fn get_unreachable_leg(
+    problem: &Problem,
+    job: &Job,
+    actors: &[&Arc<Actor>],
+) -> Option<(DomainLocation, DomainLocation)> {
+    let mut job_locations = get_job_locations(job).flatten().collect::<Vec<_>>();
+    job_locations.dedup();
+
+    // NOTE check only unique combinations of profile and start/end locations, keeping actors order
+    let mut visited = HashSet::new();
+
+    actors
         .iter()
-        .filter(|(job, _)| job.dimens().get_job_type().map_or(false, |t| t == "break"))
-        .map(|(job, _)| Violation::Break {
-            vehicle_id: job.dimens().get_vehicle_id().expect("vehicle id").clone(),
-            shift_index: job.dimens().get_shift_index().expect("shift index"),
+        .map(|actor| {
+            let start = actor.detail.start.as_ref().map(|start| start.location);
+            let end = actor.detail.end.as_ref().map(|end| end.location);
+            (&actor.vehicle.profile, start, end)
         })
-        .collect::<Vec<_>>();
+        .filter(|(profile, start, end)| visited.insert((profile.index, *start, *end)))
+        .find_map(|(profile, start, end)| {
+            start
+                .into_iter()
+                .flat_map(|start| job_locations.iter().map(move |&location| (start, location)))
+                .chain(end.into_iter().flat_map(|end| job_locations.iter().map(move |&location| (location, end))))
+                .find(|&(from, to)| problem.transport.distance_approx(profile, from, to) < 0.)
+        })
+}
+
+fn create_violations(problem: &Problem, solution: &Solution) -> Option<Vec<Violation>> {
+    // NOTE at the moment only break and fleet usage violations are mapped
+    let break_violations =
+        solution.unassigned.iter().filter(|(job, _)| job.dimens().get_job_type().map_or(false, |t| t == "break")).map(
+            |(job, _)| Violation::Break {
+                vehicle_id: job.dimens().get_vehicle_id().expect("vehicle id").clone(),
+                shift_index: job.dimens().get_shift_index().expect("shift index"),
+            },
+        );
+
+    let fleet_usage_violations =
+        get_fleet_limits(problem).into_iter().flat_map(|limits| limits.iter()).filter_map(|limit| {
+            let min_tours = limit.min_tours?;
+            let actual_tours = solution
+                .routes
+                .iter()
+                .filter(|route| route.tour.has_jobs() && limit.is_applicable(route.actor.vehicle.as_ref()))
+                .count();
+
+            if actual_tours >= min_tours {
+                return None;
+            }
+
+            let (type_id, tag) = match &limit.target {
+                FleetLimitTarget::VehicleType(type_id) => (Some(type_id.clone()), None),
+                FleetLimitTarget::VehicleTag(tag) => (None, Some(tag.clone())),
+            };
+
+            Some(Violation::FleetUsage { type_id, tag, min_tours, actual_tours })
+        });
+
+    let violations = break_violations.chain(fleet_usage_violations).collect::<Vec<_>>();
 
     if violations.is_empty() {
         None
@@ -607,35 +719,50 @@ fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<Mult
     }
 }
 
+fn get_initial_load(dimens: &Dimensions, is_multi_dimen: bool) -> Option<MultiDimLoad> {
+    if is_multi_dimen {
+        dimens.get_initial_load().cloned()
+    } else {
+        dimens.get_initial_load().map(|load: &SingleDimLoad| MultiDimLoad::new(vec![load.value]))
+    }
+}
+
 fn get_parking_time(extras: &DomainExtras) -> f64 {
     extras.get_cluster_config().map_or(0., |config| config.serving.get_parking())
 }
 
-fn create_extras(_solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Option<Extras> {
-    metrics.map(|metrics| Extras {
-        metrics: Some(ApiMetrics {
-            duration: metrics.duration,
-            generations: metrics.generations,
-            speed: metrics.speed,
-            evolution: metrics
-                .evolution
-                .iter()
-                .map(|g| ApiGeneration {
-                    number: g.number,
-                    timestamp: g.timestamp,
-                    i_all_ratio: g.i_all_ratio,
-                    i_1000_ratio: g.i_1000_ratio,
-                    is_improvement: g.is_improvement,
-                    population: AppPopulation {
-                        individuals: g
-                            .population
-                            .individuals
-                            .iter()
-                            .map(|i| ApiIndividual { difference: i.difference, fitness: i.fitness.clone() })
-                            .collect(),
-                    },
-                })
-                .collect(),
-        }),
-    })
+fn create_extras(problem: &Problem, solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Option<Extras> {
+    let fleet =
+        if problem.fleet.has_vehicle_factory() { Some(get_fleet_sizing_summary(problem, solution)) } else { None };
+
+    let metrics = metrics.map(|metrics| ApiMetrics {
+        duration: metrics.duration,
+        generations: metrics.generations,
+        speed: metrics.speed,
+        evolution: metrics
+            .evolution
+            .iter()
+            .map(|g| ApiGeneration {
+                number: g.number,
+                timestamp: g.timestamp,
+                i_all_ratio: g.i_all_ratio,
+                i_1000_ratio: g.i_1000_ratio,
+                is_improvement: g.is_improvement,
+                population: AppPopulation {
+                    individuals: g
+                        .population
+                        .individuals
+                        .iter()
+                        .map(|i| ApiIndividual { difference: i.difference, fitness: i.fitness.clone() })
+                        .collect(),
+                },
+            })
+            .collect(),
+    });
+
+    if metrics.is_none() && fleet.is_none() {
+        None
+    } else {
+        Some(Extras { metrics, fleet })
+    }
 }
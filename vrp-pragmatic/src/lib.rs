@@ -35,10 +35,10 @@ pub mod checker;
 pub mod format;
 pub mod validation;
 
-use crate::format::problem::Problem;
+use crate::format::problem::{Precision, Problem};
 use crate::format::{CoordIndex, Location};
 use time::format_description::well_known::Rfc3339;
-use time::OffsetDateTime;
+use time::{OffsetDateTime, Time};
 
 /// Get lists of problem.
 pub fn get_unique_locations(problem: &Problem) -> Vec<Location> {
@@ -50,6 +50,16 @@ fn format_time(time: f64) -> String {
     OffsetDateTime::from_unix_timestamp(time as i64).map(|time| time.format(&Rfc3339).unwrap()).unwrap()
 }
 
+fn format_time_with_precision(time: f64, precision: &Precision) -> String {
+    match precision {
+        Precision::Integer => format_time(time),
+        // NOTE keep milliseconds only as timestamps in seconds cannot represent finer values reliably
+        Precision::Float => OffsetDateTime::from_unix_timestamp_nanos((time * 1000.).round() as i128 * 1_000_000)
+            .map(|time| time.format(&Rfc3339).unwrap())
+            .unwrap(),
+    }
+}
+
 fn parse_time(time: &str) -> f64 {
     parse_time_safe(time).unwrap()
 }
@@ -59,3 +69,19 @@ fn parse_time_safe(time: &str) -> Result<f64, String> {
         .map(|time| time.unix_timestamp() as f64)
         .map_err(|err| format!("cannot parse date: {}", err))
 }
+
+fn parse_time_with_precision(time: &str, precision: &Precision) -> f64 {
+    match precision {
+        Precision::Integer => parse_time(time),
+        Precision::Float => OffsetDateTime::parse(time, &Rfc3339)
+            .map(|time| time.unix_timestamp() as f64 + time.nanosecond() as f64 / 1e9)
+            .unwrap(),
+    }
+}
+
+/// Returns a start of the day, in its own time offset, for given time.
+fn parse_day_start_safe(time: &str) -> Result<f64, String> {
+    OffsetDateTime::parse(time, &Rfc3339)
+        .map(|time| time.replace_time(Time::MIDNIGHT).unix_timestamp() as f64)
+        .map_err(|err| format!("cannot parse date: {}", err))
+}
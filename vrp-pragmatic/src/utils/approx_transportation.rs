@@ -2,26 +2,28 @@
 #[path = "../../tests/unit/utils/approx_transportation_test.rs"]
 mod approx_transportation_test;
 
+use crate::format::problem::DistanceMetric;
 use crate::format::Location;
-use vrp_core::utils::parallel_collect;
+use vrp_core::algorithms::geometry::Point;
 
-/// Gets approximated durations and distances rounded to nearest integer.
-pub fn get_approx_transportation(locations: &[Location], speeds: &[f64]) -> Vec<(Vec<i64>, Vec<i64>)> {
-    assert!(!speeds.is_empty());
-    assert!(speeds.iter().all(|&speed| speed > 0.));
-
-    let distances = locations
-        .iter()
-        .flat_map(|l1| locations.iter().map(move |l2| get_haversine_distance(l1, l2)))
-        .collect::<Vec<_>>();
-
-    let distances_rounded = distances.iter().map(|distance| distance.round() as i64).collect::<Vec<_>>();
-
-    parallel_collect(speeds, |speed| {
-        let durations = distances.iter().map(|distance| (distance / speed).round() as i64).collect::<Vec<_>>();
+/// Gets approximated distances between all locations as a single dimensional square matrix.
+/// Geocoordinates use haversine formula, planar points use given metric.
+pub fn get_approx_distances(locations: &[Location], metric: &DistanceMetric) -> Vec<f64> {
+    locations.iter().flat_map(|l1| locations.iter().map(move |l2| get_approx_distance(l1, l2, metric))).collect()
+}
 
-        (durations, distances_rounded.clone())
-    })
+fn get_approx_distance(l1: &Location, l2: &Location, metric: &DistanceMetric) -> f64 {
+    match (l1, l2) {
+        (Location::Point { x: x1, y: y1 }, Location::Point { x: x2, y: y2 }) => {
+            let (p1, p2) = (Point::new(*x1, *y1), Point::new(*x2, *y2));
+
+            match metric {
+                DistanceMetric::Euclidean => p1.distance_to_point(&p2),
+                DistanceMetric::Manhattan => p1.manhattan_distance_to_point(&p2),
+            }
+        }
+        _ => get_haversine_distance(l1, l2),
+    }
 }
 
 /// Gets distance between two points using haversine formula.
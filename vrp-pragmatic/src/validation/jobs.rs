@@ -3,7 +3,9 @@
 mod jobs_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -179,6 +181,163 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job frequency is defined properly.
+fn check_e1108_invalid_frequency(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.frequency.as_ref().map_or(false, |frequency| {
+                frequency.visits == 0
+                    || frequency.min_gap_days.map_or(false, |min_gap_days| min_gap_days == 0)
+                    || frequency.allowed_days.as_ref().map_or(false, |allowed_days| {
+                        allowed_days.iter().collect::<HashSet<_>>().len() < frequency.visits
+                    })
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid job frequency".to_string(),
+            format!(
+                "make sure that visits and minimum gap are positive and there are enough allowed days, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that jobs with frequency are not used together with features which require a single visit.
+fn check_e1109_unsupported_frequency_usage(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let referenced_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .flat_map(|relation| relation.jobs.iter())
+        .chain(
+            ctx.vehicles().flat_map(|vehicle| vehicle.shifts.iter()).flat_map(|shift| shift.onboard.iter().flatten()),
+        )
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.frequency.is_some())
+        .filter(|job| job.group.is_some() || referenced_ids.contains(&job.id))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "unsupported job frequency usage".to_string(),
+            format!(
+                "remove frequency or exclude jobs from relations, groups and vehicle on board lists: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job priority is positive.
+fn check_e1110_invalid_priority(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.priority.map_or(false, |priority| priority == 0))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid job priority".to_string(),
+            format!("change priority of jobs to be greater than zero: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job value curve is correct.
+fn check_e1111_invalid_value_curve(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.value_curve.as_ref().map_or(false, |curve| {
+                let times = curve.iter().map(|point| parse_time_safe(&point.time)).collect::<Result<Vec<_>, _>>();
+                let has_sorted_times = times.map_or(false, |times| times.windows(2).all(|pair| pair[0] < pair[1]));
+                let has_negative_values = curve.iter().any(|point| point.value < 0.);
+
+                curve.is_empty() || !has_sorted_times || has_negative_values
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid job value curve".to_string(),
+            format!(
+                "ensure that value curve is not empty, has valid times in ascending order and non-negative values, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job place access limits are positive.
+fn check_e1112_invalid_place_access(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().flat_map(|task| task.places.iter()).filter_map(|place| place.access.as_ref()).any(
+                |access| {
+                    access
+                        .max_weight
+                        .iter()
+                        .chain(access.max_height.iter())
+                        .chain(access.max_length.iter())
+                        .any(|value| !value.is_finite() || *value <= 0.)
+                },
+            )
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid job place access".to_string(),
+            format!("ensure that job place access limits are positive, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that max amount of job neighbors is greater than zero.
+fn check_e1113_invalid_max_neighbors(ctx: &ValidationContext) -> Result<(), FormatError> {
+    if ctx.problem.plan.max_neighbors.map_or(false, |max_neighbors| max_neighbors == 0) {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid max neighbors".to_string(),
+            "change plan max neighbors to be greater than zero or remove it".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -190,5 +349,11 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_invalid_frequency(ctx),
+        check_e1109_unsupported_frequency_usage(ctx),
+        check_e1110_invalid_priority(ctx),
+        check_e1111_invalid_value_curve(ctx),
+        check_e1112_invalid_place_access(ctx),
+        check_e1113_invalid_max_neighbors(ctx),
     ])
 }
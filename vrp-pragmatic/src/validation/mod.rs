@@ -1,6 +1,7 @@
 //! This module provides functionality to validate problem definition for logical correctness.
 
 use crate::format::problem::*;
+use crate::format::solution::Solution;
 use crate::format::{CoordIndex, FormatError};
 
 /// A validation context which keeps essential information.
@@ -9,6 +10,8 @@ pub struct ValidationContext<'a> {
     pub problem: &'a Problem,
     /// Routing matrices.
     pub matrices: Option<&'a Vec<Matrix>>,
+    /// A reference solution used by plan changes objective.
+    pub reference: Option<&'a Solution>,
 
     coord_index: &'a CoordIndex,
     job_index: HashMap<String, Job>,
@@ -30,7 +33,7 @@ mod relations;
 use self::relations::validate_relations;
 
 mod routing;
-use self::routing::validate_routing;
+use self::routing::{get_routing_warnings, validate_routing};
 use hashbrown::HashMap;
 
 impl<'a> ValidationContext<'a> {
@@ -39,11 +42,18 @@ impl<'a> ValidationContext<'a> {
         Self {
             problem,
             matrices,
+            reference: None,
             coord_index,
             job_index: problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect(),
         }
     }
 
+    /// Sets a reference solution used by plan changes objective.
+    pub fn with_reference(mut self, reference: Option<&'a Solution>) -> Self {
+        self.reference = reference;
+        self
+    }
+
     /// Validates problem on set of rules.
     pub fn validate(&self) -> Result<(), Vec<FormatError>> {
         let errors = validate_jobs(self)
@@ -63,6 +73,12 @@ impl<'a> ValidationContext<'a> {
         }
     }
 
+    /// Returns warnings which do not prevent solving: affected vehicles are likely unused and
+    /// affected jobs are likely reported as unassigned.
+    pub fn get_warnings(&self) -> Vec<FormatError> {
+        get_routing_warnings(self)
+    }
+
     /// Gets list of jobs from the problem.
     fn jobs(&self) -> impl Iterator<Item = &Job> {
         self.problem.plan.jobs.iter()
@@ -86,6 +102,6 @@ impl<'a> ValidationContext<'a> {
     }
 }
 
-fn is_reserved_job_id(job_id: &str) -> bool {
+pub(crate) fn is_reserved_job_id(job_id: &str) -> bool {
     job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload" || job_id == "dispatch"
 }
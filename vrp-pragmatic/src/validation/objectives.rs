@@ -28,10 +28,12 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MinimizeCost => acc.entry("minimize-cost"),
                 MinimizeDistance => acc.entry("minimize-distance"),
                 MinimizeDuration => acc.entry("minimize-duration"),
+                MinimizeWaiting => acc.entry("minimize-waiting"),
                 MinimizeTours => acc.entry("minimize-tours"),
                 MaximizeTours => acc.entry("maximize-tours"),
                 MaximizeValue { .. } => acc.entry("maximize-value"),
                 MinimizeUnassignedJobs { .. } => acc.entry("minimize-unassigned"),
+                MinimizeUnassignedJobsByPriority { .. } => acc.entry("minimize-unassigned-by-priority"),
                 MinimizeArrivalTime => acc.entry("minimize-arrival-time"),
                 BalanceMaxLoad { .. } => acc.entry("balance-max-load"),
                 BalanceActivities { .. } => acc.entry("balance-activities"),
@@ -39,6 +41,8 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 BalanceDuration { .. } => acc.entry("balance-duration"),
                 TourOrder { .. } => acc.entry("tour-order"),
                 AreaOrder { .. } => acc.entry("area-order"),
+                MinimizeInconsistency => acc.entry("minimize-inconsistency"),
+                MinimizePlanChanges { .. } => acc.entry("minimize-plan-changes"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -176,13 +180,17 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 
     let has_no_value_objective = !objectives.iter().any(|objective| matches!(objective, MaximizeValue { .. }));
-    let has_jobs_with_vlue = ctx.problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value > 0.);
+    let has_jobs_with_value = ctx.problem.plan.jobs.iter().any(|job| {
+        job.value.map_or(false, |value| value > 0.)
+            || job.value_curve.as_ref().map_or(false, |curve| curve.iter().any(|point| point.value > 0.))
+    });
 
-    if has_no_value_objective && has_jobs_with_vlue {
+    if has_no_value_objective && has_jobs_with_value {
         Err(FormatError::new(
             "E1607".to_string(),
             "missing value objective".to_string(),
-            "specify 'maximize-value' objective, remove objectives property or remove value property from jobs"
+            "specify 'maximize-value' objective, remove objectives property or remove value and valueCurve \
+             properties from jobs"
                 .to_string(),
         ))
     } else {
@@ -206,6 +214,156 @@ fn check_e1608_areas_but_no_objective(ctx: &ValidationContext, objectives: &[&Ob
     }
 }
 
+/// Checks that reference solution of plan changes objective is correct.
+fn check_e1609_invalid_plan_changes_reference(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_plan_changes_objective = objectives.iter().any(|objective| matches!(objective, MinimizePlanChanges { .. }));
+
+    let reference = match (has_plan_changes_objective, ctx.reference) {
+        (true, Some(reference)) => reference,
+        _ => return Ok(()),
+    };
+
+    let get_shifts =
+        |vehicle_id: &str| ctx.vehicles().find(|vehicle| has_vehicle_id(vehicle, vehicle_id)).map(|v| v.shifts.len());
+
+    let mut tour_job_ids = HashMap::<&String, (&String, usize)>::new();
+    let mut ids = reference
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let tour_key = (&tour.vehicle_id, tour.shift_index);
+            let unknown_vehicle = match get_shifts(&tour.vehicle_id) {
+                Some(shifts) if tour.shift_index < shifts => None,
+                _ => Some(&tour.vehicle_id),
+            };
+
+            let invalid_jobs = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .map(|activity| &activity.job_id)
+                .filter(|job_id| !is_reserved_job_id(job_id))
+                .filter(|job_id| {
+                    let is_unknown = !ctx.job_index.contains_key(*job_id);
+                    let is_duplicate = *tour_job_ids.entry(job_id).or_insert(tour_key) != tour_key;
+
+                    is_unknown || is_duplicate
+                })
+                .collect::<Vec<_>>();
+
+            unknown_vehicle.into_iter().chain(invalid_jobs).cloned().collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    ids.sort();
+    ids.dedup();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "invalid plan changes reference".to_string(),
+            format!(
+                "make sure that vehicles, their shifts and jobs from the reference solution exist and each job is assigned only to one tour: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that consistency objective can be specified only when job with consistency key is used.
+fn check_e1610_no_jobs_with_consistency_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_consistency_objective = objectives.iter().any(|objective| matches!(objective, MinimizeInconsistency));
+    let has_no_jobs_with_consistency = !ctx.jobs().any(|job| job.consistency_key.is_some());
+
+    if has_consistency_objective && has_no_jobs_with_consistency {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "redundant consistency objective".to_string(),
+            "specify at least one job with consistency key or delete 'minimize-inconsistency' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that consistency objective is specified when some jobs have consistency key set.
+fn check_e1611_jobs_with_consistency_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_consistency_objective = !objectives.iter().any(|objective| matches!(objective, MinimizeInconsistency));
+    let has_jobs_with_consistency = ctx.jobs().any(|job| job.consistency_key.is_some());
+
+    if has_no_consistency_objective && has_jobs_with_consistency {
+        Err(FormatError::new(
+            "E1611".to_string(),
+            "missing consistency objective".to_string(),
+            "specify 'minimize-inconsistency' objective, remove objectives property or remove consistency key from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that priority objective can be specified only when job with priority is used.
+fn check_e1612_no_jobs_with_priority_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_priority_objective =
+        objectives.iter().any(|objective| matches!(objective, MinimizeUnassignedJobsByPriority { .. }));
+    let has_no_jobs_with_priority = !ctx.jobs().any(|job| job.priority.is_some());
+
+    if has_priority_objective && has_no_jobs_with_priority {
+        Err(FormatError::new(
+            "E1612".to_string(),
+            "redundant priority objective".to_string(),
+            "specify at least one job with priority or delete 'minimize-unassigned-by-priority' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that priority objective is specified when some jobs have priority set.
+fn check_e1613_jobs_with_priority_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_priority_objective =
+        !objectives.iter().any(|objective| matches!(objective, MinimizeUnassignedJobsByPriority { .. }));
+    let has_jobs_with_priority = ctx.jobs().any(|job| job.priority.is_some());
+
+    if has_no_priority_objective && has_jobs_with_priority {
+        Err(FormatError::new(
+            "E1613".to_string(),
+            "missing priority objective".to_string(),
+            "specify 'minimize-unassigned-by-priority' objective, remove objectives property or remove priority \
+             from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().flatten().collect())
 }
@@ -222,6 +380,11 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1606_jobs_with_order_but_no_objective(ctx, &objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
             check_e1608_areas_but_no_objective(ctx, &objectives),
+            check_e1609_invalid_plan_changes_reference(ctx, &objectives),
+            check_e1610_no_jobs_with_consistency_objective(ctx, &objectives),
+            check_e1611_jobs_with_consistency_but_no_objective(ctx, &objectives),
+            check_e1612_no_jobs_with_priority_objective(ctx, &objectives),
+            check_e1613_jobs_with_priority_but_no_objective(ctx, &objectives),
         ])
     } else {
         Ok(())
@@ -3,6 +3,7 @@
 mod routing_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
 use hashbrown::HashSet;
 
@@ -31,14 +32,17 @@ fn check_e1501_empty_profiles(ctx: &ValidationContext) -> Result<(), FormatError
 }
 
 /// Checks that only one type of location is used.
-fn check_e1502_no_location_type_mix(_ctx: &ValidationContext, location_types: (bool, bool)) -> Result<(), FormatError> {
-    let (has_coordinates, has_indices) = location_types;
+fn check_e1502_no_location_type_mix(
+    _ctx: &ValidationContext,
+    location_types: (bool, bool, bool),
+) -> Result<(), FormatError> {
+    let (has_coordinates, has_points, has_indices) = location_types;
 
-    if has_coordinates && has_indices {
+    if [has_coordinates, has_points, has_indices].iter().filter(|&&has_type| has_type).count() > 1 {
         Err(FormatError::new(
             "E1502".to_string(),
             "mixing different location types".to_string(),
-            "use either coordinates or indices for all locations".to_string(),
+            "use either coordinates, points or indices for all locations".to_string(),
         ))
     } else {
         Ok(())
@@ -48,9 +52,9 @@ fn check_e1502_no_location_type_mix(_ctx: &ValidationContext, location_types: (b
 /// Checks that routing matrix is supplied when location indices are used.
 fn check_e1503_no_matrix_when_indices_used(
     ctx: &ValidationContext,
-    location_types: (bool, bool),
+    location_types: (bool, bool, bool),
 ) -> Result<(), FormatError> {
-    let (_, has_indices) = location_types;
+    let (_, _, has_indices) = location_types;
 
     if has_indices && ctx.matrices.map_or(true, |matrices| matrices.is_empty()) {
         Err(FormatError::new(
@@ -121,6 +125,226 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that matrix costs, if specified, have proper size and are not negative.
+fn check_e1506_matrix_costs_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_invalid_costs = ctx.matrices.iter().flat_map(|matrices| matrices.iter()).any(|matrix| {
+        matrix
+            .costs
+            .as_ref()
+            .map_or(false, |costs| costs.len() != matrix.travel_times.len() || costs.iter().any(|cost| *cost < 0.))
+    });
+
+    if has_invalid_costs {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid routing matrix costs".to_string(),
+            "ensure that matrix costs have the same size as travel times and are not negative".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that matrix timestamps, if specified, are valid dates.
+fn check_e1507_matrix_timestamps_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_timestamps = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter())
+        .filter_map(|matrix| matrix.timestamp.as_ref())
+        .filter(|timestamp| parse_time_safe(timestamp).is_err())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if invalid_timestamps.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid routing matrix timestamp".to_string(),
+            format!("ensure that matrix timestamps are in RFC3339 format: '{}'", invalid_timestamps.join(", ")),
+        ))
+    }
+}
+
+/// Checks that profile speed curves, if specified, have at least two points with ascending hours and positive speeds.
+fn check_e1508_profile_speed_curves_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profile_names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile.speed_curve.as_ref().map_or(false, |speed_curve| {
+                speed_curve.len() < 2
+                    || speed_curve.windows(2).any(|pair| pair[0].hour >= pair[1].hour)
+                    || speed_curve
+                        .iter()
+                        .any(|point| !(0. ..24.).contains(&point.hour) || !point.speed.is_finite() || point.speed <= 0.)
+            })
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1508".to_string(),
+            "invalid profile speed curve".to_string(),
+            format!(
+                "ensure that speed curve has at least two points with ascending hours in [0, 24) range and \
+                 positive speeds, profiles: '{}'",
+                profile_names.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that profile circuity, if specified, is not less than one.
+fn check_e1509_profile_circuity_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let profile_names = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| profile.circuity.map_or(false, |circuity| !circuity.is_finite() || circuity < 1.))
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if profile_names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1509".to_string(),
+            "invalid profile circuity".to_string(),
+            format!("ensure that circuity is not less than one, profiles: '{}'", profile_names.join(", ")),
+        ))
+    }
+}
+
+/// Checks that matrix travel times and distances have fractional part only when float precision is used.
+fn check_e1510_matrix_values_match_precision(ctx: &ValidationContext) -> Result<(), FormatError> {
+    if matches!(ctx.problem.precision, Some(Precision::Float)) {
+        return Ok(());
+    }
+
+    let has_fractions =
+        ctx.matrices.iter().flat_map(|matrices| matrices.iter()).any(|matrix| matrix.fractions.is_some());
+
+    if has_fractions {
+        Err(FormatError::new(
+            "E1510".to_string(),
+            "fractional routing matrix values with integer precision".to_string(),
+            "ensure that matrix travel times and distances are integers or set problem precision to 'float'"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that vehicle shift end is reachable from its start according to routing matrix error codes.
+fn check_e1511_vehicle_shift_end_is_reachable(ctx: &ValidationContext, matrices: &[Matrix]) -> Result<(), FormatError> {
+    let unreachable_shifts = ctx
+        .problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            let profile_matrices = get_profile_matrices(ctx.problem, matrices, &vehicle.profile.matrix);
+
+            vehicle.shifts.iter().enumerate().filter_map(move |(shift_index, shift)| {
+                let end_location = &shift.end.as_ref()?.location;
+                let start = ctx.coord_index.get_by_loc(&shift.start.location)?;
+                let end = ctx.coord_index.get_by_loc(end_location)?;
+
+                if start != end && is_unreachable_leg(profile_matrices.as_slice(), start, end) {
+                    Some(format!(
+                        "profile '{}': vehicle type '{}', shift {}, from {} to {}",
+                        vehicle.profile.matrix, vehicle.type_id, shift_index, shift.start.location, end_location
+                    ))
+                } else {
+                    None
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if unreachable_shifts.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1511".to_string(),
+            "vehicle shift end is unreachable from shift start".to_string(),
+            format!(
+                "ensure that routing matrix has no error code for shift start and end locations: {}",
+                unreachable_shifts.join("; ")
+            ),
+        ))
+    }
+}
+
+/// Checks that all job locations are reachable from start and back to end of at least one vehicle shift
+/// according to routing matrix error codes.
+fn check_e1512_job_locations_are_reachable(ctx: &ValidationContext, matrices: &[Matrix]) -> Result<(), FormatError> {
+    // NOTE check only unique combinations of profile and shift locations
+    let shifts = ctx
+        .problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter().map(move |shift| (vehicle, shift)))
+        .filter_map(|(vehicle, shift)| {
+            let start = ctx.coord_index.get_by_loc(&shift.start.location)?;
+            let end = match shift.end.as_ref() {
+                Some(end) => Some(ctx.coord_index.get_by_loc(&end.location)?),
+                None => None,
+            };
+
+            Some((vehicle.profile.matrix.as_str(), start, end))
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(|(profile, start, end)| (get_profile_matrices(ctx.problem, matrices, profile), start, end))
+        .collect::<Vec<_>>();
+
+    if shifts.is_empty() {
+        return Ok(());
+    }
+
+    let job_ids = ctx
+        .jobs()
+        .filter(|job| {
+            let locations = get_job_tasks(job)
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| ctx.coord_index.get_by_loc(&place.location))
+                .collect::<HashSet<_>>();
+
+            !shifts.iter().any(|(profile_matrices, start, end)| {
+                locations.iter().all(|&location| {
+                    !is_unreachable_leg(profile_matrices.as_slice(), *start, location)
+                        && end.map_or(true, |end| !is_unreachable_leg(profile_matrices.as_slice(), location, end))
+                })
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1512".to_string(),
+            "job locations are unreachable by any vehicle shift".to_string(),
+            format!(
+                "ensure that routing matrix has no error code between shift start or end and job locations, job ids: '{}'",
+                job_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let location_types = ctx.coord_index.get_used_types();
@@ -132,5 +356,20 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_matrix_costs_are_correct(ctx),
+        check_e1507_matrix_timestamps_are_correct(ctx),
+        check_e1508_profile_speed_curves_are_correct(ctx),
+        check_e1509_profile_circuity_is_correct(ctx),
+        check_e1510_matrix_values_match_precision(ctx),
     ])
 }
+
+/// Returns routing warnings which do not prevent solving, but lead to unused vehicles or unassigned jobs.
+pub fn get_routing_warnings(ctx: &ValidationContext) -> Vec<FormatError> {
+    let matrices = if let Some(matrices) = ctx.matrices { matrices } else { return vec![] };
+
+    [check_e1511_vehicle_shift_end_is_reachable(ctx, matrices), check_e1512_job_locations_are_reachable(ctx, matrices)]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect()
+}
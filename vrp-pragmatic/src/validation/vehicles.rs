@@ -347,6 +347,186 @@ fn check_e1309_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that vehicle on board jobs are correct.
+fn check_e1310_vehicle_onboard_jobs_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let onboard_ids = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .flat_map(|shift| shift.onboard.iter().flatten())
+        .collect::<Vec<_>>();
+
+    if let Some(ids) = get_duplicates(onboard_ids.into_iter()) {
+        return Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid on board jobs in vehicle shift".to_string(),
+            format!("job ids should be on board of one vehicle shift only: '{}'", ids.join(", ")),
+        ));
+    }
+
+    let is_valid_job = |job_id: &String| {
+        ctx.job_index.get(job_id).map_or(false, |job| {
+            job.services.is_none()
+                && job.replacements.is_none()
+                && job.deliveries.as_ref().map_or(false, |tasks| {
+                    !tasks.is_empty()
+                        && tasks.iter().all(|task| {
+                            task.places.len() == 1 && task.places[0].times.as_ref().map_or(true, |tws| tws.len() < 2)
+                        })
+                })
+        })
+    };
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.shifts.iter().filter_map(|shift| shift.onboard.as_ref()).any(|onboard| {
+                !onboard.is_empty() && (vehicle.vehicle_ids.len() != 1 || !onboard.iter().all(is_valid_job))
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid on board jobs in vehicle shift".to_string(),
+            format!(
+                "ensure that vehicle type has single vehicle id and on board jobs have only pickups and deliveries \
+                 with one place and at most one time window, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that fleet limits are correct.
+fn check_e1311_fleet_limits_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx.vehicles().map(|vehicle| &vehicle.type_id).collect::<HashSet<_>>();
+    let tags =
+        ctx.vehicles().filter_map(|vehicle| vehicle.tags.as_ref()).flat_map(|tags| tags.iter()).collect::<HashSet<_>>();
+
+    let invalid_limits = ctx
+        .problem
+        .fleet
+        .limits
+        .iter()
+        .flat_map(|limits| limits.iter())
+        .filter(|limit| {
+            let has_valid_target = match (&limit.type_id, &limit.tag) {
+                (Some(type_id), None) => type_ids.contains(type_id),
+                (None, Some(tag)) => tags.contains(tag),
+                _ => false,
+            };
+            let has_valid_range = match (limit.min_tours, limit.max_tours) {
+                (Some(min_tours), Some(max_tours)) => min_tours <= max_tours,
+                (None, None) => false,
+                _ => true,
+            };
+
+            !has_valid_target || !has_valid_range
+        })
+        .map(|limit| limit.type_id.as_ref().or(limit.tag.as_ref()).cloned().unwrap_or_default())
+        .collect::<Vec<_>>();
+
+    if invalid_limits.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid fleet limits".to_string(),
+            format!(
+                "ensure that each fleet limit has either known type id or known tag and min tours is not greater \
+                 than max tours, check limits: '{}'",
+                invalid_limits.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle min limits are not greater than max limits.
+fn check_e1312_vehicle_min_limits_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().map_or(false, |limits| {
+                let has_invalid_tour_size = limits
+                    .min_tour_size
+                    .zip(limits.tour_size)
+                    .map_or(false, |(min_tour_size, tour_size)| min_tour_size > tour_size);
+                let has_invalid_shift_time = limits
+                    .min_shift_time
+                    .zip(limits.shift_time)
+                    .map_or(false, |(min_shift_time, shift_time)| min_shift_time > shift_time);
+
+                has_invalid_tour_size || has_invalid_shift_time
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle min limits".to_string(),
+            format!(
+                "ensure that minTourSize is not greater than tourSize and minShiftTime is not greater than \
+                 shiftTime, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle max waiting time limits are not negative.
+fn check_e1313_vehicle_waiting_limits_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids =
+        ctx.vehicles()
+            .filter(|vehicle| {
+                vehicle.limits.as_ref().and_then(|limits| limits.max_waiting_time.as_ref()).map_or(false, |limits| {
+                    limits.per_stop.iter().chain(limits.per_tour.iter()).any(|limit| *limit < 0.)
+                })
+            })
+            .map(|vehicle| vehicle.type_id.to_string())
+            .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle max waiting time limits".to_string(),
+            format!("ensure that maxWaitingTime limits are not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle travel buffers are not negative.
+fn check_e1314_vehicle_travel_buffers_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.profile.buffer.as_ref().map_or(false, |buffer| {
+                buffer.percentage.iter().chain(buffer.fixed.iter()).chain(buffer.slack.iter()).any(|value| *value < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle travel buffer".to_string(),
+            format!("ensure that profile buffer values are not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift: CheckShiftFn) -> Vec<String> {
@@ -383,6 +563,91 @@ fn get_shift_time_window(shift: &VehicleShift) -> Option<TimeWindow> {
     )
 }
 
+/// Checks that vehicle physical attributes are not negative.
+fn check_e1315_vehicle_attributes_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.attributes.as_ref().map_or(false, |attributes| {
+                attributes
+                    .weight
+                    .iter()
+                    .chain(attributes.height.iter())
+                    .chain(attributes.length.iter())
+                    .any(|value| !value.is_finite() || *value < 0.)
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid vehicle attributes".to_string(),
+            format!("ensure that vehicle attributes are not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that unlimited vehicle types have no vehicle specific shift properties.
+fn check_e1316_unlimited_vehicle_shifts_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_not_empty = |items: Option<usize>| items.map_or(false, |len| len > 0);
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.is_unlimited.unwrap_or(false))
+        .filter(|vehicle| {
+            vehicle.shifts.iter().any(|shift| {
+                is_not_empty(shift.breaks.as_ref().map(|breaks| breaks.len()))
+                    || is_not_empty(shift.reloads.as_ref().map(|reloads| reloads.len()))
+                    || is_not_empty(shift.dispatch.as_ref().map(|dispatch| dispatch.len()))
+                    || is_not_empty(shift.onboard.as_ref().map(|onboard| onboard.len()))
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "unsupported shift properties of unlimited vehicle type".to_string(),
+            format!(
+                "remove breaks, reloads, dispatch and onboard jobs from shifts of unlimited vehicle types: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that listed vehicle ids cannot be confused with ids of vehicles created on demand for unlimited type.
+fn check_e1317_unlimited_vehicle_ids_are_unique(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let unlimited_types = ctx.vehicles().filter(|vehicle| vehicle.is_unlimited.unwrap_or(false)).collect::<Vec<_>>();
+
+    let vehicle_ids = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.vehicle_ids.iter())
+        .filter(|vehicle_id| unlimited_types.iter().any(|vehicle| is_generated_vehicle_id(vehicle, vehicle_id)))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1317".to_string(),
+            "vehicle id collides with id of unlimited vehicle type".to_string(),
+            format!(
+                "rename vehicles which have '{{typeId}}_{{index}}' id reserved for unlimited vehicle types: '{}'",
+                vehicle_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates vehicles from the fleet.
 pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -396,5 +661,13 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1307_vehicle_has_no_zero_costs(ctx),
         check_e1308_vehicle_required_break_rescheduling(ctx),
         check_e1309_vehicle_reload_resources(ctx),
+        check_e1310_vehicle_onboard_jobs_are_correct(ctx),
+        check_e1311_fleet_limits_are_correct(ctx),
+        check_e1312_vehicle_min_limits_are_correct(ctx),
+        check_e1313_vehicle_waiting_limits_are_correct(ctx),
+        check_e1314_vehicle_travel_buffers_are_correct(ctx),
+        check_e1315_vehicle_attributes_are_correct(ctx),
+        check_e1316_unlimited_vehicle_shifts_are_correct(ctx),
+        check_e1317_unlimited_vehicle_ids_are_unique(ctx),
     ])
 }
@@ -69,6 +69,7 @@ prop_compose! {
             },
             fleet,
             objectives: None,
+            precision: None,
         }
     }
 }
@@ -40,9 +40,9 @@ fn can_assign_break_between_jobs() {
         Solution {
             statistic: Statistic {
                 cost: 54.,
-                distance: 20,
-                duration: 24,
-                times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                distance: 20.,
+                duration: 24.,
+                times: Timing { driving: 20., serving: 2., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -93,9 +93,9 @@ fn can_assign_break_between_jobs() {
                 ],
                 statistic: Statistic {
                     cost: 54.,
-                    distance: 20,
-                    duration: 24,
-                    times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    distance: 20.,
+                    duration: 24.,
+                    times: Timing { driving: 20., serving: 2., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -48,9 +48,9 @@ fn can_assign_break_using_second_place() {
         Solution {
             statistic: Statistic {
                 cost: 74.,
-                distance: 30,
-                duration: 34,
-                times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                distance: 30.,
+                duration: 34.,
+                times: Timing { driving: 30., serving: 2., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -101,9 +101,9 @@ fn can_assign_break_using_second_place() {
                 ],
                 statistic: Statistic {
                     cost: 74.,
-                    distance: 30,
-                    duration: 34,
-                    times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                    distance: 30.,
+                    duration: 34.,
+                    times: Timing { driving: 30., serving: 2., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -30,6 +30,7 @@ fn can_assign_interval_break_between_jobs() {
             ..create_default_fleet()
         },
         objectives: create_test_objectives(),
+        precision: None,
     };
     let matrix = create_matrix_from_problem(&problem);
 
@@ -40,9 +41,9 @@ fn can_assign_interval_break_between_jobs() {
         Solution {
             statistic: Statistic {
                 cost: 74.,
-                distance: 30,
-                duration: 34,
-                times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                distance: 30.,
+                duration: 34.,
+                times: Timing { driving: 30., serving: 2., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -63,7 +64,7 @@ fn can_assign_interval_break_between_jobs() {
                             arrival: "1970-01-01T00:00:05Z".to_string(),
                             departure: "1970-01-01T00:00:08Z".to_string(),
                         },
-                        distance: 5,
+                        distance: 5.,
                         load: vec![1],
                         parking: None,
                         activities: vec![
@@ -110,9 +111,9 @@ fn can_assign_interval_break_between_jobs() {
                 ],
                 statistic: Statistic {
                     cost: 74.,
-                    distance: 30,
-                    duration: 34,
-                    times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                    distance: 30.,
+                    duration: 34.,
+                    times: Timing { driving: 30., serving: 2., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -153,6 +154,7 @@ fn can_assign_interval_break_with_reload() {
                         duration: 3.0,
                         ..create_default_reload()
                     }]),
+                    onboard: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -171,9 +173,9 @@ fn can_assign_interval_break_with_reload() {
         Solution {
             statistic: Statistic {
                 cost: 139.,
-                distance: 60,
-                duration: 69,
-                times: Timing { driving: 60, serving: 7, break_time: 2, ..Timing::default() },
+                distance: 60.,
+                duration: 69.,
+                times: Timing { driving: 60., serving: 7., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -194,7 +196,7 @@ fn can_assign_interval_break_with_reload() {
                             arrival: "1970-01-01T00:00:10Z".to_string(),
                             departure: "1970-01-01T00:00:13Z".to_string(),
                         },
-                        distance: 10,
+                        distance: 10.,
                         load: vec![1],
                         parking: None,
                         activities: vec![
@@ -265,9 +267,9 @@ fn can_assign_interval_break_with_reload() {
                 ],
                 statistic: Statistic {
                     cost: 139.,
-                    distance: 60,
-                    duration: 69,
-                    times: Timing { driving: 60, serving: 7, break_time: 2, ..Timing::default() },
+                    distance: 60.,
+                    duration: 69.,
+                    times: Timing { driving: 60., serving: 7., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -51,9 +51,9 @@ fn can_use_two_breaks() {
         Solution {
             statistic: Statistic {
                 cost: 412.,
-                distance: 198,
-                duration: 204,
-                times: Timing { driving: 198, serving: 2, break_time: 4, ..Timing::default() },
+                distance: 198.,
+                duration: 204.,
+                times: Timing { driving: 198., serving: 2., break_time: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,7 +90,7 @@ fn can_use_two_breaks() {
                             arrival: "1970-01-01T00:01:42Z".to_string(),
                             departure: "1970-01-01T00:01:45Z".to_string(),
                         },
-                        distance: 99,
+                        distance: 99.,
                         parking: None,
                         load: vec![0],
                         activities: vec![
@@ -129,9 +129,9 @@ fn can_use_two_breaks() {
                 ],
                 statistic: Statistic {
                     cost: 412.,
-                    distance: 198,
-                    duration: 204,
-                    times: Timing { driving: 198, serving: 2, break_time: 4, ..Timing::default() },
+                    distance: 198.,
+                    duration: 204.,
+                    times: Timing { driving: 198., serving: 2., break_time: 4., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
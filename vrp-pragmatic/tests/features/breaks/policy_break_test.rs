@@ -40,6 +40,7 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                             policy,
                         }]),
                         reloads: None,
+                        onboard: None,
                     }],
                     ..create_default_vehicle_type()
                 },
@@ -58,9 +59,9 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
         Solution {
             statistic: Statistic {
                 cost: 52.,
-                distance: 20,
-                duration: 22,
-                times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                distance: 20.,
+                duration: 22.,
+                times: Timing { driving: 20., serving: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_without_break_1".to_string(),
@@ -102,9 +103,9 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                 ],
                 statistic: Statistic {
                     cost: 52.,
-                    distance: 20,
-                    duration: 22,
-                    times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                    distance: 20.,
+                    duration: 22.,
+                    times: Timing { driving: 20., serving: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -154,9 +155,9 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
         Solution {
             statistic: Statistic {
                 cost: 24.,
-                distance: 2,
-                duration: 12,
-                times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                distance: 2.,
+                duration: 12.,
+                times: Timing { driving: 2., serving: 10., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -190,9 +191,9 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                 ],
                 statistic: Statistic {
                     cost: 24.,
-                    distance: 2,
-                    duration: 12,
-                    times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                    distance: 2.,
+                    duration: 12.,
+                    times: Timing { driving: 2., serving: 10., ..Timing::default() },
                 },
             }],
             violations: Some(vec![Violation::Break { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
@@ -252,9 +253,9 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
         Solution {
             statistic: Statistic {
                 cost: 54.,
-                distance: 20,
-                duration: 24,
-                times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                distance: 20.,
+                duration: 24.,
+                times: Timing { driving: 20., serving: 2., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -304,9 +305,9 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                 ],
                 statistic: Statistic {
                     cost: 54.,
-                    distance: 20,
-                    duration: 24,
-                    times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    distance: 20.,
+                    duration: 24.,
+                    times: Timing { driving: 20., serving: 2., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -355,5 +356,5 @@ fn can_skip_break_depending_on_policy_impl(
     let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
 
     assert!(solution.violations.is_none());
-    assert_eq!(solution.statistic.times.break_time, expected);
+    assert_eq!(solution.statistic.times.break_time, expected as f64);
 }
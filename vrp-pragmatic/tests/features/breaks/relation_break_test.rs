@@ -76,9 +76,9 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
         Solution {
             statistic: Statistic {
                 cost: 26.,
-                distance: 6,
-                duration: 10,
-                times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                distance: 6.,
+                duration: 10.,
+                times: Timing { driving: 6., serving: 2., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -128,9 +128,9 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                 ],
                 statistic: Statistic {
                     cost: 26.,
-                    distance: 6,
-                    duration: 10,
-                    times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                    distance: 6.,
+                    duration: 10.,
+                    times: Timing { driving: 6., serving: 2., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -155,9 +155,9 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
         Solution {
             statistic: Statistic {
                 cost: 26.,
-                distance: 6,
-                duration: 10,
-                times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                distance: 6.,
+                duration: 10.,
+                times: Timing { driving: 6., serving: 2., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -207,9 +207,9 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                 ],
                 statistic: Statistic {
                     cost: 26.,
-                    distance: 6,
-                    duration: 10,
-                    times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                    distance: 6.,
+                    duration: 10.,
+                    times: Timing { driving: 6., serving: 2., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -40,9 +40,9 @@ fn can_assign_break_during_travel() {
         Solution {
             statistic: Statistic {
                 cost: 54.,
-                distance: 20,
-                duration: 24,
-                times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                distance: 20.,
+                duration: 24.,
+                times: Timing { driving: 20., serving: 2., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -99,9 +99,9 @@ fn can_assign_break_during_travel() {
                 ],
                 statistic: Statistic {
                     cost: 54.,
-                    distance: 20,
-                    duration: 24,
-                    times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    distance: 20.,
+                    duration: 24.,
+                    times: Timing { driving: 20., serving: 2., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -139,9 +139,9 @@ fn can_assign_break_during_activity() {
         Solution {
             statistic: Statistic {
                 cost: 35.,
-                distance: 10,
-                duration: 15,
-                times: Timing { driving: 10, serving: 3, break_time: 2, ..Timing::default() },
+                distance: 10.,
+                duration: 15.,
+                times: Timing { driving: 10., serving: 3., break_time: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -162,7 +162,7 @@ fn can_assign_break_during_activity() {
                             arrival: "1970-01-01T00:00:05Z".to_string(),
                             departure: "1970-01-01T00:00:10Z".to_string(),
                         },
-                        distance: 5,
+                        distance: 5.,
                         parking: None,
                         load: vec![0],
                         activities: vec![
@@ -201,9 +201,9 @@ fn can_assign_break_during_activity() {
                 ],
                 statistic: Statistic {
                     cost: 35.,
-                    distance: 10,
-                    duration: 15,
-                    times: Timing { driving: 10, serving: 3, break_time: 2, ..Timing::default() },
+                    distance: 10.,
+                    duration: 15.,
+                    times: Timing { driving: 10., serving: 3., break_time: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -76,7 +76,7 @@ impl From<StopData> for Stop {
         Stop::Point(PointStop {
             location: stop.location,
             time: Schedule { arrival: format_time(stop.time.0), departure: format_time(stop.time.1) },
-            distance: stop.distance,
+            distance: stop.distance as f64,
             parking: if stop.parking > 0 {
                 Some(Interval { start: format_time(stop.time.0), end: format_time(stop.time.0 + stop.parking as f64) })
             } else {
@@ -91,13 +91,13 @@ impl From<StopData> for Stop {
 fn create_statistic(data: (f64, i64, i64, (i64, i64, i64, i64))) -> Statistic {
     Statistic {
         cost: data.0,
-        distance: data.1,
-        duration: data.2,
+        distance: data.1 as f64,
+        duration: data.2 as f64,
         times: Timing {
-            driving: data.3 .0,
-            serving: data.3 .1,
-            commuting: data.3 .2,
-            parking: data.3 .3,
+            driving: data.3 .0 as f64,
+            serving: data.3 .1 as f64,
+            commuting: data.3 .2 as f64,
+            parking: data.3 .3 as f64,
             ..Timing::default()
         },
     }
@@ -36,9 +36,9 @@ fn can_assign_single_dispatch() {
         Solution {
             statistic: Statistic {
                 cost: 42.,
-                distance: 14,
-                duration: 18,
-                times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                distance: 14.,
+                duration: 18.,
+                times: Timing { driving: 14., serving: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -88,9 +88,9 @@ fn can_assign_single_dispatch() {
                 ],
                 statistic: Statistic {
                     cost: 42.,
-                    distance: 14,
-                    duration: 18,
-                    times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                    distance: 14.,
+                    duration: 18.,
+                    times: Timing { driving: 14., serving: 4., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
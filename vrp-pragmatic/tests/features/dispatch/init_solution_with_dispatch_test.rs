@@ -44,9 +44,9 @@ fn can_use_init_solution_with_dispatch() {
     let init_solution = Solution {
         statistic: Statistic {
             cost: 34.,
-            distance: 4,
-            duration: 10,
-            times: Timing { driving: 4, serving: 6, ..Timing::default() },
+            distance: 4.,
+            duration: 10.,
+            times: Timing { driving: 4., serving: 6., ..Timing::default() },
         },
         tours: vec![
             Tour {
@@ -60,7 +60,7 @@ fn can_use_init_solution_with_dispatch() {
                             arrival: "1970-01-01T00:00:00Z".to_string(),
                             departure: "1970-01-01T00:00:04Z".to_string(),
                         },
-                        distance: 0,
+                        distance: 0.,
                         parking: None,
                         load: vec![1],
                         activities: vec![
@@ -107,9 +107,9 @@ fn can_use_init_solution_with_dispatch() {
                 ],
                 statistic: Statistic {
                     cost: 17.,
-                    distance: 2,
-                    duration: 5,
-                    times: Timing { driving: 2, serving: 3, ..Timing::default() },
+                    distance: 2.,
+                    duration: 5.,
+                    times: Timing { driving: 2., serving: 3., ..Timing::default() },
                 },
             },
             Tour {
@@ -123,7 +123,7 @@ fn can_use_init_solution_with_dispatch() {
                             arrival: "1970-01-01T00:00:00Z".to_string(),
                             departure: "1970-01-01T00:00:06Z".to_string(),
                         },
-                        distance: 0,
+                        distance: 0.,
                         parking: None,
                         load: vec![1],
                         activities: vec![
@@ -170,9 +170,9 @@ fn can_use_init_solution_with_dispatch() {
                 ],
                 statistic: Statistic {
                     cost: 17.,
-                    distance: 2,
-                    duration: 5,
-                    times: Timing { driving: 2, serving: 3, ..Timing::default() },
+                    distance: 2.,
+                    duration: 5.,
+                    times: Timing { driving: 2., serving: 3., ..Timing::default() },
                 },
             },
         ],
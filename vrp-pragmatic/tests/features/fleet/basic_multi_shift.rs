@@ -44,9 +44,9 @@ fn can_use_multiple_times_from_vehicle_and_job() {
         Solution {
             statistic: Statistic {
                 cost: 102.,
-                distance: 40,
-                duration: 42,
-                times: Timing { driving: 40, serving: 2, ..Timing::default() },
+                distance: 40.,
+                duration: 42.,
+                times: Timing { driving: 40., serving: 2., ..Timing::default() },
             },
             tours: vec![
                 Tour {
@@ -81,9 +81,9 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                     ],
                     statistic: Statistic {
                         cost: 51.,
-                        distance: 20,
-                        duration: 21,
-                        times: Timing { driving: 20, serving: 1, ..Timing::default() },
+                        distance: 20.,
+                        duration: 21.,
+                        times: Timing { driving: 20., serving: 1., ..Timing::default() },
                     },
                 },
                 Tour {
@@ -118,9 +118,9 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                     ],
                     statistic: Statistic {
                         cost: 51.,
-                        distance: 20,
-                        duration: 21,
-                        times: Timing { driving: 20, serving: 1, ..Timing::default() },
+                        distance: 20.,
+                        duration: 21.,
+                        times: Timing { driving: 20., serving: 1., ..Timing::default() },
                     },
                 },
             ],
@@ -24,9 +24,9 @@ fn can_use_vehicle_with_open_end() {
         Solution {
             statistic: Statistic {
                 cost: 13.,
-                distance: 1,
-                duration: 2,
-                times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                distance: 1.,
+                duration: 2.,
+                times: Timing { driving: 1., serving: 1., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -52,9 +52,9 @@ fn can_use_vehicle_with_open_end() {
                 ],
                 statistic: Statistic {
                     cost: 13.,
-                    distance: 1,
-                    duration: 2,
-                    times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                    distance: 1.,
+                    duration: 2.,
+                    times: Timing { driving: 1., serving: 1., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
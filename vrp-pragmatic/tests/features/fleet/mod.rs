@@ -2,4 +2,6 @@ mod basic_multi_shift;
 mod basic_open_end;
 mod multi_dimens;
 mod profile_variation;
+mod unlimited_vehicles;
 mod unreachable_jobs;
+mod vehicle_access;
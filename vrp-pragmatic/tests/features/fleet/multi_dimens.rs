@@ -31,9 +31,9 @@ fn can_use_two_dimensions() {
         Solution {
             statistic: Statistic {
                 cost: 16.,
-                distance: 2,
-                duration: 4,
-                times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                distance: 2.,
+                duration: 4.,
+                times: Timing { driving: 2., serving: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -67,9 +67,9 @@ fn can_use_two_dimensions() {
                 ],
                 statistic: Statistic {
                     cost: 16.,
-                    distance: 2,
-                    duration: 4,
-                    times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                    distance: 2.,
+                    duration: 4.,
+                    times: Timing { driving: 2., serving: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -4,7 +4,7 @@ use crate::helpers::*;
 fn create_vehicle_type(type_id: &str, scale: Option<f64>) -> VehicleType {
     VehicleType {
         type_id: type_id.to_string(),
-        profile: VehicleProfile { matrix: "car".to_string(), scale },
+        profile: VehicleProfile { matrix: "car".to_string(), scale, buffer: None },
         vehicle_ids: vec![format!("{}_1", type_id)],
         ..create_default_vehicle_type()
     }
@@ -27,6 +27,6 @@ fn can_use_scale() {
     assert_eq!(solution.tours.len(), 1);
     let tour = solution.tours.first().unwrap();
     assert_eq!(tour.vehicle_id, "slow_1");
-    assert_eq!(tour.statistic.distance, 20);
-    assert_eq!(tour.statistic.duration, 11)
+    assert_eq!(tour.statistic.distance, 20.);
+    assert_eq!(tour.statistic.duration, 11.)
 }
@@ -0,0 +1,37 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_add_vehicles_of_unlimited_type_on_demand() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                is_unlimited: Some(true),
+                ..create_vehicle_with_capacity("my_vehicle", vec![1])
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 3);
+    assert!(solution
+        .tours
+        .iter()
+        .all(|tour| tour.type_id == "my_vehicle" && tour.vehicle_id.starts_with("my_vehicle_")));
+    let fleet = solution.extras.as_ref().and_then(|extras| extras.fleet.as_ref()).expect("no fleet summary");
+    assert_eq!(fleet.len(), 1);
+    assert_eq!((fleet[0].type_id.as_str(), fleet[0].vehicles, fleet[0].tours), ("my_vehicle", 3, 3));
+}
@@ -15,6 +15,10 @@ fn can_use_vehicle_with_open_end() {
         travel_times: vec![0, 1, 1, 0],
         distances: vec![0, 1, 1, 0],
         error_codes: Some(vec![0, 1, 1, 1]),
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -28,7 +32,7 @@ fn can_use_vehicle_with_open_end() {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "REACHABLE_CONSTRAINT".to_string(),
-                    description: "location unreachable".to_string(),
+                    description: "location unreachable: from lat=0, lng=0 to lat=1, lng=0".to_string(),
                     details: None,
                 }]
             }]),
@@ -36,3 +40,28 @@ fn can_use_vehicle_with_open_end() {
         }
     );
 }
+
+#[test]
+fn can_name_unreachable_leg_back_to_shift_end() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = Matrix { error_codes: Some(vec![0, 1, 0, 0]), ..create_matrix(vec![0, 1, 1, 0]) };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "REACHABLE_CONSTRAINT".to_string(),
+                description: "location unreachable: from lat=1, lng=0 to lat=0, lng=0".to_string(),
+                details: None,
+            }]
+        }])
+    );
+}
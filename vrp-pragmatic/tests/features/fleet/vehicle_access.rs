@@ -0,0 +1,72 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_restricted_job(id: &str, location: (f64, f64), max_weight: f64) -> Job {
+    let mut job = create_delivery_job(id, location);
+    job.deliveries.as_mut().unwrap().first_mut().unwrap().places.first_mut().unwrap().access =
+        Some(PlaceAccess { max_weight: Some(max_weight), max_height: None, max_length: None });
+
+    job
+}
+
+fn create_vehicle_with_weight(id: &str, weight: f64) -> VehicleType {
+    VehicleType {
+        attributes: Some(VehicleAttributes { weight: Some(weight), height: None, length: None }),
+        ..create_default_vehicle(id)
+    }
+}
+
+#[test]
+fn can_serve_restricted_job_with_allowed_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_restricted_job("job1", (1., 0.), 7.5), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_weight("heavy", 12.), create_vehicle_with_weight("light", 3.5)],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution
+        .tours
+        .iter()
+        .find(|tour| tour.stops.iter().flat_map(|stop| stop.activities()).any(|activity| activity.job_id == "job1"))
+        .expect("cannot find tour with restricted job");
+    assert_eq!(tour.type_id, "light");
+}
+
+#[test]
+fn can_skip_restricted_job_when_no_allowed_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_restricted_job("job1", (1., 0.), 7.5), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_weight("heavy", 12.)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "VEHICLE_ACCESS_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to access restrictions of job location".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "heavy_1".to_string(), shift_index: 0 }]),
+            }]
+        }])
+    );
+}
@@ -0,0 +1,104 @@
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::helpers::*;
+
+fn create_test_problem(precision: Option<Precision>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        precision,
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_matrix() -> Matrix {
+    let (travel_times, distances, fractions) = split_matrix_values(vec![0., 1.5, 1.5, 0.], vec![0., 2.25, 2.25, 0.]);
+
+    Matrix { travel_times, distances, fractions, ..create_matrix(vec![0; 4]) }
+}
+
+#[test]
+fn can_use_fractional_matrix_values_with_float_precision() {
+    let solution =
+        solve_with_cheapest_insertion(create_test_problem(Some(Precision::Float)), Some(vec![create_test_matrix()]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.distance, 2.25);
+    assert_eq!(solution.statistic.times.driving, 1.5);
+    assert_eq!(solution.statistic.duration, 2.5);
+
+    let stop = solution.tours[0].stops.last().unwrap();
+    assert_eq!(stop.as_point().map(|point| point.distance), Some(2.25));
+    assert_eq!(stop.schedule().arrival, "1970-01-01T00:00:01.5Z");
+    assert_eq!(stop.schedule().departure, "1970-01-01T00:00:02.5Z");
+
+    let json = serde_json::to_string(&solution).unwrap();
+    assert!(json.contains("\"distance\":2.25,\"duration\":2.5,"));
+    assert!(json.contains("\"driving\":1.5,"));
+    assert_eq!(serde_json::from_str::<Solution>(json.as_str()).unwrap(), solution);
+}
+
+#[test]
+fn can_keep_integer_values_with_default_precision() {
+    let matrix = Matrix { distances: vec![0, 3, 3, 0], ..create_matrix(vec![0, 2, 2, 0]) };
+
+    let solution = solve_with_cheapest_insertion(create_test_problem(None), Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.distance, 3.);
+    assert_eq!(solution.statistic.duration, 3.);
+
+    let stop = solution.tours[0].stops.last().unwrap();
+    assert_eq!(stop.schedule().arrival, "1970-01-01T00:00:02Z");
+    let json = serde_json::to_string(&solution.statistic).unwrap();
+    assert!(json.contains("\"distance\":3,"));
+}
+
+#[test]
+fn can_serialize_integer_matrix_values_as_integers() {
+    let matrix = Matrix { distances: vec![0, 3, 3, 0], ..create_matrix(vec![0, 2, 2, 0]) };
+
+    let json = serde_json::to_string(&matrix).unwrap();
+
+    assert!(json.contains("\"travelTimes\":[0,2,2,0]"));
+    assert!(json.contains("\"distances\":[0,3,3,0]"));
+}
+
+#[test]
+fn can_serialize_fractional_matrix_values() {
+    let json = serde_json::to_string(&create_test_matrix()).unwrap();
+
+    assert!(json.contains("\"travelTimes\":[0,1.5,1.5,0]"));
+    assert!(json.contains("\"distances\":[0,2.25,2.25,0]"));
+}
+
+#[test]
+fn can_deserialize_fractional_matrix_values() {
+    let json = r#"{"profile":"car","travelTimes":[0,1.5,1.5,0],"distances":[0,2,2,0]}"#;
+
+    let matrix = serde_json::from_str::<Matrix>(json).unwrap();
+
+    assert_eq!(matrix.travel_times, vec![0, 2, 2, 0]);
+    assert_eq!(matrix.distances, vec![0, 2, 2, 0]);
+    assert_eq!(
+        matrix.fractions,
+        Some(MatrixFractions { travel_times: vec![0., 1.5, 1.5, 0.], distances: vec![0., 2., 2., 0.] })
+    );
+}
+
+#[test]
+fn can_deserialize_integer_matrix_values_without_fractions() {
+    let json = r#"{"profile":"car","durations":[0,2,2,0],"distances":[0,3,3,0]}"#;
+
+    let matrix = serde_json::from_str::<Matrix>(json).unwrap();
+
+    assert_eq!(matrix.travel_times, vec![0, 2, 2, 0]);
+    assert_eq!(matrix.distances, vec![0, 3, 3, 0]);
+    assert!(matrix.fractions.is_none());
+}
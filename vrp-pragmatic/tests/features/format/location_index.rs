@@ -35,6 +35,10 @@ fn create_test_matrix() -> Matrix {
         travel_times: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         distances: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
     }
 }
 
@@ -50,9 +54,9 @@ fn can_use_location_index() {
         Solution {
             statistic: Statistic {
                 cost: 18.,
-                distance: 3,
-                duration: 5,
-                times: Timing { driving: 3, serving: 2, ..Timing::default() }
+                distance: 3.,
+                duration: 5.,
+                times: Timing { driving: 3., serving: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -98,9 +102,9 @@ fn can_use_location_index() {
                 ],
                 statistic: Statistic {
                     cost: 18.,
-                    distance: 3,
-                    duration: 5,
-                    times: Timing { driving: 3, serving: 2, ..Timing::default() }
+                    distance: 3.,
+                    duration: 5.,
+                    times: Timing { driving: 3., serving: 2., ..Timing::default() },
                 }
             }],
             ..create_empty_solution()
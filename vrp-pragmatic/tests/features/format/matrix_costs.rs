@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: Location::Reference { index: 2 },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_matrix(costs: Option<Vec<f64>>) -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 1, 3, 1, 0, 3, 3, 3, 0],
+        distances: vec![0, 1, 3, 1, 0, 3, 3, 3, 0],
+        error_codes: None,
+        costs,
+        interpolation: None,
+        compact: None,
+        fractions: None,
+    }
+}
+
+#[test]
+fn can_avoid_leg_with_high_extra_cost() {
+    let problem = create_test_problem();
+    let matrix = create_test_matrix(Some(vec![0., 0., 0., 0., 0., 0., 5., 10., 0.]));
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure".to_string()], vec!["job1".to_string()], vec!["job2".to_string()]]
+    );
+    assert_eq!(solution.statistic.distance, 4.);
+    assert_eq!(solution.statistic.duration, 6.);
+    assert_eq!(solution.statistic.cost, 25.);
+}
@@ -1 +1,5 @@
+mod float_precision;
 mod location_index;
+mod matrix_costs;
+mod planar_points;
+mod speed_curve;
@@ -0,0 +1,59 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_test_problem(metric: Option<DistanceMetric>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_point("job1", (100., 0.)),
+                create_delivery_job_with_point("job2", (100., 100.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { location: Location::new_point(0., 0.), ..create_default_vehicle_shift().start },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: "1970-01-01T01:00:00Z".to_string(),
+                        location: Location::new_point(0., 0.),
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: Some(1.),
+                speed_curve: None,
+                circuity: None,
+                metric,
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_use_planar_points_with_approximation, (metric, expected_distance), {
+    can_use_planar_points_with_approximation_impl(metric, expected_distance);
+}}
+
+can_use_planar_points_with_approximation! {
+    case01_default: (None, 341),
+    case02_euclidean: (Some(DistanceMetric::Euclidean), 341),
+    case03_manhattan: (Some(DistanceMetric::Manhattan), 400),
+}
+
+fn can_use_planar_points_with_approximation_impl(metric: Option<DistanceMetric>, expected_distance: i64) {
+    let problem = create_test_problem(metric);
+
+    let solution = solve_with_cheapest_insertion(problem, None);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, expected_distance as f64);
+    assert_eq!(solution.tours[0].stops[0].as_point().map(|stop| &stop.location), Some(&Location::new_point(0., 0.)));
+}
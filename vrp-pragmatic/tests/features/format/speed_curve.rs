@@ -0,0 +1,37 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem(speed_curve: Option<Vec<HourlySpeed>>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (0., 0.01))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_curve,
+                circuity: None,
+                metric: None,
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_speed_curve_for_approximated_routing() {
+    let speed_curve = vec![HourlySpeed { hour: 0., speed: 1. }, HourlySpeed { hour: 12., speed: 10. }];
+
+    let regular = solve_with_cheapest_insertion(create_test_problem(None), None);
+    let slowed = solve_with_cheapest_insertion(create_test_problem(Some(speed_curve)), None);
+
+    assert!(regular.unassigned.is_none());
+    assert!(slowed.unassigned.is_none());
+    assert_eq!(regular.statistic.distance, slowed.statistic.distance);
+    assert_eq!(regular.statistic.times.driving, 111.);
+    assert_eq!(slowed.statistic.times.driving, 1113.);
+}
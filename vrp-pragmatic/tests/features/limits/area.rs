@@ -35,6 +35,10 @@ fn can_use_constrained_areas() {
                         vec![AreaLimit { area_id: "area1".to_string(), job_value: 10. }],
                         vec![AreaLimit { area_id: "area2".to_string(), job_value: 1. }],
                     ]),
+                    backhaul: None,
+                    min_shift_time: None,
+                    min_tour_size: None,
+                    max_waiting_time: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -92,6 +96,10 @@ fn can_use_unconstrained_areas_impl(area1_job_value: f64, expected_job_ids: Vec<
                         vec![AreaLimit { area_id: "area1".to_string(), job_value: area1_job_value }],
                         vec![AreaLimit { area_id: "area2".to_string(), job_value: 1. }],
                     ]),
+                    backhaul: None,
+                    min_shift_time: None,
+                    min_tour_size: None,
+                    max_waiting_time: None,
                 }),
                 ..create_default_vehicle_type()
             }],
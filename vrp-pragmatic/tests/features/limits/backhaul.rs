@@ -0,0 +1,86 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn get_activity_types(solution: &Solution) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.activity_type.clone())
+        .filter(|activity_type| activity_type == "delivery" || activity_type == "pickup")
+        .collect()
+}
+
+#[test]
+fn can_serve_deliveries_before_pickups_with_backhaul() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_pickup_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    areas: None,
+                    backhaul: Some(true),
+                    min_shift_time: None,
+                    min_tour_size: None,
+                    max_waiting_time: None,
+                }),
+                ..create_default_vehicle("v1")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_activity_types(&solution), vec!["delivery", "delivery", "pickup", "pickup"]);
+}
+
+#[test]
+fn can_ignore_pickup_delivery_jobs_with_backhaul() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_pickup_job("job1", (1., 0.)), create_pickup_delivery_job("job2", (2., 0.), (3., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    areas: None,
+                    backhaul: Some(true),
+                    min_shift_time: None,
+                    min_tour_size: None,
+                    max_waiting_time: None,
+                }),
+                ..create_default_vehicle("v1")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_activity_types(&solution), vec!["pickup", "pickup", "delivery"]);
+}
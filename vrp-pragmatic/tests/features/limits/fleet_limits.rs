@@ -0,0 +1,74 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_fleet_limit(
+    type_id: Option<&str>,
+    tag: Option<&str>,
+    min_tours: Option<usize>,
+    max_tours: Option<usize>,
+) -> FleetLimit {
+    FleetLimit { type_id: type_id.map(|t| t.to_string()), tag: tag.map(|t| t.to_string()), max_tours, min_tours }
+}
+
+#[test]
+fn can_limit_max_tours_per_vehicle_type() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1_1".to_string(), "v1_2".to_string()],
+                capacity: vec![1],
+                ..create_default_vehicle("v1")
+            }],
+            limits: Some(vec![create_fleet_limit(Some("v1"), None, None, Some(1))]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.iter().flat_map(|unassigned| unassigned.iter()).count(), 1);
+}
+
+#[test]
+fn can_report_min_tours_violation_per_vehicle_tag() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("v1"),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    tags: Some(vec!["rental".to_string()]),
+                    ..create_default_vehicle("v2")
+                },
+            ],
+            limits: Some(vec![create_fleet_limit(None, Some("rental"), Some(2), None)]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].type_id, "v2");
+    assert_eq!(
+        solution.violations,
+        Some(vec![Violation::FleetUsage {
+            type_id: None,
+            tag: Some("rental".to_string()),
+            min_tours: 2,
+            actual_tours: 1
+        }])
+    );
+}
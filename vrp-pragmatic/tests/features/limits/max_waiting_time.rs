@@ -0,0 +1,101 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_waiting_limits(per_stop: Option<f64>, per_tour: Option<f64>) -> VehicleType {
+    let shift = create_default_vehicle_shift();
+
+    VehicleType {
+        // NOTE fixed departure time makes waiting unavoidable for job2
+        shifts: vec![VehicleShift {
+            start: ShiftStart { latest: Some(format_time(0.)), ..shift.start.clone() },
+            ..shift
+        }],
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            tour_size: None,
+            areas: None,
+            backhaul: None,
+            min_shift_time: None,
+            min_tour_size: None,
+            max_waiting_time: Some(WaitingTimeLimits { per_stop, per_tour }),
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_test_problem(vehicle: VehicleType) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 200)], 1.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(100, 110)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+fn get_unassigned_codes(solution: &Solution) -> Vec<(String, String)> {
+    solution
+        .unassigned
+        .iter()
+        .flat_map(|unassigned| unassigned.iter())
+        .map(|job| (job.job_id.clone(), job.reasons.first().unwrap().code.clone()))
+        .collect()
+}
+
+parameterized_test! {can_limit_waiting_time, (per_stop, per_tour, expected), {
+    can_limit_waiting_time_impl(per_stop, per_tour, expected);
+}}
+
+can_limit_waiting_time! {
+    case01_no_limits_violation: (Some(100.), Some(100.), vec![]),
+    case02_per_stop_violation: (Some(50.), None, vec![("job2", "MAX_WAITING_TIME_CONSTRAINT")]),
+    case03_per_tour_violation: (None, Some(50.), vec![("job2", "MAX_WAITING_TIME_CONSTRAINT")]),
+}
+
+fn can_limit_waiting_time_impl(per_stop: Option<f64>, per_tour: Option<f64>, expected: Vec<(&str, &str)>) {
+    let problem = create_test_problem(create_vehicle_type_with_waiting_limits(per_stop, per_tour));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        get_unassigned_codes(&solution),
+        expected.into_iter().map(|(job_id, code)| (job_id.to_string(), code.to_string())).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn can_use_minimize_waiting_objective() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 10)], 1.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(100, 110)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        objectives: Some(vec![
+            vec![Objective::MinimizeUnassignedJobs { breaks: None }],
+            vec![Objective::MinimizeTours],
+            vec![Objective::MinimizeWaiting],
+            vec![Objective::MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    // NOTE departure is shifted as late as job1 time window allows, so waiting is minimal
+    assert_eq!(solution.statistic.times.waiting, 88.);
+}
@@ -0,0 +1,114 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_min_limits(
+    type_id: &str,
+    min_tour_size: Option<usize>,
+    min_shift_time: Option<f64>,
+) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{}_1", type_id)],
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            tour_size: None,
+            areas: None,
+            backhaul: None,
+            min_shift_time,
+            min_tour_size,
+            max_waiting_time: None,
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn get_unassigned_ids_with_code(solution: &Solution, code: &str) -> Vec<String> {
+    let mut ids = solution
+        .unassigned
+        .iter()
+        .flat_map(|unassigned| unassigned.iter())
+        .filter(|job| job.reasons.iter().any(|reason| reason.code == code))
+        .map(|job| job.job_id.clone())
+        .collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+#[test]
+fn can_unassign_jobs_when_min_tour_size_is_not_reached() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_min_limits("my_vehicle", Some(3), None)],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        get_unassigned_ids_with_code(&solution, "MIN_TOUR_SIZE_CONSTRAINT"),
+        vec!["job1".to_string(), "job2".to_string()]
+    );
+}
+
+#[test]
+fn can_unassign_jobs_when_min_shift_time_is_not_reached() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_min_limits("my_vehicle", None, Some(100.))],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        get_unassigned_ids_with_code(&solution, "MIN_SHIFT_TIME_CONSTRAINT"),
+        vec!["job1".to_string(), "job2".to_string()]
+    );
+}
+
+#[test]
+fn can_use_vehicle_which_reaches_min_tour_size() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_type_with_min_limits("contractor", Some(3), None),
+                create_vehicle_type_with_min_limits("own", None, None),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.tours.iter().all(|tour| tour.type_id == "own" || tour.stops.len() > 3));
+}
@@ -1,4 +1,8 @@
 mod area;
+mod backhaul;
+mod fleet_limits;
 mod max_distance;
+mod max_waiting_time;
+mod min_limits;
 mod shift_time;
 mod tour_size;
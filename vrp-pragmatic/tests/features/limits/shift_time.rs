@@ -4,7 +4,16 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_shift_time_limit(shift_time: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, shift_time: Some(shift_time), tour_size: None, areas: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: Some(shift_time),
+            tour_size: None,
+            areas: None,
+            backhaul: None,
+            min_shift_time: None,
+            min_tour_size: None,
+            max_waiting_time: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -22,6 +31,10 @@ fn can_limit_one_job_by_shift_time() {
         travel_times: vec![1, 100, 100, 1],
         distances: vec![1, 1, 1, 1],
         error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -69,9 +82,9 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
         Solution {
             statistic: Statistic {
                 cost: 52.,
-                distance: 6,
-                duration: 36,
-                times: Timing { driving: 6, serving: 30, ..Timing::default() },
+                distance: 6.,
+                duration: 36.,
+                times: Timing { driving: 6., serving: 30., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -121,9 +134,9 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 ],
                 statistic: Statistic {
                     cost: 52.,
-                    distance: 6,
-                    duration: 36,
-                    times: Timing { driving: 6, serving: 30, ..Timing::default() },
+                    distance: 6.,
+                    duration: 36.,
+                    times: Timing { driving: 6., serving: 30., ..Timing::default() },
                 },
             }],
             unassigned: Some(vec![
@@ -16,7 +16,16 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                limits: Some(VehicleLimits { max_distance: None, shift_time: None, areas: None, tour_size: Some(2) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    areas: None,
+                    tour_size: Some(2),
+                    backhaul: None,
+                    min_shift_time: None,
+                    min_tour_size: None,
+                    max_waiting_time: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -32,9 +41,9 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
         Solution {
             statistic: Statistic {
                 cost: 16.,
-                distance: 2,
-                duration: 4,
-                times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                distance: 2.,
+                duration: 4.,
+                times: Timing { driving: 2., serving: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -68,9 +77,9 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                 ],
                 statistic: Statistic {
                     cost: 16.,
-                    distance: 2,
-                    duration: 4,
-                    times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                    distance: 2.,
+                    duration: 4.,
+                    times: Timing { driving: 2., serving: 2., ..Timing::default() },
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
@@ -10,11 +10,13 @@ mod format;
 mod group;
 mod limits;
 mod multjob;
+mod periodic;
 mod pickdev;
 mod priorities;
 mod relations;
 mod reload;
 mod skills;
+mod stability;
 mod timing;
 mod unassigned;
 mod work_balance;
@@ -28,9 +28,9 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
         Solution {
             statistic: Statistic {
                 cost: 46.,
-                distance: 16,
-                duration: 20,
-                times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                distance: 16.,
+                duration: 20.,
+                times: Timing { driving: 16., serving: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -91,9 +91,9 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 ],
                 statistic: Statistic {
                     cost: 46.,
-                    distance: 16,
-                    duration: 20,
-                    times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                    distance: 16.,
+                    duration: 20.,
+                    times: Timing { driving: 16., serving: 4., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -124,9 +124,9 @@ fn can_assign_multi_job_in_pickup_effective_way() {
         Solution {
             statistic: Statistic {
                 cost: 37.,
-                distance: 12,
-                duration: 15,
-                times: Timing { driving: 12, serving: 3, ..Timing::default() },
+                distance: 12.,
+                duration: 15.,
+                times: Timing { driving: 12., serving: 3., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -179,9 +179,9 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 ],
                 statistic: Statistic {
                     cost: 37.,
-                    distance: 12,
-                    duration: 15,
-                    times: Timing { driving: 12, serving: 3, ..Timing::default() },
+                    distance: 12.,
+                    duration: 15.,
+                    times: Timing { driving: 12., serving: 3., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
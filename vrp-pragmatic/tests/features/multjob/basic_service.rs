@@ -35,9 +35,9 @@ fn can_assign_service_job() {
         Solution {
             statistic: Statistic {
                 cost: 21.,
-                distance: 4,
-                duration: 7,
-                times: Timing { driving: 4, serving: 3, ..Timing::default() },
+                distance: 4.,
+                duration: 7.,
+                times: Timing { driving: 4., serving: 3., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -87,9 +87,9 @@ fn can_assign_service_job() {
                 ],
                 statistic: Statistic {
                     cost: 21.,
-                    distance: 4,
-                    duration: 7,
-                    times: Timing { driving: 4, serving: 3, ..Timing::default() },
+                    distance: 4.,
+                    duration: 7.,
+                    times: Timing { driving: 4., serving: 3., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -31,9 +31,9 @@ fn can_handle_limited_capacity() {
         solution.statistic,
         Statistic {
             cost: 88.,
-            distance: 36,
-            duration: 42,
-            times: Timing { driving: 36, serving: 6, ..Timing::default() },
+            distance: 36.,
+            duration: 42.,
+            times: Timing { driving: 36., serving: 6., ..Timing::default() },
         }
     );
     assert!(solution.unassigned.is_none());
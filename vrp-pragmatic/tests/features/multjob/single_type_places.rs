@@ -28,9 +28,9 @@ fn can_use_only_deliveries_as_static_demand() {
         Solution {
             statistic: Statistic {
                 cost: 29.,
-                distance: 8,
-                duration: 11,
-                times: Timing { driving: 8, serving: 3, ..Timing::default() },
+                distance: 8.,
+                duration: 11.,
+                times: Timing { driving: 8., serving: 3., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -66,9 +66,9 @@ fn can_use_only_deliveries_as_static_demand() {
                 ],
                 statistic: Statistic {
                     cost: 29.,
-                    distance: 8,
-                    duration: 11,
-                    times: Timing { driving: 8, serving: 3, ..Timing::default() },
+                    distance: 8.,
+                    duration: 11.,
+                    times: Timing { driving: 8., serving: 3., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -104,9 +104,9 @@ fn can_use_only_pickups_as_static_demand() {
         Solution {
             statistic: Statistic {
                 cost: 33.,
-                distance: 10,
-                duration: 13,
-                times: Timing { driving: 10, serving: 3, ..Timing::default() },
+                distance: 10.,
+                duration: 13.,
+                times: Timing { driving: 10., serving: 3., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -150,9 +150,9 @@ fn can_use_only_pickups_as_static_demand() {
                 ],
                 statistic: Statistic {
                     cost: 33.,
-                    distance: 10,
-                    duration: 13,
-                    times: Timing { driving: 10, serving: 3, ..Timing::default() },
+                    distance: 10.,
+                    duration: 13.,
+                    times: Timing { driving: 10., serving: 3., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
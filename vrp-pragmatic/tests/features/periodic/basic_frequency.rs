@@ -0,0 +1,139 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_day_shift(day: f64) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart { earliest: format_time(day * 86400.), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(day * 86400. + 1000.), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    }
+}
+
+fn create_problem(jobs: Vec<Job>, days: usize) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: (0..days).map(|day| create_day_shift(day as f64)).collect(),
+                ..create_default_vehicle("v1")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_periodic_job(id: &str, location: (f64, f64), frequency: JobFrequency) -> Job {
+    Job { frequency: Some(frequency), ..create_delivery_job(id, location) }
+}
+
+fn get_visit_shifts(solution: &Solution, job_id: &str) -> Vec<usize> {
+    let mut shifts = solution
+        .tours
+        .iter()
+        .filter(|tour| {
+            tour.stops.iter().flat_map(|stop| stop.activities().iter()).any(|activity| activity.job_id == job_id)
+        })
+        .map(|tour| tour.shift_index)
+        .collect::<Vec<_>>();
+    shifts.sort_unstable();
+
+    shifts
+}
+
+#[test]
+fn can_visit_periodic_job_on_different_days() {
+    let problem = create_problem(
+        vec![
+            create_periodic_job("job1", (10., 0.), JobFrequency { visits: 2, min_gap_days: None, allowed_days: None }),
+            create_delivery_job("job2", (20., 0.)),
+        ],
+        3,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let shifts = get_visit_shifts(&solution, "job1");
+    assert_eq!(shifts.len(), 2);
+    assert_ne!(shifts[0], shifts[1]);
+    assert_eq!(get_visit_shifts(&solution, "job2").len(), 1);
+}
+
+#[test]
+fn can_respect_allowed_days_and_min_gap() {
+    let problem = create_problem(
+        vec![create_periodic_job(
+            "job1",
+            (10., 0.),
+            JobFrequency { visits: 2, min_gap_days: Some(2), allowed_days: Some(vec![0, 1, 3]) },
+        )],
+        4,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let shifts = get_visit_shifts(&solution, "job1");
+    assert!(shifts == vec![0, 3] || shifts == vec![1, 3], "unexpected shifts: {:?}", shifts);
+}
+
+#[test]
+fn can_unassign_all_visits_when_frequency_cannot_be_satisfied() {
+    let problem = create_problem(
+        vec![
+            create_periodic_job("job1", (10., 0.), JobFrequency { visits: 3, min_gap_days: None, allowed_days: None }),
+            create_delivery_job("job2", (20., 0.)),
+        ],
+        2,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(get_visit_shifts(&solution, "job1").is_empty());
+    assert_eq!(get_visit_shifts(&solution, "job2").len(), 1);
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+}
+
+#[test]
+fn can_count_days_in_time_offset_of_shifts() {
+    // NOTE both shifts are on the same local day, but on different days in UTC
+    let create_shift = |start: &str, end: &str| VehicleShift {
+        start: ShiftStart { earliest: start.to_string(), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: end.to_string(), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![
+                    create_shift("2020-07-04T01:00:00+02:00", "2020-07-04T02:00:00+02:00"),
+                    create_shift("2020-07-04T10:00:00+02:00", "2020-07-04T11:00:00+02:00"),
+                ],
+                ..create_default_vehicle("v1")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_problem(
+            vec![create_periodic_job(
+                "job1",
+                (10., 0.),
+                JobFrequency { visits: 2, min_gap_days: Some(1), allowed_days: None },
+            )],
+            0,
+        )
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(get_visit_shifts(&solution, "job1").is_empty());
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
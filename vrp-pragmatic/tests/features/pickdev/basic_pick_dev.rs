@@ -18,9 +18,9 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
         Solution {
             statistic: Statistic {
                 cost: 20.,
-                distance: 4,
-                duration: 6,
-                times: Timing { driving: 4, serving: 2, ..Timing::default() },
+                distance: 4.,
+                duration: 6.,
+                times: Timing { driving: 4., serving: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -64,9 +64,9 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                 ],
                 statistic: Statistic {
                     cost: 20.,
-                    distance: 4,
-                    duration: 6,
-                    times: Timing { driving: 4, serving: 2, ..Timing::default() },
+                    distance: 4.,
+                    duration: 6.,
+                    times: Timing { driving: 4., serving: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -25,9 +25,9 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
         Solution {
             statistic: Statistic {
                 cost: 30.,
-                distance: 8,
-                duration: 12,
-                times: Timing { driving: 8, serving: 4, ..Timing::default() },
+                distance: 8.,
+                duration: 12.,
+                times: Timing { driving: 8., serving: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -87,9 +87,9 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 ],
                 statistic: Statistic {
                     cost: 30.,
-                    distance: 8,
-                    duration: 12,
-                    times: Timing { driving: 8, serving: 4, ..Timing::default() },
+                    distance: 8.,
+                    duration: 12.,
+                    times: Timing { driving: 8., serving: 4., ..Timing::default() },
                 }
             }],
             ..create_empty_solution()
@@ -0,0 +1,110 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_onboard_vehicle(id: &str, capacity: i32, onboard: Vec<&str>) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            onboard: Some(onboard.into_iter().map(|id| id.to_string()).collect()),
+            ..create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))
+        }],
+        ..create_vehicle_with_capacity(id, vec![capacity])
+    }
+}
+
+fn get_tour_activities(solution: &Solution, vehicle_id: &str) -> Vec<(String, String)> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| (activity.job_id.clone(), activity.activity_type.clone()))
+        .collect()
+}
+
+#[test]
+fn can_plan_only_delivery_of_onboard_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (0., 0.), (15., 0.)),
+                create_pickup_delivery_job("job2", (11., 0.), (12., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_onboard_vehicle("v1", 2, vec!["job1"])], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops[0].load(), &vec![1]);
+    let activities = get_tour_activities(&solution, "v1_1");
+    assert!(!activities.contains(&("job1".to_string(), "pickup".to_string())));
+    assert!(activities.contains(&("job1".to_string(), "delivery".to_string())));
+    assert!(activities.contains(&("job2".to_string(), "pickup".to_string())));
+}
+
+#[test]
+fn can_respect_onboard_load_and_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (0., 0.), (11., 0.)),
+                create_pickup_delivery_job("job2", (0., 0.), (12., 0.)),
+                create_delivery_job("job3", (13., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_onboard_vehicle("v1", 2, vec!["job1", "job2"]), create_default_vehicle("v2")],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_tour_activities(&solution, "v1_1")
+            .into_iter()
+            .filter(|(job_id, _)| job_id.starts_with("job"))
+            .map(|(job_id, _)| job_id)
+            .collect::<Vec<_>>()
+            .len(),
+        2
+    );
+    assert!(get_tour_activities(&solution, "v2_1").contains(&("job3".to_string(), "delivery".to_string())));
+}
+
+#[test]
+fn can_keep_onboard_load_when_delivery_is_unassigned() {
+    let mut job1 = create_pickup_delivery_job("job1", (0., 0.), (11., 0.));
+    job1.deliveries.as_mut().unwrap().first_mut().unwrap().places.first_mut().unwrap().times =
+        Some(vec![vec![format_time(2000.), format_time(2010.)]]);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![job1, create_pickup_job("job2", (12., 0.)), create_pickup_job("job3", (13., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_onboard_vehicle("v1", 2, vec!["job1"])], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(unassigned.len(), 2);
+    assert!(unassigned.contains(&"job1"));
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops.first().unwrap().load(), &vec![1]);
+    assert_eq!(solution.tours[0].stops.last().unwrap().load(), &vec![1]);
+}
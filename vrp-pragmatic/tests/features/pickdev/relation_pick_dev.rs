@@ -36,9 +36,9 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
         Solution {
             statistic: Statistic {
                 cost: 114.,
-                distance: 50,
-                duration: 54,
-                times: Timing { driving: 50, serving: 4, ..Timing::default() },
+                distance: 50.,
+                duration: 54.,
+                times: Timing { driving: 50., serving: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -100,9 +100,9 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 ],
                 statistic: Statistic {
                     cost: 114.,
-                    distance: 50,
-                    duration: 54,
-                    times: Timing { driving: 50, serving: 4, ..Timing::default() },
+                    distance: 50.,
+                    duration: 54.,
+                    times: Timing { driving: 50., serving: 4., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
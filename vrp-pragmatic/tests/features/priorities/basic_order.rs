@@ -15,7 +15,16 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), shift_time: None, tour_size: None, areas: None })
+    Some(VehicleLimits {
+        max_distance: Some(15.),
+        shift_time: None,
+        tour_size: None,
+        areas: None,
+        backhaul: None,
+        min_shift_time: None,
+        min_tour_size: None,
+        max_waiting_time: None,
+    })
 }
 
 fn create_order_objective(is_constrained: bool) -> Vec<Vec<Objective>> {
@@ -52,9 +61,9 @@ fn can_follow_orders() {
         Solution {
             statistic: Statistic {
                 cost: 53.,
-                distance: 20,
-                duration: 23,
-                times: Timing { driving: 20, serving: 3, ..Timing::default() },
+                distance: 20.,
+                duration: 23.,
+                times: Timing { driving: 20., serving: 3., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -104,9 +113,9 @@ fn can_follow_orders() {
                 ],
                 statistic: Statistic {
                     cost: 53.,
-                    distance: 20,
-                    duration: 23,
-                    times: Timing { driving: 20, serving: 3, ..Timing::default() },
+                    distance: 20.,
+                    duration: 23.,
+                    times: Timing { driving: 20., serving: 3., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -123,7 +132,7 @@ fn can_assign_more_jobs_ignoring_order_when_is_not_constrained() {
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
 
     assert!(solution.unassigned.is_none());
-    assert_eq!(solution.statistic.distance, 14);
+    assert_eq!(solution.statistic.distance, 14.);
 }
 
 #[test]
@@ -134,7 +143,7 @@ fn can_follow_order_with_default_objective() {
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
 
     assert!(solution.unassigned.is_some());
-    assert_eq!(solution.statistic.distance, 10);
+    assert_eq!(solution.statistic.distance, 10.);
 }
 
 #[test]
@@ -163,7 +172,14 @@ fn can_follow_order_when_prioritized_property_set() {
 fn can_handle_order_between_special_activities() {
     let create_test_job = |id: &str, location: (f64, f64), order: i32| Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: location.to_loc(),
+                duration: 100.,
+                tag: None,
+                parking: None,
+                access: None,
+            }],
             demand: Some(vec![1]),
             order: Some(order),
         }]),
@@ -1,2 +1,4 @@
 mod basic_order;
 mod basic_value;
+mod priority_tiers;
+mod value_curve;
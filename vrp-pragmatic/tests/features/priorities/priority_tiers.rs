@@ -0,0 +1,38 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_serve_jobs_with_higher_priority_first, objectives, {
+    can_serve_jobs_with_higher_priority_first_impl(objectives);
+}}
+
+can_serve_jobs_with_higher_priority_first! {
+    case01: Some(vec![vec![MinimizeUnassignedJobsByPriority { breaks: None }], vec![MinimizeCost]]),
+    case02: None,
+}
+
+fn can_serve_jobs_with_higher_priority_first_impl(objectives: Option<Vec<Vec<Objective>>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { priority: Some(2), ..create_delivery_job("job1", (1., 0.)) },
+                Job { priority: Some(2), ..create_delivery_job("job2", (2., 0.)) },
+                Job { priority: Some(1), ..create_delivery_job_with_demand("job3", (10., 0.), vec![2]) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![2], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut unassigned = solution.unassigned.iter().flatten().map(|job| job.job_id.clone()).collect::<Vec<_>>();
+    unassigned.sort();
+    assert_eq!(unassigned, vec!["job1".to_string(), "job2".to_string()]);
+}
@@ -0,0 +1,66 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_job_with_value_curve(id: &str, location: (f64, f64), curve: Option<Vec<(f64, f64)>>) -> Job {
+    Job {
+        value: Some(10.),
+        value_curve: curve.map(|curve| {
+            curve.into_iter().map(|(time, value)| JobValuePoint { time: format_time(time), value }).collect()
+        }),
+        ..create_delivery_job(id, location)
+    }
+}
+
+parameterized_test! {can_serve_job_with_decaying_value_first, (job1_curve, job2_curve, objectives, expected), {
+    can_serve_job_with_decaying_value_first_impl(job1_curve, job2_curve, objectives, expected);
+}}
+
+can_serve_job_with_decaying_value_first! {
+    case01_first_decays: (Some(vec![(0., 100.), (20., 0.)]), None, None, vec!["job1", "job2"]),
+    case02_second_decays: (None, Some(vec![(0., 100.), (20., 0.)]), None, vec!["job2", "job1"]),
+    case03_explicit_objective: (
+        Some(vec![(0., 100.), (20., 0.)]), None,
+        Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MaximizeValue { reduction_factor: None, breaks: None }],
+            vec![MinimizeCost],
+        ]),
+        vec!["job1", "job2"]
+    ),
+}
+
+fn can_serve_job_with_decaying_value_first_impl(
+    job1_curve: Option<Vec<(f64, f64)>>,
+    job2_curve: Option<Vec<(f64, f64)>>,
+    objectives: Option<Vec<Vec<Objective>>>,
+    expected: Vec<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_value_curve("job1", (5., 0.), job1_curve),
+                create_job_with_value_curve("job2", (-5., 0.), job2_curve),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        objectives,
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids, expected);
+}
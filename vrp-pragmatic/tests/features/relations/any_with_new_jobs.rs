@@ -42,9 +42,9 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
         Solution {
             statistic: Statistic {
                 cost: 19.,
-                distance: 3,
-                duration: 6,
-                times: Timing { driving: 3, serving: 3, ..Timing::default() },
+                distance: 3.,
+                duration: 6.,
+                times: Timing { driving: 3., serving: 3., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -86,9 +86,9 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 ],
                 statistic: Statistic {
                     cost: 19.,
-                    distance: 3,
-                    duration: 6,
-                    times: Timing { driving: 3, serving: 3, ..Timing::default() },
+                    distance: 3.,
+                    duration: 6.,
+                    times: Timing { driving: 3., serving: 3., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
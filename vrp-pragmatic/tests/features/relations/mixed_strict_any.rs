@@ -43,9 +43,9 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
         Solution {
             statistic: Statistic {
                 cost: 53.,
-                distance: 18,
-                duration: 25,
-                times: Timing { driving: 18, serving: 7, ..Timing::default() },
+                distance: 18.,
+                duration: 25.,
+                times: Timing { driving: 18., serving: 7., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -127,9 +127,9 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 ],
                 statistic: Statistic {
                     cost: 53.,
-                    distance: 18,
-                    duration: 25,
-                    times: Timing { driving: 18, serving: 7, ..Timing::default() },
+                    distance: 18.,
+                    duration: 25.,
+                    times: Timing { driving: 18., serving: 7., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
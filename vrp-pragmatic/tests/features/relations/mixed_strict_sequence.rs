@@ -43,9 +43,9 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
         Solution {
             statistic: Statistic {
                 cost: 61.,
-                distance: 22,
-                duration: 29,
-                times: Timing { driving: 22, serving: 7, ..Timing::default() },
+                distance: 22.,
+                duration: 29.,
+                times: Timing { driving: 22., serving: 7., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -127,9 +127,9 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 ],
                 statistic: Statistic {
                     cost: 61.,
-                    distance: 22,
-                    duration: 29,
-                    times: Timing { driving: 22, serving: 7, ..Timing::default() },
+                    distance: 22.,
+                    duration: 29.,
+                    times: Timing { driving: 22., serving: 7., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -199,9 +199,9 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
         Solution {
             statistic: Statistic {
                 cost: 80.,
-                distance: 26,
-                duration: 34,
-                times: Timing { driving: 26, serving: 8, ..Timing::default() },
+                distance: 26.,
+                duration: 34.,
+                times: Timing { driving: 26., serving: 8., ..Timing::default() },
             },
             tours: vec![
                 Tour {
@@ -252,9 +252,9 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                     ],
                     statistic: Statistic {
                         cost: 40.,
-                        distance: 13,
-                        duration: 17,
-                        times: Timing { driving: 13, serving: 4, ..Timing::default() },
+                        distance: 13.,
+                        duration: 17.,
+                        times: Timing { driving: 13., serving: 4., ..Timing::default() },
                     },
                 },
                 Tour {
@@ -305,9 +305,9 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                     ],
                     statistic: Statistic {
                         cost: 40.,
-                        distance: 13,
-                        duration: 17,
-                        times: Timing { driving: 13, serving: 4, ..Timing::default() },
+                        distance: 13.,
+                        duration: 17.,
+                        times: Timing { driving: 13., serving: 4., ..Timing::default() },
                     },
                 }
             ],
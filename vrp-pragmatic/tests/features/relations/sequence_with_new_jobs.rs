@@ -40,9 +40,9 @@ fn can_use_sequence_relation_with_strict_time_windows() {
         Solution {
             statistic: Statistic {
                 cost: 270.,
-                distance: 100,
-                duration: 160,
-                times: Timing { driving: 100, serving: 50, waiting: 10, ..Timing::default() },
+                distance: 100.,
+                duration: 160.,
+                times: Timing { driving: 100., serving: 50., waiting: 10., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -108,9 +108,9 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                 ],
                 statistic: Statistic {
                     cost: 270.,
-                    distance: 100,
-                    duration: 160,
-                    times: Timing { driving: 100, serving: 50, waiting: 10, ..Timing::default() },
+                    distance: 100.,
+                    duration: 160.,
+                    times: Timing { driving: 100., serving: 50., waiting: 10., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -53,9 +53,9 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
         Solution {
             statistic: Statistic {
                 cost: 114.,
-                distance: 42,
-                duration: 52,
-                times: Timing { driving: 42, serving: 10, ..Timing::default() },
+                distance: 42.,
+                duration: 52.,
+                times: Timing { driving: 42., serving: 10., ..Timing::default() },
             },
             tours: vec![
                 Tour {
@@ -122,9 +122,9 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                     ],
                     statistic: Statistic {
                         cost: 59.,
-                        distance: 22,
-                        duration: 27,
-                        times: Timing { driving: 22, serving: 5, ..Timing::default() },
+                        distance: 22.,
+                        duration: 27.,
+                        times: Timing { driving: 22., serving: 5., ..Timing::default() },
                     },
                 },
                 Tour {
@@ -191,9 +191,9 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                     ],
                     statistic: Statistic {
                         cost: 55.,
-                        distance: 20,
-                        duration: 25,
-                        times: Timing { driving: 20, serving: 5, ..Timing::default() },
+                        distance: 20.,
+                        duration: 25.,
+                        times: Timing { driving: 20., serving: 5., ..Timing::default() },
                     },
                 }
             ],
@@ -51,9 +51,9 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
         Solution {
             statistic: Statistic {
                 cost: 96.,
-                distance: 34,
-                duration: 42,
-                times: Timing { driving: 34, serving: 8, ..Timing::default() },
+                distance: 34.,
+                duration: 42.,
+                times: Timing { driving: 34., serving: 8., ..Timing::default() },
             },
             tours: vec![
                 Tour {
@@ -112,9 +112,9 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                     ],
                     statistic: Statistic {
                         cost: 54.,
-                        distance: 20,
-                        duration: 24,
-                        times: Timing { driving: 20, serving: 4, ..Timing::default() },
+                        distance: 20.,
+                        duration: 24.,
+                        times: Timing { driving: 20., serving: 4., ..Timing::default() },
                     },
                 },
                 Tour {
@@ -173,9 +173,9 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                     ],
                     statistic: Statistic {
                         cost: 42.,
-                        distance: 14,
-                        duration: 18,
-                        times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                        distance: 14.,
+                        duration: 18.,
+                        times: Timing { driving: 14., serving: 4., ..Timing::default() },
                     },
                 }
             ],
@@ -37,6 +37,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    onboard: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -54,9 +55,9 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
         Solution {
             statistic: Statistic {
                 cost: 46.,
-                distance: 16,
-                duration: 20,
-                times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                distance: 16.,
+                duration: 20.,
+                times: Timing { driving: 16., serving: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -117,9 +118,9 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                 ],
                 statistic: Statistic {
                     cost: 46.,
-                    distance: 16,
-                    duration: 20,
-                    times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                    distance: 16.,
+                    duration: 20.,
+                    times: Timing { driving: 16., serving: 4., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -44,6 +44,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    onboard: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -61,9 +62,9 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
         Solution {
             statistic: Statistic {
                 cost: 26.,
-                distance: 6,
-                duration: 10,
-                times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                distance: 6.,
+                duration: 10.,
+                times: Timing { driving: 6., serving: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -113,9 +114,9 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 ],
                 statistic: Statistic {
                     cost: 26.,
-                    distance: 6,
-                    duration: 10,
-                    times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                    distance: 6.,
+                    duration: 10.,
+                    times: Timing { driving: 6., serving: 4., ..Timing::default() },
                 },
             }],
             unassigned,
@@ -41,9 +41,9 @@ fn can_use_multi_dim_capacity() {
         Solution {
             statistic: Statistic {
                 cost: 26.,
-                distance: 6,
-                duration: 10,
-                times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                distance: 6.,
+                duration: 10.,
+                times: Timing { driving: 6., serving: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -93,9 +93,9 @@ fn can_use_multi_dim_capacity() {
                 ],
                 statistic: Statistic {
                     cost: 26.,
-                    distance: 6,
-                    duration: 10,
-                    times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                    distance: 6.,
+                    duration: 10.,
+                    times: Timing { driving: 6., serving: 4., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
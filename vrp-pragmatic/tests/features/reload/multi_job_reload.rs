@@ -32,6 +32,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    onboard: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -49,9 +50,9 @@ fn can_serve_multi_job_and_delivery_with_reload() {
         Solution {
             statistic: Statistic {
                 cost: 46.,
-                distance: 14,
-                duration: 22,
-                times: Timing { driving: 14, serving: 8, ..Timing::default() },
+                distance: 14.,
+                duration: 22.,
+                times: Timing { driving: 14., serving: 8., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -136,9 +137,9 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 ],
                 statistic: Statistic {
                     cost: 46.,
-                    distance: 14,
-                    duration: 22,
-                    times: Timing { driving: 14, serving: 8, ..Timing::default() },
+                    distance: 14.,
+                    duration: 22.,
+                    times: Timing { driving: 14., serving: 8., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
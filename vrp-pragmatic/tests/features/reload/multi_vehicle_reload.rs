@@ -23,6 +23,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    onboard: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -40,9 +41,9 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
         Solution {
             statistic: Statistic {
                 cost: 26.,
-                distance: 6,
-                duration: 10,
-                times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                distance: 6.,
+                duration: 10.,
+                times: Timing { driving: 6., serving: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -92,9 +93,9 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 ],
                 statistic: Statistic {
                     cost: 26.,
-                    distance: 6,
-                    duration: 10,
-                    times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                    distance: 6.,
+                    duration: 10.,
+                    times: Timing { driving: 6., serving: 4., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -28,6 +28,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    onboard: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -106,9 +107,9 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
             ],
             statistic: Statistic {
                 cost: 28.,
-                distance: 6,
-                duration: 12,
-                times: Timing { driving: 6, serving: 6, ..Timing::default() },
+                distance: 6.,
+                duration: 12.,
+                times: Timing { driving: 6., serving: 6., ..Timing::default() },
             },
         }]
     );
@@ -116,9 +117,9 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
         solution.statistic,
         Statistic {
             cost: 28.,
-            distance: 6,
-            duration: 12,
-            times: Timing { driving: 6, serving: 6, ..Timing::default() },
+            distance: 6.,
+            duration: 12.,
+            times: Timing { driving: 6., serving: 6., ..Timing::default() },
         }
     );
     assert!(solution.violations.is_none());
@@ -37,9 +37,9 @@ fn can_wait_for_job_start() {
         Solution {
             statistic: Statistic {
                 cost: 47.,
-                distance: 18,
-                duration: 19,
-                times: Timing { driving: 18, serving: 1, ..Timing::default() },
+                distance: 18.,
+                duration: 19.,
+                times: Timing { driving: 18., serving: 1., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_with_skill_1".to_string(),
@@ -73,9 +73,9 @@ fn can_wait_for_job_start() {
                 ],
                 statistic: Statistic {
                     cost: 47.,
-                    distance: 18,
-                    duration: 19,
-                    times: Timing { driving: 18, serving: 1, ..Timing::default() },
+                    distance: 18.,
+                    duration: 19.,
+                    times: Timing { driving: 18., serving: 1., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,71 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_day_shift(day: f64) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart { earliest: format_time(day * 1000.), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(day * 1000. + 100.), location: (0., 0.).to_loc() }),
+        ..create_default_vehicle_shift()
+    }
+}
+
+fn create_customer_job(id: &str, customer: &str, location: (f64, f64), day: i32) -> Job {
+    Job {
+        consistency_key: Some(customer.to_string()),
+        ..create_delivery_job_with_times(id, location, vec![(day * 1000, day * 1000 + 100)], 1.)
+    }
+}
+
+fn get_vehicle_ids(solution: &Solution, job_ids: &[&str]) -> Vec<String> {
+    let mut vehicle_ids = solution
+        .tours
+        .iter()
+        .filter(|tour| {
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .any(|activity| job_ids.contains(&activity.job_id.as_str()))
+        })
+        .map(|tour| tour.vehicle_id.clone())
+        .collect::<Vec<_>>();
+    vehicle_ids.dedup();
+
+    vehicle_ids
+}
+
+#[test]
+fn can_serve_jobs_with_the_same_consistency_key_by_the_same_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_customer_job("a1", "a", (10., 0.), 0),
+                create_customer_job("b1", "b", (20., 0.), 0),
+                create_customer_job("a2", "a", (10., 0.), 1),
+                create_customer_job("b2", "b", (20., 0.), 1),
+                create_customer_job("a3", "a", (10., 0.), 2),
+                create_customer_job("b3", "b", (20., 0.), 2),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                shifts: vec![create_day_shift(0.), create_day_shift(1.), create_day_shift(2.)],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 6);
+    assert_eq!(get_vehicle_ids(&solution, &["a1", "a2", "a3"]).len(), 1);
+    assert_eq!(get_vehicle_ids(&solution, &["b1", "b2", "b3"]).len(), 1);
+}
@@ -0,0 +1,106 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_reference(reference: Vec<(&str, Vec<&str>)>) -> Solution {
+    Solution {
+        tours: reference
+            .into_iter()
+            .map(|(vehicle_id, jobs)| Tour {
+                vehicle_id: vehicle_id.to_string(),
+                stops: jobs
+                    .into_iter()
+                    .map(|job_id| {
+                        create_stop_with_activity(
+                            job_id,
+                            "delivery",
+                            (0., 0.),
+                            0,
+                            ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                            0,
+                        )
+                    })
+                    .collect(),
+                ..create_empty_tour()
+            })
+            .collect(),
+        ..create_empty_solution()
+    }
+}
+
+fn create_objectives(is_sequence_aware: bool) -> Option<Vec<Vec<Objective>>> {
+    Some(vec![
+        vec![Objective::MinimizeUnassignedJobs { breaks: None }],
+        vec![Objective::MinimizePlanChanges { is_sequence_aware: Some(is_sequence_aware) }],
+        vec![Objective::MinimizeCost],
+    ])
+}
+
+fn get_tour_job_ids(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.clone())
+        .filter(|job_id| job_id != "departure" && job_id != "arrival")
+        .collect()
+}
+
+#[test]
+fn can_keep_jobs_assigned_to_reference_vehicles() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (90., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((0., 0.), (0., 0.))],
+                    ..create_default_vehicle("v1")
+                },
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((100., 0.), (100., 0.))],
+                    ..create_default_vehicle("v2")
+                },
+            ],
+            ..create_default_fleet()
+        },
+        objectives: create_objectives(false),
+        ..create_empty_problem()
+    };
+    let reference = create_reference(vec![("v1_1", vec!["job2"]), ("v2_1", vec!["job1"])]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_reference(problem, Some(vec![matrix]), &reference);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_tour_job_ids(&solution, "v1_1"), vec!["job2"]);
+    assert_eq!(get_tour_job_ids(&solution, "v2_1"), vec!["job1"]);
+}
+
+#[test]
+fn can_keep_job_sequence_of_reference_tour() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("v1")], ..create_default_fleet() },
+        objectives: create_objectives(true),
+        ..create_empty_problem()
+    };
+    let reference = create_reference(vec![("v1_1", vec!["departure", "job3", "job1", "job2", "arrival"])]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_reference(problem, Some(vec![matrix]), &reference);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_tour_job_ids(&solution, "v1_1"), vec!["job3", "job1", "job2"]);
+}
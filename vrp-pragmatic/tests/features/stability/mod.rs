@@ -0,0 +1,2 @@
+mod basic_consistency;
+mod basic_plan_changes;
@@ -27,9 +27,9 @@ fn can_use_multiple_times() {
         Solution {
             statistic: Statistic {
                 cost: 240.,
-                distance: 100,
-                duration: 130,
-                times: Timing { driving: 100, serving: 0, waiting: 30, ..Timing::default() },
+                distance: 100.,
+                duration: 130.,
+                times: Timing { driving: 100., serving: 0., waiting: 30., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -95,9 +95,9 @@ fn can_use_multiple_times() {
                 ],
                 statistic: Statistic {
                     cost: 240.,
-                    distance: 100,
-                    duration: 130,
-                    times: Timing { driving: 100, serving: 0, waiting: 30, ..Timing::default() },
+                    distance: 100.,
+                    duration: 130.,
+                    times: Timing { driving: 100., serving: 0., waiting: 30., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -25,9 +25,9 @@ fn can_wait_for_job_start() {
         Solution {
             statistic: Statistic {
                 cost: 26.,
-                distance: 4,
-                duration: 12,
-                times: Timing { driving: 4, serving: 0, waiting: 8, ..Timing::default() },
+                distance: 4.,
+                duration: 12.,
+                times: Timing { driving: 4., serving: 0., waiting: 8., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -69,9 +69,9 @@ fn can_wait_for_job_start() {
                 ],
                 statistic: Statistic {
                     cost: 26.,
-                    distance: 4,
-                    duration: 12,
-                    times: Timing { driving: 4, serving: 0, waiting: 8, ..Timing::default() },
+                    distance: 4.,
+                    duration: 12.,
+                    times: Timing { driving: 4., serving: 0., waiting: 8., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -98,9 +98,9 @@ fn can_skip_initial_waiting() {
         Solution {
             statistic: Statistic {
                 cost: 24.,
-                distance: 2,
-                duration: 12,
-                times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                distance: 2.,
+                duration: 12.,
+                times: Timing { driving: 2., serving: 10., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -134,9 +134,9 @@ fn can_skip_initial_waiting() {
                 ],
                 statistic: Statistic {
                     cost: 24.,
-                    distance: 2,
-                    duration: 12,
-                    times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                    distance: 2.,
+                    duration: 12.,
+                    times: Timing { driving: 2., serving: 10., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -176,9 +176,9 @@ fn can_consider_latest_departure_time() {
         Solution {
             statistic: Statistic {
                 cost: 28.,
-                distance: 2,
-                duration: 16,
-                times: Timing { driving: 2, serving: 10, waiting: 4, ..Timing::default() },
+                distance: 2.,
+                duration: 16.,
+                times: Timing { driving: 2., serving: 10., waiting: 4., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -212,9 +212,9 @@ fn can_consider_latest_departure_time() {
                 ],
                 statistic: Statistic {
                     cost: 28.,
-                    distance: 2,
-                    duration: 16,
-                    times: Timing { driving: 2, serving: 10, waiting: 4, ..Timing::default() },
+                    distance: 2.,
+                    duration: 16.,
+                    times: Timing { driving: 2., serving: 10., waiting: 4., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
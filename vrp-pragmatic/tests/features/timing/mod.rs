@@ -1,4 +1,6 @@
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod stop_parking;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
+mod travel_buffer;
@@ -0,0 +1,85 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_job_with_parking(id: &str, location: (f64, f64), parking: Option<f64>) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace { parking, ..create_job_place(location, None) }],
+            demand: Some(vec![1]),
+            order: None,
+        }]),
+        ..create_job(id)
+    }
+}
+
+fn get_stop_parking(solution: &Solution) -> Vec<(Vec<String>, Option<Interval>)> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .filter_map(|stop| stop.as_point())
+        .map(|stop| (stop.activities.iter().map(|a| a.job_id.clone()).collect(), stop.parking.clone()))
+        .collect()
+}
+
+parameterized_test! {can_apply_parking_once_per_stop, (job_parking, vehicle_parking, expected_parking), {
+    can_apply_parking_once_per_stop_impl(job_parking, vehicle_parking, expected_parking);
+}}
+
+can_apply_parking_once_per_stop! {
+    case01_vehicle_parking: (None, Some(5.), 10),
+    case02_job_parking: (Some(3.), None, 6),
+    case03_job_parking_overrides_vehicle: (Some(3.), Some(5.), 6),
+    case04_no_parking: (None, None, 0),
+}
+
+fn can_apply_parking_once_per_stop_impl(job_parking: Option<f64>, vehicle_parking: Option<f64>, expected_parking: i64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_parking("job1", (10., 0.), job_parking),
+                create_job_with_parking("job2", (10., 0.), job_parking),
+                create_job_with_parking("job3", (20., 0.), job_parking),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                parking: vehicle_parking,
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+            resources: None,
+            limits: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.times.parking, expected_parking as f64);
+    assert_eq!(solution.statistic.duration, (20 + 3 + expected_parking) as f64);
+
+    let stops = get_stop_parking(&solution);
+    assert_eq!(stops.len(), 3);
+    assert_eq!(stops[1].0.len(), 2);
+    assert_eq!(stops[2].0, vec!["job3".to_string()]);
+    if expected_parking > 0 {
+        let parking = expected_parking / 2;
+        assert_eq!(stops[1].1, Some(Interval { start: format_time(10.), end: format_time((10 + parking) as f64) }));
+        assert_eq!(
+            stops[2].1,
+            Some(Interval {
+                start: format_time((20 + parking + 2) as f64),
+                end: format_time((20 + 2 * parking + 2) as f64)
+            })
+        );
+    } else {
+        assert!(stops.iter().all(|(_, parking)| parking.is_none()));
+    }
+}
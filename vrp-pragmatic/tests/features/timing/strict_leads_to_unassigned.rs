@@ -27,9 +27,9 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
         Solution {
             statistic: Statistic {
                 cost: 170.,
-                distance: 80,
-                duration: 80,
-                times: Timing { driving: 80, serving: 0, ..Timing::default() },
+                distance: 80.,
+                duration: 80.,
+                times: Timing { driving: 80., serving: 0., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -87,9 +87,9 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 ],
                 statistic: Statistic {
                     cost: 170.,
-                    distance: 80,
-                    duration: 80,
-                    times: Timing { driving: 80, serving: 0, ..Timing::default() },
+                    distance: 80.,
+                    duration: 80.,
+                    times: Timing { driving: 80., serving: 0., ..Timing::default() },
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
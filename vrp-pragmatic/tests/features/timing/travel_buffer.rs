@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_buffer(buffer: TravelBuffer) -> VehicleType {
+    let shift = create_default_vehicle_shift();
+
+    VehicleType {
+        shifts: vec![VehicleShift {
+            start: ShiftStart { latest: Some(format_time(0.)), ..shift.start.clone() },
+            ..shift
+        }],
+        profile: VehicleProfile { buffer: Some(buffer), ..create_default_vehicle_profile() },
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_test_problem(job: Job, buffer: TravelBuffer) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_vehicle_type_with_buffer(buffer)], ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_add_travel_buffer_to_schedule() {
+    let problem = create_test_problem(
+        create_delivery_job("job1", (10., 0.)),
+        TravelBuffer { percentage: Some(10.), fixed: Some(5.), slack: None },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let stops = &solution.tours[0].stops;
+    assert_eq!(stops[1].schedule().arrival, format_time(16.));
+    assert_eq!(stops[2].schedule().arrival, format_time(33.));
+    assert_eq!(solution.statistic.duration, 33.);
+    assert_eq!(solution.statistic.times, Timing { driving: 20., serving: 1., buffer: 12., ..Timing::default() });
+}
+
+parameterized_test! {can_require_slack_before_time_window_end, (slack, expected), {
+    can_require_slack_before_time_window_end_impl(slack, expected);
+}}
+
+can_require_slack_before_time_window_end! {
+    case01_enough_slack: (Some(2.), vec![]),
+    case02_not_enough_slack: (Some(5.), vec![("job1", "TIME_WINDOW_CONSTRAINT")]),
+}
+
+fn can_require_slack_before_time_window_end_impl(slack: Option<f64>, expected: Vec<(&str, &str)>) {
+    let problem = create_test_problem(
+        create_delivery_job_with_times("job1", (10., 0.), vec![(0, 12)], 1.),
+        TravelBuffer { percentage: None, fixed: None, slack },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution
+            .unassigned
+            .iter()
+            .flat_map(|unassigned| unassigned.iter())
+            .map(|job| (job.job_id.clone(), job.reasons.first().unwrap().code.clone()))
+            .collect::<Vec<_>>(),
+        expected.into_iter().map(|(job_id, code)| (job_id.to_string(), code.to_string())).collect::<Vec<_>>()
+    );
+}
@@ -35,6 +35,6 @@ fn can_balance_duration() {
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
 
     assert_eq!(solution.tours.len(), 2);
-    assert!(solution.tours.first().unwrap().statistic.duration < 30);
-    assert!(solution.tours.last().unwrap().statistic.duration < 30);
+    assert!(solution.tours.first().unwrap().statistic.duration < 30.);
+    assert!(solution.tours.last().unwrap().statistic.duration < 30.);
 }
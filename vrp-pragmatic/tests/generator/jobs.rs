@@ -90,7 +90,11 @@ prop_compose! {
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            consistency_key: None,
+            frequency: None,
+            priority: None,
+            value_curve: None,
         }
     }
 }
@@ -137,6 +141,10 @@ prop_compose! {
             value,
             group,
             compatibility,
+            consistency_key: None,
+            frequency: None,
+            priority: None,
+            value_curve: None,
         }
     }
 }
@@ -169,7 +177,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, parking: None, access: None }
     }
 }
 
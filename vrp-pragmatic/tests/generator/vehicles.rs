@@ -33,6 +33,10 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            parking: None,
+            tags: None,
+            attributes: None,
+            is_unlimited: None,
         }
     }
 }
@@ -86,7 +90,8 @@ prop_compose! {
           end: places.1,
           dispatch,
           breaks,
-          reloads
+          reloads,
+            onboard: None,
         }
     }
 }
@@ -107,7 +112,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None }
+        Fleet { vehicles, profiles, resources: None, limits: None }
     }
 }
 
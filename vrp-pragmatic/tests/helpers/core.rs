@@ -2,7 +2,7 @@ use crate::extensions::{create_typed_actor_groups, JobTie, VehicleTie};
 use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintPipeline;
 use vrp_core::construction::heuristics::InsertionContext;
-use vrp_core::construction::heuristics::{RegistryContext, SolutionContext};
+use vrp_core::construction::heuristics::{RegistryContext, RouteContext, RouteState, SolutionContext};
 use vrp_core::models::common::*;
 use vrp_core::models::examples::create_example_problem;
 use vrp_core::models::problem::*;
@@ -59,6 +59,13 @@ pub fn create_route_with_activities(fleet: &Fleet, vehicle: &str, activities: Ve
     Route { actor, tour }
 }
 
+pub fn create_route_context_with_activities(fleet: &Fleet, vehicle: &str, activities: Vec<Activity>) -> RouteContext {
+    RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(fleet, vehicle, activities)),
+        Arc::new(RouteState::default()),
+    )
+}
+
 pub fn create_activity_at_location(location: Location) -> Activity {
     Activity {
         place: vrp_core::models::solution::Place {
@@ -126,6 +133,23 @@ pub fn create_solution_context_for_fleet(fleet: &Fleet) -> SolutionContext {
     }
 }
 
+pub fn create_solution_context_with_routes(fleet: &Fleet, routes: Vec<(&str, Vec<Arc<Single>>)>) -> SolutionContext {
+    SolutionContext {
+        routes: routes
+            .into_iter()
+            .map(|(vehicle, jobs)| {
+                let activities = jobs
+                    .into_iter()
+                    .map(|job| create_activity_with_job_at_location(job, DEFAULT_JOB_LOCATION))
+                    .collect();
+
+                create_route_context_with_activities(fleet, vehicle, activities)
+            })
+            .collect(),
+        ..create_solution_context_for_fleet(fleet)
+    }
+}
+
 pub fn create_empty_insertion_context() -> InsertionContext {
     let problem = create_example_problem();
     InsertionContext {
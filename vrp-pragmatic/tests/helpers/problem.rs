@@ -5,7 +5,7 @@ use crate::format_time;
 use crate::helpers::ToLocation;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, parking: None, access: None }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
@@ -23,6 +23,10 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        consistency_key: None,
+        frequency: None,
+        priority: None,
+        value_curve: None,
     }
 }
 
@@ -153,10 +157,29 @@ pub fn create_pickup_delivery_job_with_params(
     }
 }
 
+pub fn create_delivery_job_with_point(id: &str, point: (f64, f64)) -> Job {
+    let (x, y) = point;
+
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace { location: Location::new_point(x, y), ..create_job_place((0., 0.), None) }],
+            ..create_task((0., 0.), None)
+        }]),
+        ..create_job(id)
+    }
+}
+
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                parking: None,
+                access: None,
+            }],
             demand: Some(vec![1]),
             order: None,
         }]),
@@ -208,6 +231,7 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
         dispatch: None,
         breaks: None,
         reloads: None,
+        onboard: None,
     }
 }
 
@@ -218,6 +242,7 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
         dispatch: None,
         breaks: None,
         reloads: None,
+        onboard: None,
     }
 }
 
@@ -226,11 +251,11 @@ pub fn create_default_vehicle_costs() -> VehicleCosts {
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
-    VehicleProfile { matrix: "car".to_string(), scale: None }
+    VehicleProfile { matrix: "car".to_string(), scale: None, buffer: None }
 }
 
 pub fn create_vehicle_profile_with_name(name: &str) -> VehicleProfile {
-    VehicleProfile { matrix: name.to_string(), scale: None }
+    VehicleProfile { matrix: name.to_string(), scale: None, buffer: None }
 }
 
 pub fn create_default_vehicle_type() -> VehicleType {
@@ -251,15 +276,24 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        parking: None,
+        tags: None,
+        attributes: None,
+        is_unlimited: None,
     }
 }
 
 pub fn create_default_fleet() -> Fleet {
-    Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles(), resources: None }
+    Fleet {
+        vehicles: vec![create_default_vehicle_type()],
+        profiles: create_default_matrix_profiles(),
+        resources: None,
+        limits: None,
+    }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile { name: "car".to_string(), speed: None, speed_curve: None, circuity: None, metric: None }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
@@ -267,14 +301,15 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, areas: None, clustering: None }
+    Plan { jobs: vec![], relations: None, areas: None, clustering: None, max_neighbors: None }
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None, limits: None },
         objectives: None,
+        precision: None,
     }
 }
 
@@ -289,6 +324,10 @@ pub fn create_matrix(data: Vec<i64>) -> Matrix {
         travel_times: data.clone(),
         distances: data,
         error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
     }
 }
 
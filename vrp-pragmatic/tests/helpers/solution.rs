@@ -55,7 +55,7 @@ fn create_stop_with_activity_impl(
         location: (location.0, location.1).to_loc(),
         time: Schedule { arrival: time.0.to_string(), departure: time.1.to_string() },
         load,
-        distance,
+        distance: distance as f64,
         activities: vec![Activity {
             job_id: id.to_string(),
             activity_type: activity_type.to_string(),
@@ -15,7 +15,7 @@ use vrp_core::utils::Environment;
 /// Runs solver with cheapest insertion heuristic.
 pub fn solve_with_cheapest_insertion(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
     let environment = Arc::new(Environment::default());
-    get_core_solution(problem, matrices, true, |problem: Arc<CoreProblem>| {
+    get_core_solution(problem, matrices, None, true, |problem: Arc<CoreProblem>| {
         let population = create_elitism_population(problem.objective.clone(), environment.clone());
         let refinement_ctx =
             RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone());
@@ -50,8 +50,27 @@ pub fn solve_with_metaheuristic_and_iterations_without_check(
     solve(problem, matrices, generations, false)
 }
 
+/// Runs solver with default metaheuristic and reference solution used by plan changes objective.
+pub fn solve_with_metaheuristic_and_reference(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    reference: &Solution,
+) -> Solution {
+    solve_with_reference(problem, matrices, Some(reference), 200, true)
+}
+
 pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize, perform_check: bool) -> Solution {
-    get_core_solution(problem, matrices, perform_check, |problem: Arc<CoreProblem>| {
+    solve_with_reference(problem, matrices, None, generations, perform_check)
+}
+
+fn solve_with_reference(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    reference: Option<&Solution>,
+    generations: usize,
+    perform_check: bool,
+) -> Solution {
+    get_core_solution(problem, matrices, reference, perform_check, |problem: Arc<CoreProblem>| {
         let environment = Arc::new(Environment::default());
         let telemetry_mode = get_default_telemetry_mode(environment.logger.clone());
         let (solution, _, _) = create_default_config_builder(problem.clone(), environment, telemetry_mode)
@@ -66,23 +85,28 @@ pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize
     })
 }
 
-fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
+fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>, reference: Option<&Solution>) -> Arc<CoreProblem> {
     Arc::new(
-        if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
-            .unwrap(),
+        match (matrices, reference) {
+            (matrices, Some(reference)) => (problem, matrices, reference).read_pragmatic(),
+            (Some(matrices), None) => (problem, matrices).read_pragmatic(),
+            (None, None) => problem.read_pragmatic(),
+        }
+        .unwrap(),
     )
 }
 
 fn get_core_solution<F: Fn(Arc<CoreProblem>) -> CoreSolution>(
     problem: Problem,
     matrices: Option<Vec<Matrix>>,
+    reference: Option<&Solution>,
     perform_check: bool,
     solve_func: F,
 ) -> Solution {
     let format_problem = problem.clone();
     let format_matrices = matrices.clone();
 
-    let core_problem = get_core_problem(problem, matrices);
+    let core_problem = get_core_problem(problem, matrices, reference);
 
     let core_solution = solve_func(core_problem.clone());
 
@@ -24,6 +24,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parking: None,
+                                access: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -40,6 +42,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parking: None,
+                                access: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -56,6 +60,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                parking: None,
+                                access: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -72,6 +78,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parking: None,
+                                access: None,
                             }],
                             demand: Some(vec![2]),
                             order: None,
@@ -88,6 +96,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                parking: None,
+                                access: None,
                             }],
                             demand: Some(vec![3]),
                             order: None,
@@ -104,6 +114,8 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parking: None,
+                                access: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -117,7 +129,7 @@ fn can_handle_properly_invalid_break_removal() {
                 vehicles: vec![VehicleType {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
-                    profile: VehicleProfile { matrix: "car".to_string(), scale: None },
+                    profile: VehicleProfile { matrix: "car".to_string(), scale: None, buffer: None },
                     costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
@@ -140,10 +152,15 @@ fn can_handle_properly_invalid_break_removal() {
                             policy: None,
                         }]),
                         reloads: None,
+                        onboard: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    parking: None,
+                    tags: None,
+                    attributes: None,
+                    is_unlimited: None,
                 }],
                 ..create_default_fleet()
             },
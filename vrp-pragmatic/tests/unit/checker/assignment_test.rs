@@ -133,6 +133,8 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{}{}", tgt, idx)),
+                    parking: None,
+                    access: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
@@ -196,9 +198,9 @@ fn can_detect_time_window_violation() {
     let solution = Solution {
         statistic: Statistic {
             cost: 15.,
-            distance: 2,
-            duration: 3,
-            times: Timing { driving: 2, serving: 1, ..Timing::default() },
+            distance: 2.,
+            duration: 3.,
+            times: Timing { driving: 2., serving: 1., ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -232,9 +234,9 @@ fn can_detect_time_window_violation() {
             ],
             statistic: Statistic {
                 cost: 15.,
-                distance: 2,
-                duration: 3,
-                times: Timing { driving: 2, serving: 1, ..Timing::default() },
+                distance: 2.,
+                duration: 3.,
+                times: Timing { driving: 2., serving: 1., ..Timing::default() },
             },
         }],
         ..create_empty_solution()
@@ -260,9 +262,9 @@ fn can_detect_job_duration_violation() {
     let solution = Solution {
         statistic: Statistic {
             cost: 18.,
-            distance: 2,
-            duration: 6,
-            times: Timing { driving: 2, serving: 2, waiting: 2, ..Timing::default() },
+            distance: 2.,
+            duration: 6.,
+            times: Timing { driving: 2., serving: 2., waiting: 2., ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -296,9 +298,9 @@ fn can_detect_job_duration_violation() {
             ],
             statistic: Statistic {
                 cost: 18.,
-                distance: 2,
-                duration: 6,
-                times: Timing { driving: 2, serving: 2, waiting: 2, ..Timing::default() },
+                distance: 2.,
+                duration: 6.,
+                times: Timing { driving: 2., serving: 2., waiting: 2., ..Timing::default() },
             },
         }],
         ..create_empty_solution()
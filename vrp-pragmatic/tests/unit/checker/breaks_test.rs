@@ -87,6 +87,7 @@ fn can_check_breaks_impl(
                         policy: None,
                     }]),
                     reloads: None,
+                    onboard: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -118,9 +119,9 @@ fn can_check_breaks_impl(
     let solution = Solution {
         statistic: Statistic {
             cost: 22.,
-            distance: 4,
-            duration: 8,
-            times: Timing { driving: 4, serving: 2, break_time: 2, ..Timing::default() },
+            distance: 4.,
+            duration: 8.,
+            times: Timing { driving: 4., serving: 2., break_time: 2., ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -149,7 +150,7 @@ fn can_check_breaks_impl(
                         arrival: "1970-01-01T00:00:03Z".to_string(),
                         departure: "1970-01-01T00:00:06Z".to_string(),
                     },
-                    distance: 2,
+                    distance: 2.,
                     parking: None,
                     load: vec![0],
                     activities,
@@ -165,9 +166,9 @@ fn can_check_breaks_impl(
             ],
             statistic: Statistic {
                 cost: 22.,
-                distance: 4,
-                duration: 8,
-                times: Timing { driving: 4, serving: 2, break_time: 2, ..Timing::default() },
+                distance: 4.,
+                duration: 8.,
+                times: Timing { driving: 4., serving: 2., break_time: 2., ..Timing::default() },
             },
         }],
         violations,
@@ -45,6 +45,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
+                    onboard: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -56,9 +57,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
     let solution = Solution {
         statistic: Statistic {
             cost: 13.,
-            distance: 1,
-            duration: 2,
-            times: Timing { driving: 1, serving: 1, ..Timing::default() },
+            distance: 1.,
+            duration: 2.,
+            times: Timing { driving: 1., serving: 1., ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -79,7 +80,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         arrival: "1970-01-01T00:00:03Z".to_string(),
                         departure: "1970-01-01T00:00:05Z".to_string(),
                     },
-                    distance: 1,
+                    distance: 1.,
                     parking: None,
                     load: vec![*stop_loads.get(1).unwrap()],
                     activities: vec![
@@ -107,7 +108,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         arrival: "1970-01-01T00:00:03Z".to_string(),
                         departure: "1970-01-01T00:00:05Z".to_string(),
                     },
-                    distance: 1,
+                    distance: 1.,
                     parking: None,
                     load: vec![*stop_loads.get(2).unwrap()],
                     activities: vec![Activity {
@@ -125,7 +126,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         arrival: "1970-01-01T00:00:07Z".to_string(),
                         departure: "1970-01-01T00:00:08Z".to_string(),
                     },
-                    distance: 3,
+                    distance: 3.,
                     parking: None,
                     load: vec![*stop_loads.get(3).unwrap()],
                     activities: vec![
@@ -181,9 +182,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
             ],
             statistic: Statistic {
                 cost: 13.,
-                distance: 1,
-                duration: 2,
-                times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                distance: 1.,
+                duration: 2.,
+                times: Timing { driving: 1., serving: 1., ..Timing::default() },
             },
         }],
         ..create_empty_solution()
@@ -206,9 +207,9 @@ fn can_check_load_when_departure_has_other_activity() {
     let solution = Solution {
         statistic: Statistic {
             cost: 6.,
-            distance: 2,
-            duration: 4,
-            times: Timing { driving: 2, serving: 2, ..Timing::default() },
+            distance: 2.,
+            duration: 4.,
+            times: Timing { driving: 2., serving: 2., ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -221,7 +222,7 @@ fn can_check_load_when_departure_has_other_activity() {
                         arrival: "1970-01-01T00:00:00Z".to_string(),
                         departure: "1970-01-01T00:00:01Z".to_string(),
                     },
-                    distance: 0,
+                    distance: 0.,
                     parking: None,
                     load: vec![1],
                     activities: vec![
@@ -263,9 +264,9 @@ fn can_check_load_when_departure_has_other_activity() {
             ],
             statistic: Statistic {
                 cost: 6.,
-                distance: 2,
-                duration: 4,
-                times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                distance: 2.,
+                duration: 4.,
+                times: Timing { driving: 2., serving: 2., ..Timing::default() },
             },
         }],
         ..create_empty_solution()
@@ -308,9 +309,9 @@ fn can_check_resource_consumption() {
     let solution = Solution {
         statistic: Statistic {
             cost: 17.,
-            distance: 6,
-            duration: 11,
-            times: Timing { driving: 6, serving: 5, ..Timing::default() },
+            distance: 6.,
+            duration: 11.,
+            times: Timing { driving: 6., serving: 5., ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -360,9 +361,9 @@ fn can_check_resource_consumption() {
             ],
             statistic: Statistic {
                 cost: 17.,
-                distance: 6,
-                duration: 11,
-                times: Timing { driving: 6, serving: 5, ..Timing::default() },
+                distance: 6.,
+                duration: 11.,
+                times: Timing { driving: 6., serving: 5., ..Timing::default() },
             },
         }],
         ..create_empty_solution()
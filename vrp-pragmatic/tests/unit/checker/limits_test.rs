@@ -43,24 +43,33 @@ parameterized_test! {can_check_shift_and_distance_limit, (max_distance, shift_ti
 }}
 
 can_check_shift_and_distance_limit! {
-    case_01: (Some(10.), None, 11, Result::<(), _>::Err("max distance limit")),
-    case_02: (Some(10.), None, 10, Result::<_, &str>::Ok(())),
-    case_03: (Some(10.), None, 9, Result::<_, &str>::Ok(())),
+    case_01: (Some(10.), None, 11., Result::<(), _>::Err("max distance limit")),
+    case_02: (Some(10.), None, 10., Result::<_, &str>::Ok(())),
+    case_03: (Some(10.), None, 9., Result::<_, &str>::Ok(())),
 
-    case_04: (None, Some(10.), 11, Result::<(), _>::Err("shift time limit")),
-    case_05: (None, Some(10.), 10, Result::<_, &str>::Ok(())),
-    case_06: (None, Some(10.), 9, Result::<_, &str>::Ok(())),
+    case_04: (None, Some(10.), 11., Result::<(), _>::Err("shift time limit")),
+    case_05: (None, Some(10.), 10., Result::<_, &str>::Ok(())),
+    case_06: (None, Some(10.), 9., Result::<_, &str>::Ok(())),
 
-    case_07: (None, None, i64::max_value(), Result::<_, &str>::Ok(())),
+    case_07: (None, None, f64::MAX, Result::<_, &str>::Ok(())),
 }
 
 pub fn can_check_shift_and_distance_limit_impl(
     max_distance: Option<f64>,
     shift_time: Option<f64>,
-    actual: i64,
+    actual: f64,
     expected: Result<(), String>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, shift_time, tour_size: None, areas: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        shift_time,
+        tour_size: None,
+        areas: None,
+        backhaul: None,
+        min_shift_time: None,
+        min_tour_size: None,
+        max_waiting_time: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -77,6 +86,10 @@ pub fn can_check_tour_size_limit() {
         shift_time: None,
         tour_size: Some(2),
         areas: None,
+        backhaul: None,
+        min_shift_time: None,
+        min_tour_size: None,
+        max_waiting_time: None,
     }));
     let solution = create_test_solution(
         Statistic::default(),
@@ -134,6 +147,164 @@ pub fn can_check_tour_size_limit() {
     );
 }
 
+parameterized_test! {can_check_min_limits, (min_shift_time, min_tour_size, duration, jobs, expected), {
+    can_check_min_limits_impl(min_shift_time, min_tour_size, duration, jobs, expected);
+}}
+
+can_check_min_limits! {
+    case_01: (Some(10.), None, 9., 1, Err("min shift time limit violation, expected: not less than 10, got: 9")),
+    case_02: (Some(10.), None, 10., 1, Ok(())),
+    case_03: (None, Some(2), 9., 1, Err("min tour size limit violation, expected: not less than 2, got: 1")),
+    case_04: (None, Some(2), 9., 2, Ok(())),
+}
+
+fn can_check_min_limits_impl(
+    min_shift_time: Option<f64>,
+    min_tour_size: Option<usize>,
+    duration: f64,
+    jobs: usize,
+    expected: Result<(), &str>,
+) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        tour_size: None,
+        areas: None,
+        backhaul: None,
+        min_shift_time,
+        min_tour_size,
+        max_waiting_time: None,
+    }));
+    let solution = create_test_solution(
+        Statistic { duration, ..Statistic::default() },
+        std::iter::once(("departure".to_string(), "departure"))
+            .chain((1..=jobs).map(|idx| (format!("job{}", idx), "delivery")))
+            .chain(std::iter::once(("arrival".to_string(), "arrival")))
+            .enumerate()
+            .map(|(idx, (job_id, activity_type))| {
+                let time = format_time(idx as f64);
+                create_stop_with_activity(&job_id, activity_type, (idx as f64, 0.), 0, (&time, &time), idx as i64)
+            })
+            .collect(),
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(result, expected.map_err(|msg| format!("{}, vehicle id 'some_real_vehicle', shift index: 0", msg)));
+}
+
+parameterized_test! {can_check_max_waiting_time, (per_tour, waiting, expected), {
+    can_check_max_waiting_time_impl(per_tour, waiting, expected);
+}}
+
+can_check_max_waiting_time! {
+    case_01: (Some(10.), 11., Err("max waiting time limit violation, expected: not more than 10, got: 11")),
+    case_02: (Some(10.), 10., Ok(())),
+    case_03: (None, 11., Ok(())),
+}
+
+fn can_check_max_waiting_time_impl(per_tour: Option<f64>, waiting: f64, expected: Result<(), &str>) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        tour_size: None,
+        areas: None,
+        backhaul: None,
+        min_shift_time: None,
+        min_tour_size: None,
+        max_waiting_time: Some(WaitingTimeLimits { per_stop: None, per_tour }),
+    }));
+    let solution = create_test_solution(
+        Statistic { times: Timing { waiting, ..Timing::default() }, ..Statistic::default() },
+        vec![],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(result, expected.map_err(|msg| format!("{}, vehicle id 'some_real_vehicle', shift index: 0", msg)));
+}
+
+parameterized_test! {can_check_max_waiting_time_per_stop, (per_stop, end, expected), {
+    can_check_max_waiting_time_per_stop_impl(per_stop, end, expected);
+}}
+
+can_check_max_waiting_time_per_stop! {
+    case_01: (10., 14., Err("max waiting time per stop limit violation for job 'job1', expected: not more than 10, got: 12")),
+    case_02: (10., 12., Ok(())),
+    case_03: (12., 14., Ok(())),
+}
+
+fn can_check_max_waiting_time_per_stop_impl(per_stop: f64, end: f64, expected: Result<(), &str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        ..create_test_problem(Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            tour_size: None,
+            areas: None,
+            backhaul: None,
+            min_shift_time: None,
+            min_tour_size: None,
+            max_waiting_time: Some(WaitingTimeLimits { per_stop: Some(per_stop), per_tour: None }),
+        }))
+    };
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![create_stop_with_activity("job1", "delivery", (1., 0.), 0, (&format_time(1.), &format_time(end)), 1)],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(result, expected.map_err(|msg| format!("{}, vehicle id 'some_real_vehicle', shift index: 0", msg)));
+}
+
+parameterized_test! {can_check_backhaul, (activity_types, expected), {
+    can_check_backhaul_impl(activity_types, expected);
+}}
+
+can_check_backhaul! {
+    case_01: (vec![("job1", "delivery"), ("job2", "pickup")], Ok(())),
+    case_02: (vec![("job2", "pickup"), ("job1", "delivery")], Err(())),
+}
+
+fn can_check_backhaul_impl(activity_types: Vec<(&str, &str)>, expected: Result<(), ()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_pickup_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        ..create_test_problem(Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            tour_size: None,
+            areas: None,
+            backhaul: Some(true),
+            min_shift_time: None,
+            min_tour_size: None,
+            max_waiting_time: None,
+        }))
+    };
+    let solution = create_test_solution(
+        Statistic::default(),
+        activity_types
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (job_id, activity_type))| {
+                let time = format_time(idx as f64);
+                create_stop_with_activity(job_id, activity_type, (idx as f64, 0.), 0, (&time, &time), idx as i64)
+            })
+            .collect(),
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx).map_err(|_| ());
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn can_check_shift_time() {
     let problem = Problem {
@@ -157,9 +328,9 @@ fn can_check_shift_time() {
     let solution = Solution {
         statistic: Statistic {
             cost: 17.,
-            distance: 2,
-            duration: 5,
-            times: Timing { driving: 2, serving: 1, waiting: 2, ..Timing::default() },
+            distance: 2.,
+            duration: 5.,
+            times: Timing { driving: 2., serving: 1., waiting: 2., ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -193,9 +364,9 @@ fn can_check_shift_time() {
             ],
             statistic: Statistic {
                 cost: 17.,
-                distance: 2,
-                duration: 5,
-                times: Timing { driving: 2, serving: 1, waiting: 2, ..Timing::default() },
+                distance: 2.,
+                duration: 5.,
+                times: Timing { driving: 2., serving: 1., waiting: 2., ..Timing::default() },
             },
         }],
         ..create_empty_solution()
@@ -207,3 +378,46 @@ fn can_check_shift_time() {
 
     assert_eq!(result, Err("tour time is outside shift time, vehicle id 'my_vehicle_1', shift index: 0".to_owned()));
 }
+
+parameterized_test! {can_check_fleet_limits, (min_tours, max_tours, violations, expected), {
+    can_check_fleet_limits_impl(min_tours, max_tours, violations, expected);
+}}
+
+can_check_fleet_limits! {
+    case_01: (Some(1), Some(1), None, Ok(())),
+    case_02: (None, Some(0), None, Err(())),
+    case_03: (Some(2), None, None, Err(())),
+    case_04: (Some(2), None, Some(1), Ok(())),
+    case_05: (Some(2), None, Some(0), Err(())),
+}
+
+fn can_check_fleet_limits_impl(
+    min_tours: Option<usize>,
+    max_tours: Option<usize>,
+    reported_tours: Option<usize>,
+    expected: Result<(), ()>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            limits: Some(vec![FleetLimit { type_id: Some("my_vehicle".to_string()), tag: None, max_tours, min_tours }]),
+            ..create_test_problem(None).fleet
+        },
+        ..create_test_problem(None)
+    };
+    let solution = Solution {
+        violations: reported_tours.map(|actual_tours| {
+            vec![Violation::FleetUsage {
+                type_id: Some("my_vehicle".to_string()),
+                tag: None,
+                min_tours: min_tours.unwrap_or_default(),
+                actual_tours,
+            }]
+        }),
+        ..create_test_solution(Statistic::default(), vec![])
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_fleet_limits(&ctx).map_err(|_| ());
+
+    assert_eq!(result, expected);
+}
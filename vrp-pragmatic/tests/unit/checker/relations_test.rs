@@ -100,10 +100,15 @@ mod single {
                             duration: 2.0,
                             ..create_default_reload()
                         }]),
+                        onboard: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    parking: None,
+                    tags: None,
+                    attributes: None,
+                    is_unlimited: None,
                 }],
                 ..create_default_fleet()
             },
@@ -112,9 +117,9 @@ mod single {
         let solution = Solution {
             statistic: Statistic {
                 cost: 51.,
-                distance: 16,
-                duration: 25,
-                times: Timing { driving: 16, serving: 9, break_time: 2, ..Timing::default() },
+                distance: 16.,
+                duration: 25.,
+                times: Timing { driving: 16., serving: 9., break_time: 2., ..Timing::default() },
             },
             tours: vec![
                 VehicleTour {
@@ -144,7 +149,7 @@ mod single {
                                 arrival: "1970-01-01T00:00:03Z".to_string(),
                                 departure: "1970-01-01T00:00:06Z".to_string(),
                             },
-                            distance: 2,
+                            distance: 2.,
                             parking: None,
                             load: vec![0],
                             activities: vec![
@@ -209,9 +214,9 @@ mod single {
                     ],
                     statistic: Statistic {
                         cost: 51.,
-                        distance: 16,
-                        duration: 25,
-                        times: Timing { driving: 16, serving: 9, break_time: 2, ..Timing::default() },
+                        distance: 16.,
+                        duration: 25.,
+                        times: Timing { driving: 16., serving: 9., break_time: 2., ..Timing::default() },
                     },
                 },
                 VehicleTour {
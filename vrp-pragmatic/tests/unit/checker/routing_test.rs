@@ -1,7 +1,6 @@
 use super::*;
 use crate::format_time;
 use crate::helpers::*;
-use vrp_core::models::examples::create_example_problem;
 
 fn create_test_problem() -> Problem {
     Problem {
@@ -15,10 +14,16 @@ fn create_test_problem() -> Problem {
 }
 
 fn create_test_statistic() -> Statistic {
-    Statistic { cost: 10., distance: 4, duration: 6, times: Timing { driving: 4, serving: 2, ..Timing::default() } }
+    Statistic { cost: 10., distance: 4., duration: 6., times: Timing { driving: 4., serving: 2., ..Timing::default() } }
 }
 
-fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> Solution {
+fn create_checker_context(problem: Problem, matrix: Matrix, solution: Solution) -> CheckerContext {
+    let core_problem = Arc::new((problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap());
+
+    CheckerContext::new(core_problem, problem, Some(vec![matrix]), solution).unwrap()
+}
+
+fn create_test_solution(statistic: Statistic, stop_data: &[(f64, f64); 3]) -> Solution {
     let [first, second, third] = stop_data;
     Solution {
         statistic: statistic.clone(),
@@ -71,7 +76,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                     (0., 0.),
                     0,
                     (format_time(third.0).as_str(), "1970-01-01T00:00:06Z"),
-                    third.1,
+                    third.1 as i64,
                 ),
             ],
             statistic,
@@ -100,26 +105,26 @@ parameterized_test! {can_check_stop, (stop_data, expected_result), {
 }}
 
 can_check_stop! {
-    case_01: (&[(1., 1), (3., 2), (6., 4)], Ok(())),
+    case_01: (&[(1., 1.), (3., 2.), (6., 4.)], Ok(())),
 
     // NOTE due to rounding issues, we have to compare with tolerance 1
-    case_02: (&[(2., 1), (3., 2), (6., 4)], Ok(())),
-    case_03: (&[(1., 1), (3., 1), (6., 4)], Ok(())),
+    case_02: (&[(2., 1.), (3., 2.), (6., 4.)], Ok(())),
+    case_03: (&[(1., 1.), (3., 1.), (6., 4.)], Ok(())),
 
-    case_04: (&[(3., 1), (3., 2), (6., 4)], Err(vec![duration_error_msg(1, 3, 1)])),
-    case_05: (&[(1., 1), (1., 2), (6., 4)], Err(vec![duration_error_msg(2, 1, 3)])),
-    case_06: (&[(1., 1), (3., 2), (8., 4)], Err(vec![duration_error_msg(3, 8, 6)])),
+    case_04: (&[(3., 1.), (3., 2.), (6., 4.)], Err(vec![duration_error_msg(1, 3, 1)])),
+    case_05: (&[(1., 1.), (1., 2.), (6., 4.)], Err(vec![duration_error_msg(2, 1, 3)])),
+    case_06: (&[(1., 1.), (3., 2.), (8., 4.)], Err(vec![duration_error_msg(3, 8, 6)])),
 
-    case_07: (&[(1., 3), (3., 2), (6., 4)], Err(vec![distance_error_msg(1, 3, 1)])),
-    case_08: (&[(1., 1), (3., 0), (6., 4)], Err(vec![distance_error_msg(2, 0, 2)])),
-    case_09: (&[(1., 1), (3., 2), (6., 6)], Err(vec![distance_error_msg(3, 6, 4)])),
+    case_07: (&[(1., 3.), (3., 2.), (6., 4.)], Err(vec![distance_error_msg(1, 3, 1)])),
+    case_08: (&[(1., 1.), (3., 0.), (6., 4.)], Err(vec![distance_error_msg(2, 0, 2)])),
+    case_09: (&[(1., 1.), (3., 2.), (6., 6.)], Err(vec![distance_error_msg(3, 6, 4)])),
 }
 
-fn can_check_stop_impl(stop_data: &[(f64, i64); 3], expected_result: Result<(), Vec<String>>) {
+fn can_check_stop_impl(stop_data: &[(f64, f64); 3], expected_result: Result<(), Vec<String>>) {
     let problem = create_test_problem();
     let matrix = create_matrix_from_problem(&problem);
     let solution = create_test_solution(create_test_statistic(), stop_data);
-    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution).unwrap();
+    let ctx = create_checker_context(problem, matrix, solution);
 
     let result = check_routing(&ctx);
 
@@ -134,12 +139,12 @@ can_check_tour_statistic! {
     case_01: (create_test_statistic(), Ok(())),
 
     case_02: (Statistic {
-        distance: 1,
+        distance: 1.,
         ..create_test_statistic()
     }, Err(vec!["distance mismatch for tour statistic: my_vehicle_1, expected: '4', got: '1'".to_string()])),
 
     case_03: (Statistic {
-        duration: 1,
+        duration: 1.,
         ..create_test_statistic()
     }, Err(vec!["duration mismatch for tour statistic: my_vehicle_1, expected: '6', got: '1'".to_string()])),
 }
@@ -147,8 +152,8 @@ can_check_tour_statistic! {
 fn can_check_tour_statistic_impl(statistic: Statistic, expected_result: Result<(), Vec<String>>) {
     let problem = create_test_problem();
     let matrix = create_matrix_from_problem(&problem);
-    let solution = create_test_solution(statistic, &[(1., 1), (3., 2), (6., 4)]);
-    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution).unwrap();
+    let solution = create_test_solution(statistic, &[(1., 1.), (3., 2.), (6., 4.)]);
+    let ctx = create_checker_context(problem, matrix, solution);
 
     let result = check_routing(&ctx);
 
@@ -159,10 +164,10 @@ fn can_check_tour_statistic_impl(statistic: Statistic, expected_result: Result<(
 fn can_check_solution_statistic() {
     let problem = create_test_problem();
     let matrix = create_matrix_from_problem(&problem);
-    let solution = create_test_solution(create_test_statistic(), &[(1., 1), (3., 2), (6., 4)]);
-    let wrong_statistic = Statistic { duration: 1, ..create_test_statistic() };
+    let solution = create_test_solution(create_test_statistic(), &[(1., 1.), (3., 2.), (6., 4.)]);
+    let wrong_statistic = Statistic { duration: 1., ..create_test_statistic() };
     let solution = Solution { statistic: wrong_statistic.clone(), ..solution };
-    let ctx = CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution).unwrap();
+    let ctx = create_checker_context(problem, matrix, solution);
 
     let result = check_routing(&ctx);
 
@@ -175,3 +180,94 @@ fn can_check_solution_statistic() {
         )])
     );
 }
+
+parameterized_test! {can_check_time_window_slack, (slack, expected_result), {
+    can_check_time_window_slack_impl(slack, expected_result);
+}}
+
+can_check_time_window_slack! {
+    case_01: (None, Ok(())),
+    case_02: (Some(1.), Ok(())),
+    case_03: (Some(2.), Err(vec![
+        "time window slack violation for 'job1' in tour 'my_vehicle_1', expected: not less than 2".to_string()
+    ])),
+}
+
+fn can_check_time_window_slack_impl(slack: Option<f64>, expected_result: Result<(), Vec<String>>) {
+    let problem = create_test_problem();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 2)], 1.),
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            ..problem.plan
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile {
+                    buffer: Some(TravelBuffer { percentage: None, fixed: None, slack }),
+                    ..create_default_vehicle_profile()
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..problem.fleet
+        },
+        ..problem
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = create_test_solution(create_test_statistic(), &[(1., 1.), (3., 2.), (6., 4.)]);
+    let ctx = create_checker_context(problem, matrix, solution);
+
+    let result = check_routing(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_stop_parking, (place_parking, vehicle_parking, actual_parking, expected_result), {
+    can_check_stop_parking_impl(place_parking, vehicle_parking, actual_parking, expected_result);
+}}
+
+can_check_stop_parking! {
+    case_01: (None, None, None, Ok(())),
+    case_02: (Some(10.), None, Some(10.), Ok(())),
+    case_03: (Some(10.), Some(20.), Some(10.), Err(vec![
+        "parking time mismatch for 2 stop in the tour: my_vehicle_1, expected: '20', got: '0'".to_string()
+    ])),
+    case_04: (Some(10.), None, None, Err(vec![
+        "parking time mismatch for 1 stop in the tour: my_vehicle_1, expected: '10', got: '0'".to_string()
+    ])),
+    case_05: (None, Some(20.), Some(10.), Err(vec![
+        "parking time mismatch for 1 stop in the tour: my_vehicle_1, expected: '20', got: '10'".to_string()
+    ])),
+}
+
+fn can_check_stop_parking_impl(
+    place_parking: Option<f64>,
+    vehicle_parking: Option<f64>,
+    actual_parking: Option<f64>,
+    expected_result: Result<(), Vec<String>>,
+) {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap().first_mut().unwrap().places.first_mut().unwrap().parking = place_parking;
+    let problem = create_test_problem();
+    let problem = Problem {
+        plan: Plan { jobs: vec![job, create_delivery_job("job2", (2., 0.))], ..problem.plan },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { parking: vehicle_parking, ..create_default_vehicle_type() }],
+            ..problem.fleet
+        },
+        ..problem
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let mut solution = create_test_solution(create_test_statistic(), &[(1., 1.), (3., 2.), (6., 4.)]);
+    if let Some(Stop::Point(stop)) = solution.tours[0].stops.get_mut(1) {
+        stop.parking =
+            actual_parking.map(|parking| Interval { start: format_time(1.), end: format_time(1. + parking) });
+    }
+    let ctx = create_checker_context(problem, matrix, solution);
+
+    let result = check_routing(&ctx);
+
+    assert_eq!(result, expected_result);
+}
@@ -0,0 +1,125 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::problem::{Fleet, Place};
+
+const VIOLATION_CODE: i32 = 1;
+const RESTRICTED_LOCATION: Location = 1;
+const OPEN_LOCATION: Location = 2;
+
+fn create_limits(max_weight: Option<f64>, max_height: Option<f64>) -> AccessLimits {
+    AccessLimits { max_weight, max_height, max_length: None }
+}
+
+fn create_test_module(limits: AccessLimits) -> AccessModule {
+    AccessModule::new(vec![(RESTRICTED_LOCATION, limits)].into_iter().collect(), VIOLATION_CODE)
+}
+
+fn create_test_fleet(attributes: Option<(f64, f64)>) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    if let Some((weight, height)) = attributes {
+        vehicle.dimens.set_vehicle_attributes(VehicleAttributes {
+            weight: Some(weight),
+            height: Some(height),
+            length: None,
+        });
+    }
+
+    test_fleet_with_vehicles(vec![Arc::new(vehicle)])
+}
+
+fn create_test_job(locations: Vec<Location>) -> Job {
+    let single = create_single_with_location(None);
+    let place = single.places.first().unwrap().clone();
+
+    Job::Single(Arc::new(Single {
+        places: locations.into_iter().map(|location| Place { location: Some(location), ..place.clone() }).collect(),
+        dimens: Default::default(),
+    }))
+}
+
+parameterized_test! {can_evaluate_job, (limits, attributes, locations, expected), {
+    can_evaluate_job_impl(limits, attributes, locations, expected);
+}}
+
+can_evaluate_job! {
+    case01_no_attributes: (create_limits(Some(7.5), None), None, vec![RESTRICTED_LOCATION], None),
+    case02_no_limits: (create_limits(None, None), Some((10., 3.)), vec![RESTRICTED_LOCATION], None),
+    case03_weight_exceeds: (create_limits(Some(7.5), None), Some((10., 3.)), vec![RESTRICTED_LOCATION], Some(VIOLATION_CODE)),
+    case04_weight_fits: (create_limits(Some(7.5), None), Some((7.5, 3.)), vec![RESTRICTED_LOCATION], None),
+    case05_height_exceeds: (create_limits(Some(12.), Some(2.8)), Some((10., 3.)), vec![RESTRICTED_LOCATION], Some(VIOLATION_CODE)),
+    case06_open_location: (create_limits(Some(7.5), None), Some((10., 3.)), vec![OPEN_LOCATION], None),
+    case07_alternative_place: (create_limits(Some(7.5), None), Some((10., 3.)), vec![RESTRICTED_LOCATION, OPEN_LOCATION], None),
+}
+
+fn can_evaluate_job_impl(
+    limits: AccessLimits,
+    attributes: Option<(f64, f64)>,
+    locations: Vec<Location>,
+    expected: Option<i32>,
+) {
+    let fleet = create_test_fleet(attributes);
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+
+    let result = ConstraintPipeline::default()
+        .add_module(Arc::new(create_test_module(limits)))
+        .evaluate_hard_route(&create_solution_context_for_fleet(&fleet), &route_ctx, &create_test_job(locations))
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_activity, (location, expected), {
+    can_evaluate_activity_impl(location, expected);
+}}
+
+can_evaluate_activity! {
+    case01_restricted: (RESTRICTED_LOCATION, Some(VIOLATION_CODE)),
+    case02_open: (OPEN_LOCATION, None),
+}
+
+fn can_evaluate_activity_impl(location: Location, expected: Option<i32>) {
+    let fleet = create_test_fleet(Some((10., 3.)));
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let target = create_activity_at_location(location);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = ConstraintPipeline::default()
+        .add_module(Arc::new(create_test_module(create_limits(Some(7.5), None))))
+        .evaluate_hard_activity(&route_ctx, &activity_ctx)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_both_open: (OPEN_LOCATION, OPEN_LOCATION, Ok(())),
+    case02_both_restricted: (RESTRICTED_LOCATION, RESTRICTED_LOCATION, Ok(())),
+    case03_restricted_source: (RESTRICTED_LOCATION, OPEN_LOCATION, Ok(())),
+    case04_restricted_candidate: (OPEN_LOCATION, RESTRICTED_LOCATION, Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(source: Location, candidate: Location, expected: Result<(), i32>) {
+    let module = create_test_module(create_limits(Some(7.5), None));
+
+    let result = module.merge(create_test_job(vec![source]), create_test_job(vec![candidate])).map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_combine_limits() {
+    let limits = create_limits(Some(7.5), None).combine(&create_limits(Some(12.), Some(3.)));
+
+    assert_eq!(limits.max_weight, Some(7.5));
+    assert_eq!(limits.max_height, Some(3.));
+    assert_eq!(limits.max_length, None);
+}
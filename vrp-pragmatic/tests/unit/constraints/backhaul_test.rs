@@ -0,0 +1,63 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::problem::Fleet;
+
+const STATE_KEY: i32 = 2;
+const VIOLATION_CODE: i32 = 1;
+
+fn create_test_fleet(backhaul: bool) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_backhaul(backhaul);
+
+    test_fleet_with_vehicles(vec![Arc::new(vehicle)])
+}
+
+fn create_test_activity(job_type: &str) -> Activity {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_job_type(job_type.to_string());
+
+    create_activity_with_job_at_location(Arc::new(single), DEFAULT_JOB_LOCATION)
+}
+
+parameterized_test! {can_evaluate_activity, (backhaul, activities, index, target, expected), {
+    can_evaluate_activity_impl(backhaul, activities, index, target, expected);
+}}
+
+can_evaluate_activity! {
+    case01_empty_tour: (true, vec![], 0, "pickup", None),
+    case02_delivery_before_pickup: (true, vec!["pickup"], 0, "delivery", None),
+    case03_delivery_after_pickup: (true, vec!["pickup"], 1, "delivery", Some((VIOLATION_CODE, true))),
+    case04_pickup_before_delivery: (true, vec!["delivery"], 0, "pickup", Some((VIOLATION_CODE, false))),
+    case05_pickup_after_delivery: (true, vec!["delivery"], 1, "pickup", None),
+    case06_between: (true, vec!["delivery", "pickup"], 1, "delivery", None),
+    case07_between: (true, vec!["delivery", "pickup"], 1, "pickup", None),
+    case08_service_anywhere: (true, vec!["delivery", "pickup"], 2, "service", None),
+    case09_no_backhaul: (false, vec!["pickup"], 1, "delivery", None),
+}
+
+fn can_evaluate_activity_impl(
+    backhaul: bool,
+    activities: Vec<&str>,
+    index: usize,
+    target: &str,
+    expected: Option<(i32, bool)>,
+) {
+    let fleet = create_test_fleet(backhaul);
+    let mut route_ctx =
+        create_route_context_with_activities(&fleet, "v1", activities.into_iter().map(create_test_activity).collect());
+    let module = BackhaulModule::new(VIOLATION_CODE, STATE_KEY);
+    module.accept_route_state(&mut route_ctx);
+    let target = create_test_activity(target);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route.tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(index + 1),
+    };
+
+    let result = BackhaulHardActivityConstraint { code: VIOLATION_CODE, state_key: STATE_KEY }
+        .evaluate_activity(&route_ctx, &activity_ctx)
+        .map(|violation| (violation.code, violation.stopped));
+
+    assert_eq!(result, expected);
+}
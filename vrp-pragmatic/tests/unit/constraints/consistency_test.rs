@@ -0,0 +1,99 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::problem::{Fleet, Single};
+
+const STATE_KEY: i32 = 2;
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_test_single(consistency: Option<&str>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_job_consistency(consistency.map(|consistency| consistency.to_string()));
+
+    Arc::new(single)
+}
+
+fn create_test_routes<'a>(routes: Vec<(&'a str, Vec<Option<&str>>)>) -> Vec<(&'a str, Vec<Arc<Single>>)> {
+    routes
+        .into_iter()
+        .map(|(vehicle, consistencies)| (vehicle, consistencies.into_iter().map(create_test_single).collect()))
+        .collect()
+}
+
+parameterized_test! {can_count_inconsistency, (routes, expected), {
+    can_count_inconsistency_impl(routes, expected);
+}}
+
+can_count_inconsistency! {
+    case_01: (vec![("v1", vec![Some("c1"), Some("c2")]), ("v2", vec![Some("c3"), None])], 0),
+    case_02: (vec![("v1", vec![Some("c1"), Some("c2")]), ("v2", vec![Some("c1"), None])], 1),
+    case_03: (vec![("v1", vec![Some("c1"), Some("c2")]), ("v2", vec![Some("c1"), Some("c2")])], 2),
+    case_04: (vec![("v1", vec![Some("c1")]), ("v1", vec![Some("c1")])], 0),
+    case_05: (vec![("v1", vec![None]), ("v2", vec![None])], 0),
+}
+
+fn can_count_inconsistency_impl(routes: Vec<(&str, Vec<Option<&str>>)>, expected: usize) {
+    let fleet = create_test_fleet();
+    let solution_ctx = create_solution_context_with_routes(&fleet, create_test_routes(routes));
+
+    let result = get_inconsistency(solution_ctx.routes.as_slice());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_job, (routes, route_idx, consistency, expected), {
+    can_estimate_job_impl(routes, route_idx, consistency, expected);
+}}
+
+can_estimate_job! {
+    case_01: (vec![("v1", vec![]), ("v2", vec![Some("c1")])], 0, Some("c1"), true),
+    case_02: (vec![("v1", vec![]), ("v2", vec![Some("c1")])], 1, Some("c1"), false),
+    case_03: (vec![("v1", vec![]), ("v2", vec![Some("c1")])], 0, Some("c2"), false),
+    case_04: (vec![("v1", vec![]), ("v2", vec![Some("c1")])], 0, None, false),
+    case_05: (vec![("v1", vec![]), ("v2", vec![])], 0, Some("c1"), false),
+}
+
+fn can_estimate_job_impl(
+    routes: Vec<(&str, Vec<Option<&str>>)>,
+    route_idx: usize,
+    consistency: Option<&str>,
+    expected: bool,
+) {
+    let fleet = create_test_fleet();
+    let (module, _) = ConsistencyModule::new_minimized(-1, STATE_KEY);
+    let mut solution_ctx = create_solution_context_with_routes(&fleet, create_test_routes(routes));
+    module.accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.get(route_idx).unwrap();
+    let job = Job::Single(create_test_single(consistency));
+
+    let result = ConsistencySoftRouteConstraint { state_key: STATE_KEY }.estimate_job(&solution_ctx, route_ctx, &job);
+
+    assert_eq!(result > 0., expected);
+}
+
+parameterized_test! {can_merge_consistencies, (source, candidate, expected), {
+    can_merge_consistencies_impl(Job::Single(source), Job::Single(candidate), expected);
+}}
+
+can_merge_consistencies! {
+    case_01: (create_test_single(Some("c1")), create_test_single(Some("c2")), Err(0)),
+    case_02: (create_test_single(Some("c1")), create_test_single(Some("c1")), Ok(())),
+    case_03: (create_test_single(None), create_test_single(Some("c1")), Err(0)),
+    case_04: (create_test_single(None), create_test_single(None), Ok(())),
+}
+
+fn can_merge_consistencies_impl(source: Job, candidate: Job, expected: Result<(), i32>) {
+    let (constraint, _) = ConsistencyModule::new_minimized(0, 0);
+
+    let result = constraint.merge(source, candidate).map(|_| ());
+
+    assert_eq!(result, expected);
+}
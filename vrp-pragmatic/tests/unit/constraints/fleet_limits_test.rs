@@ -0,0 +1,115 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use hashbrown::HashSet;
+use vrp_core::models::problem::{Fleet, Single};
+
+const STATE_KEY: i32 = 2;
+const VIOLATION_CODE: i32 = 1;
+
+fn create_test_vehicle(id: &str, type_id: &str, tags: Vec<&str>) -> Arc<Vehicle> {
+    let mut vehicle = test_vehicle(id);
+    vehicle.dimens.set_vehicle_type(type_id.to_string());
+    vehicle.dimens.set_vehicle_tags(tags.into_iter().map(|tag| tag.to_string()).collect::<HashSet<_>>());
+
+    Arc::new(vehicle)
+}
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![
+            create_test_vehicle("v1", "truck", vec!["rental"]),
+            create_test_vehicle("v2", "truck", vec![]),
+            create_test_vehicle("v3", "van", vec!["rental"]),
+        ],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_test_limits(
+    target: FleetLimitTarget,
+    min_tours: Option<usize>,
+    max_tours: Option<usize>,
+) -> Arc<Vec<FleetLimit>> {
+    Arc::new(vec![FleetLimit { target, min_tours, max_tours }])
+}
+
+fn create_test_routes<'a>(used: Vec<&'a str>, target: &'a str) -> Vec<(&'a str, Vec<Arc<Single>>)> {
+    used.into_iter()
+        .map(|vehicle| (vehicle, vec![Arc::new(create_single_with_location(Some(DEFAULT_JOB_LOCATION)))]))
+        .chain(std::iter::once((target, vec![])))
+        .collect()
+}
+
+fn get_target(kind: &str, value: &str) -> FleetLimitTarget {
+    match kind {
+        "type" => FleetLimitTarget::VehicleType(value.to_string()),
+        "tag" => FleetLimitTarget::VehicleTag(value.to_string()),
+        _ => unreachable!(),
+    }
+}
+
+parameterized_test! {can_evaluate_job, (used, target, limit, max_tours, expected), {
+    can_evaluate_job_impl(used, target, limit, max_tours, expected);
+}}
+
+can_evaluate_job! {
+    case01_type_below_max: (vec![], "v1", ("type", "truck"), 1, None),
+    case02_type_max_reached: (vec!["v1"], "v2", ("type", "truck"), 1, Some(VIOLATION_CODE)),
+    case03_other_type: (vec!["v1"], "v3", ("type", "truck"), 1, None),
+    case04_tag_max_reached: (vec!["v1"], "v3", ("tag", "rental"), 1, Some(VIOLATION_CODE)),
+    case05_no_tag: (vec!["v1"], "v2", ("tag", "rental"), 1, None),
+}
+
+fn can_evaluate_job_impl(used: Vec<&str>, target: &str, limit: (&str, &str), max_tours: usize, expected: Option<i32>) {
+    let fleet = create_test_fleet();
+    let limits = create_test_limits(get_target(limit.0, limit.1), None, Some(max_tours));
+    let mut solution_ctx = create_solution_context_with_routes(&fleet, create_test_routes(used, target));
+    let module = FleetLimitsModule::new(limits.clone(), VIOLATION_CODE, STATE_KEY);
+    module.accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.last().unwrap();
+    let job = Job::Single(Arc::new(create_single_with_location(Some(DEFAULT_JOB_LOCATION))));
+
+    let result = FleetLimitsHardRouteConstraint { limits, code: VIOLATION_CODE, state_key: STATE_KEY }
+        .evaluate_job(&solution_ctx, route_ctx, &job)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_job, (used, target, min_tours, expected), {
+    can_estimate_job_impl(used, target, min_tours, expected);
+}}
+
+can_estimate_job! {
+    case01_min_not_reached: (vec![], "v1", 1, -DEFAULT_ROUTE_PENALTY),
+    case02_min_reached: (vec!["v2"], "v1", 1, 0.),
+    case03_other_type: (vec![], "v3", 1, 0.),
+}
+
+fn can_estimate_job_impl(used: Vec<&str>, target: &str, min_tours: usize, expected: Cost) {
+    let fleet = create_test_fleet();
+    let limits = create_test_limits(get_target("type", "truck"), Some(min_tours), None);
+    let mut solution_ctx = create_solution_context_with_routes(&fleet, create_test_routes(used, target));
+    let module = FleetLimitsModule::new(limits.clone(), VIOLATION_CODE, STATE_KEY);
+    module.accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.last().unwrap();
+    let job = Job::Single(Arc::new(create_single_with_location(Some(DEFAULT_JOB_LOCATION))));
+
+    let result =
+        FleetLimitsSoftRouteConstraint { limits, state_key: STATE_KEY }.estimate_job(&solution_ctx, route_ctx, &job);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_shortage() {
+    let fleet = create_test_fleet();
+    let solution_ctx = create_solution_context_with_routes(&fleet, create_test_routes(vec!["v1"], "v2"));
+    let type_limit = FleetLimit { target: get_target("type", "truck"), min_tours: Some(3), max_tours: None };
+    let tag_limit = FleetLimit { target: get_target("tag", "rental"), min_tours: Some(1), max_tours: None };
+
+    assert_eq!(type_limit.get_shortage(solution_ctx.routes.as_slice()), 2);
+    assert_eq!(tag_limit.get_shortage(solution_ctx.routes.as_slice()), 0);
+}
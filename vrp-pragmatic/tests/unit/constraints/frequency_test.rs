@@ -0,0 +1,134 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::models::common::TimeInterval;
+use vrp_core::models::problem::{Fleet, Single, Vehicle, VehicleDetail, VehiclePlace};
+
+const STATE_KEY: i32 = 2;
+const VIOLATION_CODE: i32 = 1;
+
+fn create_test_vehicle(id: &str, day: usize) -> Arc<Vehicle> {
+    let time = TimeInterval { earliest: Some(day as f64 * SECONDS_PER_DAY + 3600.), latest: None };
+
+    Arc::new(Vehicle {
+        details: vec![VehicleDetail {
+            start: Some(VehiclePlace { location: 0, time: time.clone() }),
+            end: Some(VehiclePlace { location: 0, time }),
+        }],
+        ..test_vehicle(id)
+    })
+}
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![create_test_vehicle("v1", 0), create_test_vehicle("v2", 1), create_test_vehicle("v3", 3)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_test_frequency(key: &str, min_gap_days: usize, allowed_days: Option<Vec<usize>>) -> JobFrequency {
+    JobFrequency {
+        key: key.to_string(),
+        min_gap_days,
+        allowed_days: allowed_days.map(|days| days.into_iter().collect()),
+    }
+}
+
+fn create_test_single(frequency: Option<JobFrequency>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_job_frequency(frequency);
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_evaluate_job, (routes, vehicle, min_gap_days, allowed_days, expected), {
+    can_evaluate_job_impl(routes, vehicle, min_gap_days, allowed_days, expected);
+}}
+
+can_evaluate_job! {
+    case01_no_visits: (vec![], "v1", 1, None, None),
+    case02_same_day: (vec![("v1", "job1")], "v1", 1, None, Some(VIOLATION_CODE)),
+    case03_next_day: (vec![("v1", "job1")], "v2", 1, None, None),
+    case04_next_day_with_gap: (vec![("v1", "job1")], "v2", 2, None, Some(VIOLATION_CODE)),
+    case05_enough_gap: (vec![("v1", "job1")], "v3", 2, None, None),
+    case06_other_job: (vec![("v1", "job2")], "v1", 1, None, None),
+    case07_allowed_day: (vec![], "v2", 1, Some(vec![1, 3]), None),
+    case08_not_allowed_day: (vec![], "v1", 1, Some(vec![1, 3]), Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_job_impl(
+    routes: Vec<(&str, &str)>,
+    vehicle: &str,
+    min_gap_days: usize,
+    allowed_days: Option<Vec<usize>>,
+    expected: Option<i32>,
+) {
+    let fleet = create_test_fleet();
+    let routes = routes
+        .into_iter()
+        .map(|(vehicle, key)| (vehicle, vec![create_test_single(Some(create_test_frequency(key, 1, None)))]))
+        .chain(std::iter::once((vehicle, vec![])))
+        .collect();
+    let mut solution_ctx = create_solution_context_with_routes(&fleet, routes);
+    let module = FrequencyModule::new(0., VIOLATION_CODE, STATE_KEY);
+    module.accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.last().unwrap();
+    let job = Job::Single(create_test_single(Some(create_test_frequency("job1", min_gap_days, allowed_days))));
+
+    let result = FrequencyHardRouteConstraint { horizon_start: 0., code: VIOLATION_CODE, state_key: STATE_KEY }
+        .evaluate_job(&solution_ctx, route_ctx, &job)
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_remove_incomplete_visits, (is_required, expected_assigned), {
+    can_remove_incomplete_visits_impl(is_required, expected_assigned);
+}}
+
+can_remove_incomplete_visits! {
+    case01_finalized: (false, 1),
+    case02_still_required: (true, 3),
+}
+
+fn can_remove_incomplete_visits_impl(is_required: bool, expected_assigned: usize) {
+    let fleet = create_test_fleet();
+    let create_occurrence = || create_test_single(Some(create_test_frequency("job1", 1, None)));
+    let unassigned = Job::Single(create_occurrence());
+    let mut solution_ctx = create_solution_context_with_routes(
+        &fleet,
+        vec![("v1", vec![create_occurrence(), create_test_single(None)]), ("v2", vec![create_occurrence()])],
+    );
+    solution_ctx.unassigned.insert(unassigned.clone(), UnassignmentInfo::Simple(0));
+    if is_required {
+        solution_ctx.required.push(unassigned);
+    }
+
+    FrequencyModule::new(0., VIOLATION_CODE, STATE_KEY).accept_solution_state(&mut solution_ctx);
+
+    let assigned = solution_ctx.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>();
+    assert_eq!(assigned, expected_assigned);
+    assert_eq!(solution_ctx.unassigned.len(), 4 - expected_assigned);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case_01: (None, None, Ok(())),
+    case_02: (Some("job1"), None, Err(VIOLATION_CODE)),
+    case_03: (None, Some("job1"), Err(VIOLATION_CODE)),
+    case_04: (Some("job1"), Some("job1"), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(source: Option<&str>, candidate: Option<&str>, expected: Result<(), i32>) {
+    let create_job =
+        |key: Option<&str>| Job::Single(create_test_single(key.map(|key| create_test_frequency(key, 1, None))));
+    let module = FrequencyModule::new(0., VIOLATION_CODE, STATE_KEY);
+
+    let result = module.merge(create_job(source), create_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
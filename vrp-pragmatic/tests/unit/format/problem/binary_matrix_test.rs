@@ -0,0 +1,156 @@
+use super::*;
+use crate::format::problem::deserialize_matrix;
+use std::io::BufReader;
+
+fn create_matrix(error_codes: Option<Vec<i64>>, costs: Option<Vec<f64>>) -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: Some("2020-07-04T10:00:00Z".to_string()),
+        travel_times: vec![0, 10, 20, 0],
+        distances: vec![0, 100, 200, 0],
+        error_codes,
+        costs,
+        interpolation: None,
+        compact: None,
+        fractions: None,
+    }
+}
+
+fn create_fractional_matrix(travel_times: Vec<f64>, distances: Vec<f64>) -> Matrix {
+    let (travel_times, distances, fractions) = split_matrix_values(travel_times, distances);
+
+    Matrix { travel_times, distances, fractions, ..create_matrix(None, None) }
+}
+
+fn serialize_matrix(matrix: &Matrix, compress: bool) -> Result<Vec<u8>, Error> {
+    let mut buffer = Vec::new();
+    serialize_matrix_binary(BufWriter::new(&mut buffer), matrix, compress)?;
+
+    Ok(buffer)
+}
+
+parameterized_test! {can_serialize_and_deserialize_binary_matrix, (error_codes, costs, compress), {
+    can_serialize_and_deserialize_binary_matrix_impl(error_codes, costs, compress);
+}}
+
+can_serialize_and_deserialize_binary_matrix! {
+    case01_plain: (None, None, false),
+    case02_compressed: (None, None, true),
+    case03_error_codes: (Some(vec![0, 1, 0, 0]), None, false),
+    case04_costs: (None, Some(vec![0., 2.5, 0., 0.]), true),
+    case05_all: (Some(vec![0, 0, 1, 0]), Some(vec![0., 2.5, 3., 0.]), true),
+}
+
+#[test]
+fn can_serialize_and_deserialize_compact_storage() {
+    for compact in [None, Some(true)] {
+        let matrix = Matrix { compact, ..create_matrix(None, None) };
+        let buffer = serialize_matrix(&matrix, false).expect("cannot serialize matrix");
+
+        let result = deserialize_matrix(BufReader::new(buffer.as_slice())).expect("cannot deserialize matrix");
+
+        assert_eq!(result.compact, compact);
+    }
+}
+
+fn can_serialize_and_deserialize_binary_matrix_impl(
+    error_codes: Option<Vec<i64>>,
+    costs: Option<Vec<f64>>,
+    compress: bool,
+) {
+    let matrix = create_matrix(error_codes, costs);
+    let buffer = serialize_matrix(&matrix, compress).expect("cannot serialize matrix");
+
+    let result = deserialize_matrix(BufReader::new(buffer.as_slice())).expect("cannot deserialize matrix");
+
+    assert_eq!(result.profile, matrix.profile);
+    assert_eq!(result.timestamp, matrix.timestamp);
+    assert_eq!(result.travel_times, matrix.travel_times);
+    assert_eq!(result.distances, matrix.distances);
+    assert_eq!(result.error_codes, matrix.error_codes);
+    assert_eq!(result.costs, matrix.costs);
+    assert_eq!(result.compact, matrix.compact);
+    assert!(result.fractions.is_none());
+}
+
+#[test]
+fn can_serialize_and_deserialize_interpolation() {
+    for interpolation in [None, Some(TimeInterpolation::Step), Some(TimeInterpolation::Linear)] {
+        let matrix = Matrix { interpolation: interpolation.clone(), ..create_matrix(None, None) };
+        let buffer = serialize_matrix(&matrix, false).expect("cannot serialize matrix");
+
+        let result = deserialize_matrix(BufReader::new(buffer.as_slice())).expect("cannot deserialize matrix");
+
+        assert_eq!(result.interpolation, interpolation);
+    }
+}
+
+#[test]
+fn can_serialize_and_deserialize_fractional_values() {
+    let matrix = create_fractional_matrix(vec![0., 0.4, 20.25, 0.], vec![0., 1.5, 200., 0.]);
+    let buffer = serialize_matrix(&matrix, true).expect("cannot serialize matrix");
+
+    let result = deserialize_matrix(BufReader::new(buffer.as_slice())).expect("cannot deserialize matrix");
+    let fractions = result.fractions.expect("no fractions");
+
+    assert_eq!(buffer[5] & FRACTIONAL_VALUES_FLAG, FRACTIONAL_VALUES_FLAG);
+    assert_eq!(result.travel_times, vec![0, 0, 20, 0]);
+    assert_eq!(fractions.travel_times, vec![0., 0.4_f32 as f64, 20.25, 0.]);
+    assert_eq!(fractions.distances, vec![0., 1.5, 200., 0.]);
+}
+
+#[test]
+fn can_compress_binary_matrix() {
+    let matrix = Matrix { travel_times: vec![1; 10_000], distances: vec![2; 10_000], ..create_matrix(None, None) };
+
+    let plain = serialize_matrix(&matrix, false).unwrap();
+    let compressed = serialize_matrix(&matrix, true).unwrap();
+
+    assert!(plain.len() > 80_000);
+    assert!(compressed.len() < plain.len() / 10);
+}
+
+parameterized_test! {can_detect_invalid_matrix_on_serialization, (travel_times, distances), {
+    can_detect_invalid_matrix_on_serialization_impl(travel_times, distances);
+}}
+
+can_detect_invalid_matrix_on_serialization! {
+    case01_negative_value: (vec![0., -1., 1., 0.], vec![0., 1., 1., 0.]),
+    case02_too_big_value: (vec![0., 1., 1., 0.], vec![0., u32::MAX as f64 + 1., 1., 0.]),
+    case03_not_square: (vec![0., 1., 1.], vec![0., 1., 1.]),
+    case04_size_mismatch: (vec![0., 1., 1., 0.], vec![0., 1., 1., 0., 1.]),
+    case05_negative_fractional_value: (vec![0., -0.5, 1., 0.], vec![0., 1., 1., 0.]),
+}
+
+fn can_detect_invalid_matrix_on_serialization_impl(travel_times: Vec<f64>, distances: Vec<f64>) {
+    let matrix = create_fractional_matrix(travel_times, distances);
+
+    let result = serialize_matrix(&matrix, false);
+
+    assert_eq!(result.err().map(|err| err.kind()), Some(ErrorKind::InvalidInput));
+}
+
+#[test]
+fn can_detect_invalid_binary_matrix_on_deserialization() {
+    let buffer = serialize_matrix(&create_matrix(None, None), false).unwrap();
+
+    let mut unsupported_version = buffer.clone();
+    unsupported_version[4] = VERSION + 1;
+    let truncated = &buffer[..buffer.len() - 1];
+
+    let errors = deserialize_matrix(BufReader::new(unsupported_version.as_slice())).err().expect("no error");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "E0001");
+    assert!(errors[0].action.contains("unsupported version: 2"));
+    assert!(deserialize_matrix(BufReader::new(truncated)).is_err());
+}
+
+#[test]
+fn can_detect_binary_matrix_when_signature_is_read_partially() {
+    let matrix = create_matrix(None, None);
+    let buffer = serialize_matrix(&matrix, false).unwrap();
+
+    let result = deserialize_matrix(BufReader::with_capacity(1, buffer.as_slice())).expect("cannot deserialize matrix");
+
+    assert_eq!(result.travel_times, matrix.travel_times);
+}
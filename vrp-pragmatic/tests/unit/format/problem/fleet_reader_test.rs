@@ -15,6 +15,10 @@ fn matrix(profile: Option<&str>, timestamp: Option<f64>, fill_value: i64, size:
         travel_times: vec![fill_value; size],
         distances: vec![fill_value; size],
         error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
     }
 }
 
@@ -25,13 +29,26 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
         travel_times: vec![1; 4],
         distances: vec![2; 3],
         error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
     }
 }
 
 fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile {
+                    name: p.to_string(),
+                    speed: None,
+                    speed_curve: None,
+                    circuity: None,
+                    metric: None,
+                })
+                .collect(),
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -146,6 +163,17 @@ can_create_transport_costs_positive_cases! {
               matrix(Some("car2"), Some(10.), 4, 4)],
             &[(0, 0., 1.), (0, 10., 2.), (1, 0., 3.), (1, 10., 4.)]
         ),
+        case08: (
+            &["car"],
+            &[matrix(Some("car"), Some(0.), 1, 4), matrix(Some("car"), Some(10.), 2, 4)],
+            &[(0, 5., 1.5)]
+        ),
+        case09: (
+            &["car"],
+            &[Matrix { interpolation: Some(TimeInterpolation::Step), ..matrix(Some("car"), Some(0.), 1, 4) },
+              Matrix { interpolation: Some(TimeInterpolation::Step), ..matrix(Some("car"), Some(10.), 2, 4) }],
+            &[(0, 5., 1.), (0, 10., 2.)]
+        ),
 }
 
 fn can_create_transport_costs_positive_cases_impl(
@@ -0,0 +1,119 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{create_elitism_population, RefinementContext};
+use vrp_core::utils::Environment;
+
+fn create_test_vehicle(type_id: &str, capacity: i32, fixed: f64) -> VehicleType {
+    VehicleType {
+        costs: VehicleCosts { fixed: Some(fixed), ..create_default_vehicle_costs() },
+        ..create_vehicle_with_capacity(type_id, vec![capacity])
+    }
+}
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle("small", 1, 10.), create_test_vehicle("big", 3, 1000.)],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_create_virtual_vehicles() {
+    let problem = create_test_problem();
+
+    let problem = create_fleet_sizing_problem(&problem).unwrap();
+
+    let vehicle_ids = problem.fleet.vehicles.iter().map(|vehicle| vehicle.vehicle_ids.clone()).collect::<Vec<_>>();
+    assert_eq!(vehicle_ids, vec![vec!["small_1"], vec!["big_1"]]);
+    assert!(problem.fleet.vehicles.iter().all(|vehicle| vehicle.is_unlimited == Some(true)));
+    assert!(matches!(
+        problem.objectives.as_ref().map(|objectives| objectives.as_slice()),
+        Some([unassigned, cost]) if matches!(unassigned.as_slice(), [Objective::MinimizeUnassignedJobs { .. }])
+            && matches!(cost.as_slice(), [Objective::MinimizeCost])
+    ));
+}
+
+#[test]
+fn can_keep_user_defined_objectives() {
+    let problem = Problem { objectives: Some(vec![vec![Objective::MinimizeTours]]), ..create_test_problem() };
+
+    let problem = create_fleet_sizing_problem(&problem).unwrap();
+
+    assert!(matches!(
+        problem.objectives.as_ref().map(|objectives| objectives.as_slice()),
+        Some([tours]) if matches!(tours.as_slice(), [Objective::MinimizeTours])
+    ));
+}
+
+parameterized_test! {can_reject_unsupported_features, (has_relations, has_onboard, has_breaks), {
+    can_reject_unsupported_features_impl(has_relations, has_onboard, has_breaks);
+}}
+
+can_reject_unsupported_features! {
+    case01_relations: (true, false, false),
+    case02_onboard: (false, true, false),
+    case03_breaks: (false, false, true),
+}
+
+fn can_reject_unsupported_features_impl(has_relations: bool, has_onboard: bool, has_breaks: bool) {
+    let mut problem = create_test_problem();
+    if has_relations {
+        problem.plan.relations = Some(vec![Relation {
+            type_field: RelationType::Any,
+            jobs: vec!["job1".to_string()],
+            vehicle_id: "small_1".to_string(),
+            shift_index: None,
+        }]);
+    }
+    if has_onboard {
+        problem.fleet.vehicles[0].shifts[0].onboard = Some(vec!["job1".to_string()]);
+    }
+    if has_breaks {
+        problem.fleet.vehicles[0].shifts[0].breaks = Some(vec![VehicleBreak::Optional {
+            time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(100.)]),
+            places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
+            policy: None,
+        }]);
+    }
+
+    assert!(create_fleet_sizing_problem(&problem).is_err());
+}
+
+#[test]
+fn can_get_fleet_sizing_summary() {
+    let problem = create_fleet_sizing_problem(&create_test_problem()).unwrap();
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let population = create_elitism_population(problem.objective.clone(), environment.clone());
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone());
+
+    let solution = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment))
+        .solution
+        .to_solution(problem.extras.clone());
+
+    assert_eq!(
+        get_fleet_sizing_summary(&problem, &solution),
+        vec![
+            FleetSizingSummary { type_id: "big".to_string(), vehicles: 0, tours: 0, fixed_cost: 0. },
+            FleetSizingSummary { type_id: "small".to_string(), vehicles: 3, tours: 3, fixed_cost: 30. },
+        ]
+    );
+}
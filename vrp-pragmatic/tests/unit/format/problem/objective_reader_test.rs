@@ -21,10 +21,17 @@ fn create_problem_props() -> ProblemProperties {
         has_order: false,
         has_group: false,
         has_compatibility: false,
+        has_consistency: false,
+        has_frequency: false,
         has_tour_size_limits: false,
+        has_backhaul: false,
         has_tour_travel_limits: false,
         max_job_value: None,
         max_area_value: None,
+        has_fleet_limits: false,
+        has_priorities: false,
+        has_waiting_limits: false,
+        has_access_limits: false,
     }
 }
 
@@ -41,7 +48,7 @@ fn can_define_proper_place_for_value_objective_by_default() {
     let mut constraint = ConstraintPipeline::default();
     let props = ProblemProperties { max_job_value: Some(1.), ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(&problem, &mut constraint, &props, None);
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[0].fitness(&create_solution_with_state_value(TOTAL_VALUE_KEY, 1234.)), 1234.);
@@ -53,7 +60,7 @@ fn can_define_proper_place_for_order_objective_by_default() {
     let mut constraint = ConstraintPipeline::default();
     let props = ProblemProperties { has_order: true, ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(&problem, &mut constraint, &props, None);
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[1].fitness(&create_solution_with_state_value(TOUR_ORDER_KEY, 1234_usize)), 1234.);
@@ -69,7 +76,7 @@ fn can_define_proper_places_for_mixed_priority_and_order_objectives_by_default()
 
     let props = ProblemProperties { max_job_value: Some(1.), has_order: true, ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost = create_objective(&problem, &mut constraint, &props, None);
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[0].fitness(&insertion_ctx), 123.);
@@ -107,3 +114,40 @@ fn can_get_default_order() {
     compare_for_type("delivery", OrderResult::Default);
     compare_for_type("service", OrderResult::Default);
 }
+
+parameterized_test! {can_get_value_curve_value, (time, expected), {
+    can_get_value_curve_value_impl(time, expected);
+}}
+
+can_get_value_curve_value! {
+    case01_before_first: (0., 100.),
+    case02_at_first: (10., 100.),
+    case03_first_segment: (15., 75.),
+    case04_at_middle: (20., 50.),
+    case05_second_segment: (30., 40.),
+    case06_after_last: (100., 30.),
+}
+
+fn can_get_value_curve_value_impl(time: f64, expected: f64) {
+    let curve = vec![(10., 100.), (20., 50.), (40., 30.)];
+
+    let result = get_value_curve_value(curve.as_slice(), time);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_get_value_curve_average, (curve, expected), {
+    can_get_value_curve_average_impl(curve, expected);
+}}
+
+can_get_value_curve_average! {
+    case01_single_point: (vec![(10., 100.)], 100.),
+    case02_single_segment: (vec![(0., 100.), (10., 0.)], 50.),
+    case03_multiple_segments: (vec![(10., 100.), (20., 50.), (30., 30.)], 57.5),
+}
+
+fn can_get_value_curve_average_impl(curve: Vec<(f64, f64)>, expected: f64) {
+    let result = get_value_curve_average(curve.as_slice());
+
+    assert_eq!(result, expected);
+}
@@ -78,6 +78,8 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            parking: None,
+                            access: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
@@ -95,6 +97,8 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            parking: None,
+                            access: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -108,6 +112,8 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            parking: None,
+                            access: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -124,6 +130,8 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            parking: None,
+                            access: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
@@ -165,6 +173,7 @@ fn can_read_complex_problem() {
                         policy: None,
                     }]),
                     reloads: None,
+                    onboard: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
@@ -173,11 +182,20 @@ fn can_read_complex_problem() {
                     shift_time: Some(100.),
                     tour_size: Some(3),
                     areas: None,
+                    backhaul: None,
+                    min_shift_time: None,
+                    min_tour_size: None,
+                    max_waiting_time: None,
                 }),
+                parking: None,
+                tags: None,
+                attributes: None,
+                is_unlimited: None,
             }],
             ..create_default_fleet()
         },
         objectives: None,
+        precision: None,
     };
     let matrix = Matrix {
         profile: Some("car".to_owned()),
@@ -185,6 +203,10 @@ fn can_read_complex_problem() {
         travel_times: vec![1; 25],
         distances: vec![2; 25],
         error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -298,10 +320,34 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile {
+                    name: "car1".to_string(),
+                    speed: Some(8.),
+                    speed_curve: None,
+                    circuity: None,
+                    metric: None,
+                },
+                MatrixProfile {
+                    name: "car2".to_string(),
+                    speed: Some(10.),
+                    speed_curve: None,
+                    circuity: None,
+                    metric: None,
+                },
+                MatrixProfile {
+                    name: "car3".to_string(),
+                    speed: Some(5.),
+                    speed_curve: None,
+                    circuity: None,
+                    metric: None,
+                },
+                MatrixProfile {
+                    name: "car4".to_string(),
+                    speed: None,
+                    speed_curve: None,
+                    circuity: None,
+                    metric: None,
+                },
             ],
             ..create_default_fleet()
         },
@@ -321,3 +367,75 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+#[test]
+fn can_create_approximation_matrices_with_speed_curve_and_circuity() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (52.52599, 13.45413)),
+                create_delivery_job("job2", (52.5165, 13.3808)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: "2020-07-04T06:00:00+02:00".to_string(),
+                        latest: None,
+                        location: (52.52599, 13.45413).to_loc(),
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: "2020-07-05T02:00:00+02:00".to_string(),
+                        location: (52.52599, 13.45413).to_loc(),
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![
+                MatrixProfile {
+                    name: "car1".to_string(),
+                    speed: None,
+                    speed_curve: Some(vec![HourlySpeed { hour: 0., speed: 10. }, HourlySpeed { hour: 7., speed: 5. }]),
+                    circuity: None,
+                    metric: None,
+                },
+                MatrixProfile {
+                    name: "car2".to_string(),
+                    speed: None,
+                    speed_curve: None,
+                    circuity: Some(1.2),
+                    metric: None,
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let matrices = create_approx_matrices(&problem);
+    let get_matrices = |profile: &str| {
+        matrices
+            .iter()
+            .filter(|matrix| matrix.profile.as_deref() == Some(profile))
+            .map(|matrix| (matrix.timestamp.clone().unwrap(), matrix.travel_times[1], matrix.distances[1]))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        get_matrices("car1"),
+        vec![
+            ("2020-07-03T22:00:00Z".to_string(), 508, 5078),
+            ("2020-07-04T05:00:00Z".to_string(), 1016, 5078),
+            ("2020-07-04T22:00:00Z".to_string(), 508, 5078),
+            ("2020-07-05T05:00:00Z".to_string(), 1016, 5078),
+        ]
+    );
+    assert_eq!(
+        get_matrices("car2"),
+        vec![("2020-07-03T22:00:00Z".to_string(), 609, 6094), ("2020-07-05T22:00:00Z".to_string(), 609, 6094)]
+    );
+}
@@ -0,0 +1,129 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+const ROAD_GRAPH: &str = r#"
+{
+  "nodes": [
+    { "lat": 0.0, "lng": 0.0 },
+    { "lat": 0.0, "lng": 0.01 },
+    { "lat": 0.01, "lng": 0.0 },
+    { "lat": 0.01, "lng": 0.01 }
+  ],
+  "edges": [
+    { "from": 0, "to": 1, "length": 800, "speeds": { "car": 8, "truck": 5 } },
+    { "from": 0, "to": 2, "length": 500, "speeds": { "car": 25 } },
+    { "from": 2, "to": 1, "length": 500, "speeds": { "car": 25 } },
+    { "from": 1, "to": 0, "length": 1000, "speeds": { "car": 10 } }
+  ]
+}
+"#;
+
+fn create_test_network() -> RoadNetwork {
+    let graph = deserialize_road_graph(BufReader::new(ROAD_GRAPH.as_bytes())).expect("cannot read road graph");
+
+    RoadNetwork::new(graph).expect("cannot create road network")
+}
+
+fn create_test_edge(from: usize, to: usize, length: f64, speed: f64) -> RoadEdge {
+    RoadEdge { from, to, length, speeds: vec![("car".to_string(), speed)].into_iter().collect() }
+}
+
+#[test]
+fn can_calculate_matrix_using_fastest_paths() {
+    let network = create_test_network();
+    let locations = vec![(0., 0.).to_loc(), (0., 0.01).to_loc(), (0.01, 0.01).to_loc()];
+
+    let matrix = network.get_matrix("car", locations.as_slice()).unwrap();
+
+    assert_eq!(matrix.profile, Some("car".to_string()));
+    assert_eq!(matrix.travel_times, vec![0, 40, 0, 100, 0, 0, 0, 0, 0]);
+    assert_eq!(matrix.distances, vec![0, 1000, 0, 1000, 0, 0, 0, 0, 0]);
+    assert_eq!(matrix.error_codes, Some(vec![0, 0, 1, 0, 0, 1, 1, 1, 0]));
+}
+
+#[test]
+fn can_use_profile_specific_speeds() {
+    let network = create_test_network();
+    let locations = vec![(0., 0.).to_loc(), (0., 0.01).to_loc()];
+
+    let matrix = network.get_matrix("truck", locations.as_slice()).unwrap();
+
+    assert_eq!(matrix.travel_times, vec![0, 160, 0, 0]);
+    assert_eq!(matrix.distances, vec![0, 800, 0, 0]);
+    assert_eq!(matrix.error_codes, Some(vec![0, 0, 1, 0]));
+}
+
+#[test]
+fn can_snap_locations_to_nearest_node() {
+    let network = create_test_network();
+    let locations = vec![(0.0001, 0.0099).to_loc(), (-0.0001, 0.0002).to_loc()];
+
+    let matrix = network.get_matrix("car", locations.as_slice()).unwrap();
+
+    assert_eq!(matrix.travel_times, vec![0, 100, 40, 0]);
+    assert!(matrix.error_codes.is_none());
+}
+
+parameterized_test! {can_detect_invalid_road_graph, (edge, expected), {
+    can_detect_invalid_road_graph_impl(edge, expected);
+}}
+
+can_detect_invalid_road_graph! {
+    case01_unknown_node: (create_test_edge(0, 2, 1., 1.), "edge '0->2' refers to unknown node"),
+    case02_negative_length: (create_test_edge(0, 1, -1., 1.), "edge '0->1' has invalid length: -1"),
+    case03_zero_speed: (create_test_edge(1, 0, 1., 0.), "edge '1->0' has invalid speed"),
+}
+
+fn can_detect_invalid_road_graph_impl(edge: RoadEdge, expected: &str) {
+    let graph =
+        RoadGraph { nodes: vec![RoadNode { lat: 0., lng: 0. }, RoadNode { lat: 0., lng: 1. }], edges: vec![edge] };
+
+    let result = RoadNetwork::new(graph);
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
+
+#[test]
+fn can_detect_unsupported_locations() {
+    let network = create_test_network();
+
+    assert_eq!(
+        network.get_matrix("bike", &[(0., 0.).to_loc()]).err(),
+        Some("unknown road graph profile: 'bike'".to_string())
+    );
+    assert_eq!(
+        network.get_matrix("car", &[Location::Reference { index: 0 }]).err(),
+        Some("road network routing requires coordinates".to_string())
+    );
+}
+
+#[test]
+fn can_read_problem_with_road_network() {
+    let network = create_test_network();
+    let create_problem = |profile: &str| Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (0., 0.01))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: create_vehicle_profile_with_name(profile),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![MatrixProfile {
+                name: profile.to_string(),
+                speed: None,
+                speed_curve: None,
+                circuity: None,
+                metric: None,
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = (create_problem("car"), &network).read_pragmatic();
+    assert!(result.is_ok());
+
+    let errors = (create_problem("bike"), &network).read_pragmatic().err().expect("no errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "E0002");
+}
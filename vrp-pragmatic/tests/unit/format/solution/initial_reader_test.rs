@@ -83,9 +83,9 @@ fn can_read_basic_init_solution() {
     let solution = Solution {
         statistic: Statistic {
             cost: 32.,
-            distance: 8,
-            duration: 14,
-            times: Timing { driving: 8, serving: 4, break_time: 2, ..Timing::default() },
+            distance: 8.,
+            duration: 14.,
+            times: Timing { driving: 8., serving: 4., break_time: 2., ..Timing::default() },
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -123,7 +123,7 @@ fn can_read_basic_init_solution() {
                         arrival: "1970-01-01T00:00:05Z".to_string(),
                         departure: "1970-01-01T00:00:08Z".to_string(),
                     },
-                    distance: 3,
+                    distance: 3.,
                     parking: None,
                     load: vec![2],
                     activities: vec![
@@ -171,9 +171,9 @@ fn can_read_basic_init_solution() {
             ],
             statistic: Statistic {
                 cost: 32.,
-                distance: 8,
-                duration: 14,
-                times: Timing { driving: 8, serving: 4, break_time: 2, ..Timing::default() },
+                distance: 8.,
+                duration: 14.,
+                times: Timing { driving: 8., serving: 4., break_time: 2., ..Timing::default() },
             },
         }],
         unassigned: create_unassigned_jobs(&["job3"]),
@@ -225,7 +225,7 @@ fn can_handle_commute_error_in_init_solution() {
                         arrival: "1970-01-01T00:00:01Z".to_string(),
                         departure: "1970-01-01T00:00:02Z".to_string(),
                     },
-                    distance: 1,
+                    distance: 1.,
                     parking: None,
                     load: vec![0],
                     activities: vec![Activity {
@@ -0,0 +1,164 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem(jobs: Vec<Job>, vehicle_ids: Vec<&str>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.into_iter().map(|id| id.to_string()).collect(),
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_tour_job_ids(solution: &crate::format::solution::Solution, vehicle_id: &str) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+fn get_relation_jobs(problem: &Problem) -> Vec<(String, Vec<String>)> {
+    problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .map(|relation| (relation.vehicle_id.clone(), relation.jobs.clone()))
+        .collect()
+}
+
+parameterized_test! {can_lock_executed_part_of_tour, (at, expected), {
+    can_lock_executed_part_of_tour_impl(at, expected);
+}}
+
+can_lock_executed_part_of_tour! {
+    case01_not_started: (0., vec![]),
+    case02_on_the_way_to_first: (5., vec!["departure", "job1"]),
+    case03_served_first: (11., vec!["departure", "job1"]),
+    case04_on_the_way_to_second: (15., vec!["departure", "job1", "job2"]),
+    case05_all_executed: (100., vec!["departure", "job1", "job2", "job3"]),
+}
+
+fn can_lock_executed_part_of_tour_impl(at: f64, expected: Vec<&str>) {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job("job1", (10., 0.)),
+            create_delivery_job("job2", (20., 0.)),
+            create_delivery_job("job3", (30., 0.)),
+        ],
+        vec!["my_vehicle_1"],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    assert_eq!(get_tour_job_ids(&solution, "my_vehicle_1"), vec!["departure", "job1", "job2", "job3"]);
+
+    let result = create_reoptimization_problem(&problem, &solution, &format_time(at)).expect("cannot create problem");
+
+    let expected = if expected.is_empty() {
+        vec![]
+    } else {
+        vec![("my_vehicle_1".to_string(), expected.into_iter().map(|id| id.to_string()).collect())]
+    };
+    assert_eq!(get_relation_jobs(&result), expected);
+    let shift_start = &result.fleet.vehicles[0].shifts[0].start;
+    if at > 0. {
+        assert_eq!(shift_start.earliest, format_time(0.));
+        assert_eq!(shift_start.latest, Some(format_time(0.)));
+    } else {
+        assert_eq!(shift_start.earliest, format_time(0.));
+        assert_eq!(shift_start.latest, None);
+    }
+}
+
+#[test]
+fn can_keep_remaining_tasks_of_partially_served_job() {
+    let problem = create_test_problem(
+        vec![create_pickup_delivery_job("job1", (10., 0.), (30., 0.)), create_delivery_job("job2", (20., 0.))],
+        vec!["my_vehicle_1"],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    assert_eq!(get_tour_job_ids(&solution, "my_vehicle_1"), vec!["departure", "job1", "job2", "job1"]);
+
+    let result = create_reoptimization_problem(&problem, &solution, &format_time(11.)).expect("cannot create problem");
+
+    assert_eq!(
+        get_relation_jobs(&result),
+        vec![("my_vehicle_1".to_string(), vec!["departure".to_string(), "job1".to_string(), "job1".to_string()])]
+    );
+}
+
+#[test]
+fn can_split_vehicle_type_and_delay_not_started_vehicles() {
+    let problem = create_test_problem(
+        vec![create_delivery_job("job1", (10., 0.))],
+        vec!["my_vehicle_1", "my_vehicle_2", "my_vehicle_3"],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    let used_vehicle = solution.tours[0].vehicle_id.clone();
+
+    let result = create_reoptimization_problem(&problem, &solution, &format_time(5.)).expect("cannot create problem");
+
+    let vehicles = &result.fleet.vehicles;
+    assert_eq!(vehicles.len(), 2);
+    assert_eq!(vehicles[0].type_id, format!("my_vehicle_{}", used_vehicle));
+    assert_eq!(vehicles[0].vehicle_ids, vec![used_vehicle.clone()]);
+    assert_eq!(vehicles[0].shifts[0].start.earliest, format_time(0.));
+    assert_eq!(vehicles[1].type_id, "my_vehicle");
+    assert_eq!(vehicles[1].vehicle_ids.len(), 2);
+    assert!(!vehicles[1].vehicle_ids.contains(&used_vehicle));
+    assert_eq!(vehicles[1].shifts[0].start.earliest, format_time(5.));
+}
+
+#[test]
+fn can_reoptimize_with_new_job() {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job("job1", (10., 0.)),
+            create_delivery_job("job2", (20., 0.)),
+            create_delivery_job("job3", (30., 0.)),
+        ],
+        vec!["my_vehicle_1"],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    let mut problem = problem;
+    problem.plan.jobs.push(create_delivery_job("job4", (10., 0.)));
+
+    let problem = create_reoptimization_problem(&problem, &solution, &format_time(15.)).expect("cannot create problem");
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let job_ids = get_tour_job_ids(&solution, "my_vehicle_1");
+    assert_eq!(job_ids.len(), 5);
+    assert_eq!(job_ids[..3].to_vec(), vec!["departure", "job1", "job2"]);
+    assert!(job_ids.contains(&"job4".to_string()));
+}
+
+#[test]
+fn can_reduce_time_windows_of_locked_jobs() {
+    let problem = create_test_problem(
+        vec![create_delivery_job_with_times("job1", (10., 0.), vec![(10, 20), (30, 40)], 1.)],
+        vec!["my_vehicle_1"],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+
+    let result = create_reoptimization_problem(&problem, &solution, &format_time(5.)).expect("cannot create problem");
+
+    let places = &result.plan.jobs[0].deliveries.as_ref().unwrap()[0].places;
+    assert_eq!(places.len(), 1);
+    assert_eq!(places[0].times, Some(vec![vec![format_time(10.), format_time(20.)]]));
+}
@@ -45,9 +45,9 @@ fn can_create_solution() {
         Solution {
             statistic: Statistic {
                 cost: 52.,
-                distance: 20,
-                duration: 22,
-                times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                distance: 20.,
+                duration: 22.,
+                times: Timing { driving: 20., serving: 2., ..Timing::default() },
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -89,9 +89,9 @@ fn can_create_solution() {
                 ],
                 statistic: Statistic {
                     cost: 52.,
-                    distance: 20,
-                    duration: 22,
-                    times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                    distance: 20.,
+                    duration: 22.,
+                    times: Timing { driving: 20., serving: 2., ..Timing::default() },
                 },
             }],
             ..create_empty_solution()
@@ -117,9 +117,9 @@ fn can_merge_activities_with_same_location_in_one_stop() {
         solution.statistic,
         Statistic {
             cost: 32.,
-            distance: 10,
-            duration: 12,
-            times: Timing { driving: 10, serving: 2, ..Timing::default() },
+            distance: 10.,
+            duration: 12.,
+            times: Timing { driving: 10., serving: 2., ..Timing::default() },
         }
     );
     assert_eq!(solution.tours.len(), 1);
@@ -178,7 +178,7 @@ fn can_merge_activities_with_commute_in_one_stop_impl(
         .collect();
     let route = create_route_with_activities(&problem.fleet, "v1", activities);
 
-    let tour = create_tour(&problem, &route, &coord_index, &Default::default());
+    let tour = create_tour(&problem, &route, &coord_index, &Default::default(), &Precision::Integer);
 
     assert_eq!(expected.len(), tour.stops.len() - 2);
     expected.iter().zip(tour.stops.iter().skip(1)).for_each(|((expected_stop_idx, expected_acts), actual_stop)| {
@@ -222,7 +222,7 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     let reserved_times_index =
         vec![(route.actor.clone(), vec![TimeSpan::Window(TimeWindow::new(4., 5.))])].into_iter().collect();
 
-    let tour = create_tour(&problem, &route, &coord_index, &reserved_times_index);
+    let tour = create_tour(&problem, &route, &coord_index, &reserved_times_index, &Precision::Integer);
 
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
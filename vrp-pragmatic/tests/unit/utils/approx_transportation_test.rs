@@ -26,12 +26,11 @@ fn can_use_approximated_with_matrix_costs() {
     let profile = Profile::default();
     let locations = get_test_locations();
     let speed = 10.;
-    let approx_data = get_approx_transportation(&locations, &[speed]);
-    assert_eq!(approx_data.len(), 1);
+    let distances = get_approx_distances(&locations, &DistanceMetric::Euclidean);
+    assert_eq!(distances.len(), 9);
 
-    let (durations, distances) = approx_data.first().unwrap();
-    let durations = durations.iter().map(|&d| d as f64).collect();
-    let distances = distances.iter().map(|&d| d as f64).collect();
+    let durations = distances.iter().map(|d| (d / speed).round()).collect();
+    let distances = distances.iter().map(|d| d.round()).collect();
 
     let costs = create_matrix_transport_cost(vec![MatrixData::new(profile.index, None, durations, distances)])
         .expect("Cannot create matrix transport costs");
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 
 fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
@@ -74,3 +75,206 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_frequency, (visits, min_gap_days, allowed_days, expected), {
+    can_detect_invalid_frequency_impl(visits, min_gap_days, allowed_days, expected);
+}}
+
+can_detect_invalid_frequency! {
+    case01: (2, None, None, false),
+    case02: (2, Some(2), Some(vec![0, 2]), false),
+    case03: (0, None, None, true),
+    case04: (2, Some(0), None, true),
+    case05: (3, None, Some(vec![0, 1]), true),
+    case06: (2, None, Some(vec![1, 1]), true),
+}
+
+fn can_detect_invalid_frequency_impl(
+    visits: usize,
+    min_gap_days: Option<usize>,
+    allowed_days: Option<Vec<usize>>,
+    expected: bool,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                frequency: Some(JobFrequency { visits, min_gap_days, allowed_days }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_invalid_frequency(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1108", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_unsupported_frequency_usage, (group, relation, onboard, expected), {
+    can_detect_unsupported_frequency_usage_impl(group, relation, onboard, expected);
+}}
+
+can_detect_unsupported_frequency_usage! {
+    case01: (None, false, false, false),
+    case02: (Some("group1"), false, false, true),
+    case03: (None, true, false, true),
+    case04: (None, false, true, true),
+}
+
+fn can_detect_unsupported_frequency_usage_impl(group: Option<&str>, relation: bool, onboard: bool, expected: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                group: group.map(|group| group.to_string()),
+                frequency: Some(JobFrequency { visits: 2, min_gap_days: None, allowed_days: None }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            relations: if relation {
+                Some(vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec!["job1".to_string()],
+                    vehicle_id: "v1_1".to_string(),
+                    shift_index: None,
+                }])
+            } else {
+                None
+            },
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    onboard: if onboard { Some(vec!["job1".to_string()]) } else { None },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle("v1")
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_unsupported_frequency_usage(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err();
+
+    if expected {
+        assert_result("E1109", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_priority, (priority, expected), {
+    can_detect_invalid_priority_impl(priority, expected);
+}}
+
+can_detect_invalid_priority! {
+    case01: (None, false),
+    case02: (Some(1), false),
+    case03: (Some(0), true),
+}
+
+fn can_detect_invalid_priority_impl(priority: Option<usize>, expected: bool) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { priority, ..create_delivery_job("job1", (1., 0.)) }], ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1110_invalid_priority(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1110", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_value_curve, (curve, expected), {
+    can_detect_invalid_value_curve_impl(curve, expected);
+}}
+
+can_detect_invalid_value_curve! {
+    case01: (Some(vec![(0., 10.), (10., 0.)]), false),
+    case02: (Some(vec![(0., 10.)]), false),
+    case03: (None, false),
+    case04: (Some(vec![]), true),
+    case05: (Some(vec![(10., 10.), (0., 0.)]), true),
+    case06: (Some(vec![(0., 10.), (0., 0.)]), true),
+    case07: (Some(vec![(0., 10.), (10., -1.)]), true),
+}
+
+fn can_detect_invalid_value_curve_impl(curve: Option<Vec<(f64, f64)>>, expected: bool) {
+    let value_curve = curve
+        .map(|curve| curve.into_iter().map(|(time, value)| JobValuePoint { time: format_time(time), value }).collect());
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { value_curve, ..create_delivery_job("job1", (1., 0.)) }], ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1111_invalid_value_curve(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1111", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_place_access, (max_weight, max_height, expected), {
+    can_detect_invalid_place_access_impl(max_weight, max_height, expected);
+}}
+
+can_detect_invalid_place_access! {
+    case01: (Some(7.5), Some(4.), false),
+    case02: (None, None, false),
+    case03: (Some(0.), None, true),
+    case04: (None, Some(-1.), true),
+    case05: (Some(f64::INFINITY), None, true),
+}
+
+fn can_detect_invalid_place_access_impl(max_weight: Option<f64>, max_height: Option<f64>, expected: bool) {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap().first_mut().unwrap().places.first_mut().unwrap().access =
+        Some(PlaceAccess { max_weight, max_height, max_length: None });
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1112_invalid_place_access(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if expected {
+        assert_result("E1112", "job1", result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_max_neighbors, (max_neighbors, expected), {
+    can_detect_invalid_max_neighbors_impl(max_neighbors, expected);
+}}
+
+can_detect_invalid_max_neighbors! {
+    case01: (Some(1), None),
+    case02: (None, None),
+    case03: (Some(0), Some("E1113".to_string())),
+}
+
+fn can_detect_invalid_max_neighbors_impl(max_neighbors: Option<usize>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], max_neighbors, ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1113_invalid_max_neighbors(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected);
+}
@@ -1,5 +1,7 @@
 use super::*;
 use crate::format::problem::Objective::*;
+use crate::format::solution::{Solution, Tour};
+use crate::format_time;
 use crate::helpers::create_empty_problem;
 use crate::helpers::*;
 
@@ -197,29 +199,43 @@ fn can_detect_missing_order_objective_impl(objectives: Option<Vec<Vec<Objective>
     assert_eq!(result.err().map(|e| e.code), expected);
 }
 
-parameterized_test! {can_detect_missing_value_objective, (objectives, expected), {
-    can_detect_missing_value_objective_impl(objectives, expected);
+parameterized_test! {can_detect_missing_value_objective, (objectives, has_value_curve, expected), {
+    can_detect_missing_value_objective_impl(objectives, has_value_curve, expected);
 }}
 
 can_detect_missing_value_objective! {
     case01: (Some(vec![
                 vec![MinimizeUnassignedJobs { breaks: None }],
                 vec![MinimizeCost],
-            ]), Some("E1607".to_string())),
+            ]), false, Some("E1607".to_string())),
     case02: (Some(vec![
                 vec![MinimizeUnassignedJobs { breaks: None }],
                 vec![MaximizeValue { breaks: None, reduction_factor: None }],
                 vec![MinimizeCost],
-            ]), None),
-    case03: (None, None),
+            ]), false, None),
+    case03: (None, false, None),
+    case04: (Some(vec![
+                vec![MinimizeUnassignedJobs { breaks: None }],
+                vec![MinimizeCost],
+            ]), true, Some("E1607".to_string())),
 }
 
-fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Vec<Objective>>>, expected: Option<String>) {
+fn can_detect_missing_value_objective_impl(
+    objectives: Option<Vec<Vec<Objective>>>,
+    has_value_curve: bool,
+    expected: Option<String>,
+) {
+    let (value, value_curve) = if has_value_curve {
+        (None, Some(vec![JobValuePoint { time: format_time(0.), value: 1. }]))
+    } else {
+        (Some(1.), None)
+    };
     let problem = Problem {
         plan: Plan {
             jobs: vec![Job {
                 deliveries: Some(vec![create_task((1., 0.), None)]),
-                value: Some(1.),
+                value,
+                value_curve,
                 ..create_job("job1")
             }],
             ..create_empty_plan()
@@ -251,3 +267,142 @@ fn can_detect_missing_area_objective() {
 
     assert_eq!(result.err().unwrap().code, "E1608".to_string());
 }
+
+parameterized_test! {can_detect_invalid_plan_changes_reference, (reference, expected), {
+    can_detect_invalid_plan_changes_reference_impl(reference, expected);
+}}
+
+can_detect_invalid_plan_changes_reference! {
+    case01_correct: (vec![("my_vehicle_1", 0, vec!["departure", "job1", "job2", "arrival"])], None),
+    case02_multi_job: (vec![("my_vehicle_1", 0, vec!["job1", "job2", "job1"])], None),
+    case03_unknown_job: (vec![("my_vehicle_1", 0, vec!["job1", "job3"])], Some("job3")),
+    case04_unknown_vehicle: (vec![("my_vehicle_3", 0, vec!["job1"])], Some("my_vehicle_3")),
+    case05_unknown_shift: (vec![("my_vehicle_1", 1, vec!["job1"])], Some("my_vehicle_1")),
+    case06_duplicate_job: (vec![("my_vehicle_1", 0, vec!["job1"]), ("my_vehicle_2", 0, vec!["job1"])], Some("job1")),
+}
+
+fn can_detect_invalid_plan_changes_reference_impl(reference: Vec<(&str, usize, Vec<&str>)>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![vec![MinimizePlanChanges { is_sequence_aware: None }], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let reference = Solution {
+        tours: reference
+            .into_iter()
+            .map(|(vehicle_id, shift_index, jobs)| Tour {
+                vehicle_id: vehicle_id.to_string(),
+                shift_index,
+                stops: jobs
+                    .into_iter()
+                    .map(|job_id| {
+                        create_stop_with_activity(
+                            job_id,
+                            "delivery",
+                            (0., 0.),
+                            0,
+                            ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                            0,
+                        )
+                    })
+                    .collect(),
+                ..create_empty_tour()
+            })
+            .collect(),
+        ..create_empty_solution()
+    };
+    let ctx = ValidationContext::new(&problem, None, &coord_index).with_reference(Some(&reference));
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1609_invalid_plan_changes_reference(&ctx, &objectives);
+
+    assert_eq!(
+        result.err().map(|err| (err.code, err.action)),
+        expected.map(|id| (
+            "E1609".to_string(),
+            format!(
+                "make sure that vehicles, their shifts and jobs from the reference solution exist and each job is assigned only to one tour: '{}'",
+                id
+            )
+        ))
+    );
+}
+
+parameterized_test! {can_detect_consistency_objective_issues, (objectives, consistency_key, expected), {
+    can_detect_consistency_objective_issues_impl(objectives, consistency_key, expected);
+}}
+
+can_detect_consistency_objective_issues! {
+    case01_redundant: (vec![vec![MinimizeInconsistency], vec![min_cost()]], None, Some("E1610")),
+    case02_missing: (vec![vec![min_cost()]], Some("customer1"), Some("E1611")),
+    case03_correct: (vec![vec![MinimizeInconsistency], vec![min_cost()]], Some("customer1"), None),
+    case04_correct: (vec![vec![min_cost()]], None, None),
+}
+
+fn can_detect_consistency_objective_issues_impl(
+    objectives: Vec<Vec<Objective>>,
+    consistency_key: Option<&str>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                consistency_key: consistency_key.map(|key| key.to_string()),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1610_no_jobs_with_consistency_objective(&ctx, &objectives)
+        .and_then(|_| check_e1611_jobs_with_consistency_but_no_objective(&ctx, &objectives));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_priority_objective_issues, (objectives, priority, expected), {
+    can_detect_priority_objective_issues_impl(objectives, priority, expected);
+}}
+
+can_detect_priority_objective_issues! {
+    case01_redundant: (vec![vec![MinimizeUnassignedJobsByPriority { breaks: None }], vec![min_cost()]], None, Some("E1612")),
+    case02_missing: (vec![vec![min_cost()]], Some(2), Some("E1613")),
+    case03_correct: (vec![vec![MinimizeUnassignedJobsByPriority { breaks: None }], vec![min_cost()]], Some(2), None),
+    case04_correct: (vec![vec![min_cost()]], None, None),
+}
+
+fn can_detect_priority_objective_issues_impl(
+    objectives: Vec<Vec<Objective>>,
+    priority: Option<usize>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { priority, ..create_delivery_job("job1", (1., 0.)) }], ..create_empty_plan() },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1612_no_jobs_with_priority_objective(&ctx, &objectives)
+        .and_then(|_| check_e1613_jobs_with_priority_but_no_objective(&ctx, &objectives));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
@@ -6,8 +6,20 @@ fn can_detect_duplicates() {
     let problem = Problem {
         fleet: Fleet {
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile {
+                    name: "my_vehicle".to_string(),
+                    speed: None,
+                    speed_curve: None,
+                    circuity: None,
+                    metric: None,
+                },
+                MatrixProfile {
+                    name: "my_vehicle".to_string(),
+                    speed: None,
+                    speed_curve: None,
+                    circuity: None,
+                    metric: None,
+                },
             ],
             ..create_default_fleet()
         },
@@ -32,22 +44,25 @@ fn can_detect_empty_profiles() {
     assert_eq!(result.err().map(|err| err.code), Some("E1501".to_string()));
 }
 
-#[test]
-fn can_detect_mixed_locations() {
-    let problem = Problem {
-        plan: Plan {
-            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job("job2", (1., 0.))],
-            relations: None,
-            ..create_empty_plan()
-        },
-        ..create_empty_problem()
-    };
+parameterized_test! {can_detect_mixed_locations, (jobs, expected), {
+    can_detect_mixed_locations_impl(jobs, expected);
+}}
+
+can_detect_mixed_locations! {
+    case01_index_and_coordinate: (vec![create_delivery_job_with_index("job1", 0), create_delivery_job("job2", (1., 0.))], Some("E1502".to_string())),
+    case02_point_and_coordinate: (vec![create_delivery_job_with_point("job1", (0., 1.)), create_delivery_job("job2", (1., 0.))], Some("E1502".to_string())),
+    case03_point_and_index: (vec![create_delivery_job_with_point("job1", (0., 1.)), create_delivery_job_with_index("job2", 0)], Some("E1502".to_string())),
+    case04_points: (vec![create_delivery_job_with_point("job1", (0., 1.)), create_delivery_job_with_point("job2", (1., 0.))], None),
+}
+
+fn can_detect_mixed_locations_impl(jobs: Vec<Job>, expected: Option<String>) {
+    let problem = Problem { plan: Plan { jobs, relations: None, ..create_empty_plan() }, ..create_empty_problem() };
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, None, &coord_index);
 
     let result = check_e1502_no_location_type_mix(&ctx, ctx.coord_index.get_used_types());
 
-    assert_eq!(result.err().map(|err| err.code), Some("E1502".to_string()));
+    assert_eq!(result.err().map(|err| err.code), expected);
 }
 
 #[test]
@@ -83,6 +98,10 @@ fn can_detect_index_mismatch() {
         travel_times: vec![1; 4],
         distances: vec![1; 4],
         error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
     }];
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
@@ -100,7 +119,13 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_curve: None,
+                circuity: None,
+                metric: None,
+            }],
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -112,3 +137,236 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_matrix_costs, (costs, expected), {
+    can_detect_invalid_matrix_costs_impl(costs, expected);
+}}
+
+can_detect_invalid_matrix_costs! {
+    case01: (None, None),
+    case02: (Some(vec![0., 1., 2., 0.]), None),
+    case03: (Some(vec![0., 1., 2.]), Some("E1506".to_string())),
+    case04: (Some(vec![0., -1., 2., 0.]), Some("E1506".to_string())),
+}
+
+fn can_detect_invalid_matrix_costs_impl(costs: Option<Vec<f64>>, expected: Option<String>) {
+    let problem = create_empty_problem();
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![1; 4],
+        distances: vec![1; 4],
+        error_codes: None,
+        costs,
+        interpolation: None,
+        compact: None,
+        fractions: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1506_matrix_costs_are_correct(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_matrix_timestamps, (timestamp, expected), {
+    can_detect_invalid_matrix_timestamps_impl(timestamp, expected);
+}}
+
+can_detect_invalid_matrix_timestamps! {
+    case01: (None, None),
+    case02: (Some("2020-07-04T07:00:00Z"), None),
+    case03: (Some("2020-07-04 07:00"), Some("E1507".to_string())),
+}
+
+fn can_detect_invalid_matrix_timestamps_impl(timestamp: Option<&str>, expected: Option<String>) {
+    let problem = create_empty_problem();
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: timestamp.map(|timestamp| timestamp.to_string()),
+        travel_times: vec![1; 4],
+        distances: vec![1; 4],
+        error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1507_matrix_timestamps_are_correct(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_profile_speed_curve, (speed_curve, expected), {
+    can_detect_invalid_profile_speed_curve_impl(speed_curve, expected);
+}}
+
+can_detect_invalid_profile_speed_curve! {
+    case01: (None, None),
+    case02: (Some(vec![(0., 10.), (7., 5.)]), None),
+    case03: (Some(vec![(0., 10.)]), Some("E1508".to_string())),
+    case04: (Some(vec![(7., 5.), (0., 10.)]), Some("E1508".to_string())),
+    case05: (Some(vec![(0., 10.), (24., 5.)]), Some("E1508".to_string())),
+    case06: (Some(vec![(0., 10.), (7., 0.)]), Some("E1508".to_string())),
+}
+
+fn can_detect_invalid_profile_speed_curve_impl(speed_curve: Option<Vec<(f64, f64)>>, expected: Option<String>) {
+    let speed_curve =
+        speed_curve.map(|curve| curve.into_iter().map(|(hour, speed)| HourlySpeed { hour, speed }).collect());
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_curve,
+                circuity: None,
+                metric: None,
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1508_profile_speed_curves_are_correct(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_profile_circuity, (circuity, expected), {
+    can_detect_invalid_profile_circuity_impl(circuity, expected);
+}}
+
+can_detect_invalid_profile_circuity! {
+    case01: (None, None),
+    case02: (Some(1.), None),
+    case03: (Some(1.3), None),
+    case04: (Some(0.9), Some("E1509".to_string())),
+    case05: (Some(f64::INFINITY), Some("E1509".to_string())),
+}
+
+fn can_detect_invalid_profile_circuity_impl(circuity: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_curve: None,
+                circuity,
+                metric: None,
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1509_profile_circuity_is_correct(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_fractional_matrix_values, (precision, value, expected), {
+    can_detect_fractional_matrix_values_impl(precision, value, expected);
+}}
+
+can_detect_fractional_matrix_values! {
+    case01: (None, 1., None),
+    case02: (None, 1.5, Some("E1510".to_string())),
+    case03: (Some(Precision::Integer), 1.5, Some("E1510".to_string())),
+    case04: (Some(Precision::Float), 1.5, None),
+}
+
+fn can_detect_fractional_matrix_values_impl(precision: Option<Precision>, value: f64, expected: Option<String>) {
+    let problem = Problem { precision, ..create_empty_problem() };
+    let (travel_times, distances, fractions) =
+        split_matrix_values(vec![0., value, value, 0.], vec![0., value, value, 0.]);
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times,
+        distances,
+        error_codes: None,
+        costs: None,
+        interpolation: None,
+        compact: None,
+        fractions,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1510_matrix_values_match_precision(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_unreachable_shift_end, (error_codes, has_end, expected), {
+    can_detect_unreachable_shift_end_impl(error_codes, has_end, expected);
+}}
+
+can_detect_unreachable_shift_end! {
+    case01: (None, true, None),
+    case02: (Some(vec![0, 0, 0, 0]), true, None),
+    case03: (Some(vec![0, 1, 0, 0]), true, Some("E1511".to_string())),
+    case04: (Some(vec![0, 0, 1, 0]), true, None),
+    case05: (Some(vec![0, 1, 0, 0]), false, None),
+}
+
+fn can_detect_unreachable_shift_end_impl(error_codes: Option<Vec<i64>>, has_end: bool, expected: Option<String>) {
+    let shift = if has_end {
+        create_default_vehicle_shift_with_locations((0., 0.), (1., 0.))
+    } else {
+        create_default_open_vehicle_shift()
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix { error_codes, ..create_matrix(vec![0, 1, 1, 0]) }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1511_vehicle_shift_end_is_reachable(&ctx, &matrices);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_unreachable_job_locations, (error_codes, has_end, expected), {
+    can_detect_unreachable_job_locations_impl(error_codes, has_end, expected);
+}}
+
+can_detect_unreachable_job_locations! {
+    case01: (Some(vec![0, 0, 0, 0]), true, None),
+    case02: (Some(vec![0, 1, 0, 0]), true, Some("E1512".to_string())),
+    case03: (Some(vec![0, 1, 0, 0]), false, None),
+    case04: (Some(vec![0, 0, 1, 0]), false, Some("E1512".to_string())),
+}
+
+fn can_detect_unreachable_job_locations_impl(error_codes: Option<Vec<i64>>, has_end: bool, expected: Option<String>) {
+    let shift = if has_end { create_default_vehicle_shift() } else { create_default_open_vehicle_shift() };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix { error_codes, ..create_matrix(vec![0, 1, 1, 0]) }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1512_job_locations_are_reachable(&ctx, &matrices);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+    assert!(ctx.validate().is_ok());
+}
@@ -73,6 +73,10 @@ fn can_detect_invalid_area_impl(
                             .map(|area_id| AreaLimit { area_id: area_id.to_string(), job_value: 1. })
                             .collect()]
                     }),
+                    backhaul: None,
+                    min_shift_time: None,
+                    min_tour_size: None,
+                    max_waiting_time: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -239,3 +243,323 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_handle_onboard_jobs, (onboard, vehicle_ids, expected), {
+    can_handle_onboard_jobs_impl(onboard, vehicle_ids, expected);
+}}
+
+can_handle_onboard_jobs! {
+    case01: (vec!["job1"], vec!["v1"], None),
+    case02: (vec!["job1", "job2"], vec!["v1"], None),
+    case03: (vec!["job1"], vec!["v1", "v2"], Some("E1310".to_string())),
+    case04: (vec!["job3"], vec!["v1"], Some("E1310".to_string())),
+    case05: (vec!["job4"], vec!["v1"], Some("E1310".to_string())),
+    case06: (vec!["job1", "job1"], vec!["v1"], Some("E1310".to_string())),
+}
+
+fn can_handle_onboard_jobs_impl(onboard: Vec<&str>, vehicle_ids: Vec<&str>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", (1., 0.), (2., 0.)),
+                create_delivery_job("job2", (1., 0.)),
+                create_pickup_job("job3", (1., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.iter().map(|id| id.to_string()).collect(),
+                shifts: vec![VehicleShift {
+                    onboard: Some(onboard.iter().map(|id| id.to_string()).collect()),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_onboard_jobs_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_fleet_limits, (type_id, tag, min_tours, max_tours, expected), {
+    can_handle_fleet_limits_impl(type_id, tag, min_tours, max_tours, expected);
+}}
+
+can_handle_fleet_limits! {
+    case01_type: (Some("my_vehicle"), None, Some(1), Some(2), None),
+    case02_tag: (None, Some("rental"), None, Some(2), None),
+    case03_unknown_type: (Some("unknown"), None, Some(1), None, Some("E1311".to_string())),
+    case04_unknown_tag: (None, Some("unknown"), Some(1), None, Some("E1311".to_string())),
+    case05_both: (Some("my_vehicle"), Some("rental"), Some(1), None, Some("E1311".to_string())),
+    case06_none: (None, None, Some(1), None, Some("E1311".to_string())),
+    case07_min_greater_max: (Some("my_vehicle"), None, Some(2), Some(1), Some("E1311".to_string())),
+    case08_no_tours: (Some("my_vehicle"), None, None, None, Some("E1311".to_string())),
+}
+
+fn can_handle_fleet_limits_impl(
+    type_id: Option<&str>,
+    tag: Option<&str>,
+    min_tours: Option<usize>,
+    max_tours: Option<usize>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { tags: Some(vec!["rental".to_string()]), ..create_default_vehicle_type() }],
+            limits: Some(vec![FleetLimit {
+                type_id: type_id.map(|id| id.to_string()),
+                tag: tag.map(|tag| tag.to_string()),
+                max_tours,
+                min_tours,
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_fleet_limits_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_vehicle_min_limits, (tour_size, shift_time, expected), {
+    can_handle_vehicle_min_limits_impl(tour_size, shift_time, expected);
+}}
+
+can_handle_vehicle_min_limits! {
+    case01_valid: ((Some(2), Some(3)), (Some(10.), Some(20.)), None),
+    case02_only_min: ((Some(2), None), (Some(10.), None), None),
+    case03_invalid_tour_size: ((Some(3), Some(2)), (None, None), Some("E1312".to_string())),
+    case04_invalid_shift_time: ((None, None), (Some(20.), Some(10.)), Some("E1312".to_string())),
+}
+
+fn can_handle_vehicle_min_limits_impl(
+    tour_size: (Option<usize>, Option<usize>),
+    shift_time: (Option<f64>, Option<f64>),
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: shift_time.1,
+                    tour_size: tour_size.1,
+                    areas: None,
+                    backhaul: None,
+                    min_shift_time: shift_time.0,
+                    min_tour_size: tour_size.0,
+                    max_waiting_time: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1312_vehicle_min_limits_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_vehicle_waiting_limits, (per_stop, per_tour, expected), {
+    can_handle_vehicle_waiting_limits_impl(per_stop, per_tour, expected);
+}}
+
+can_handle_vehicle_waiting_limits! {
+    case01_valid: (Some(10.), Some(20.), None),
+    case02_only_per_stop: (Some(10.), None, None),
+    case03_only_per_tour: (None, Some(0.), None),
+    case04_invalid_per_stop: (Some(-1.), Some(20.), Some("E1313".to_string())),
+    case05_invalid_per_tour: (None, Some(-1.), Some("E1313".to_string())),
+}
+
+fn can_handle_vehicle_waiting_limits_impl(per_stop: Option<f64>, per_tour: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    areas: None,
+                    backhaul: None,
+                    min_shift_time: None,
+                    min_tour_size: None,
+                    max_waiting_time: Some(WaitingTimeLimits { per_stop, per_tour }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1313_vehicle_waiting_limits_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_vehicle_travel_buffers, (percentage, fixed, slack, expected), {
+    can_handle_vehicle_travel_buffers_impl(percentage, fixed, slack, expected);
+}}
+
+can_handle_vehicle_travel_buffers! {
+    case01_valid: (Some(10.), Some(60.), Some(300.), None),
+    case02_only_percentage: (Some(10.), None, None, None),
+    case03_invalid_percentage: (Some(-1.), None, None, Some("E1314".to_string())),
+    case04_invalid_fixed: (None, Some(-1.), None, Some("E1314".to_string())),
+    case05_invalid_slack: (None, None, Some(-1.), Some("E1314".to_string())),
+}
+
+fn can_handle_vehicle_travel_buffers_impl(
+    percentage: Option<f64>,
+    fixed: Option<f64>,
+    slack: Option<f64>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile {
+                    buffer: Some(TravelBuffer { percentage, fixed, slack }),
+                    ..create_default_vehicle_profile()
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1314_vehicle_travel_buffers_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_vehicle_attributes, (weight, height, expected), {
+    can_handle_vehicle_attributes_impl(weight, height, expected);
+}}
+
+can_handle_vehicle_attributes! {
+    case01_valid: (Some(3.5), Some(2.8), None),
+    case02_empty: (None, None, None),
+    case03_zero_weight: (Some(0.), None, None),
+    case04_invalid_weight: (Some(-1.), None, Some("E1315".to_string())),
+    case05_invalid_height: (None, Some(f64::NAN), Some("E1315".to_string())),
+}
+
+fn can_handle_vehicle_attributes_impl(weight: Option<f64>, height: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                attributes: Some(VehicleAttributes { weight, height, length: None }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1315_vehicle_attributes_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_unlimited_vehicle_shifts, (is_unlimited, has_onboard, expected), {
+    can_handle_unlimited_vehicle_shifts_impl(is_unlimited, has_onboard, expected);
+}}
+
+can_handle_unlimited_vehicle_shifts! {
+    case01_unlimited: (Some(true), false, None),
+    case02_limited_with_onboard: (None, true, None),
+    case03_unlimited_with_onboard: (Some(true), true, Some("E1316".to_string())),
+}
+
+fn can_handle_unlimited_vehicle_shifts_impl(is_unlimited: Option<bool>, has_onboard: bool, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    onboard: if has_onboard { Some(vec!["job1".to_string()]) } else { None },
+                    ..create_default_vehicle_shift()
+                }],
+                is_unlimited,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1316_unlimited_vehicle_shifts_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_collision_with_unlimited_vehicle_ids, (other_id, own_ids, expected), {
+    can_detect_collision_with_unlimited_vehicle_ids_impl(other_id, own_ids, expected);
+}}
+
+can_detect_collision_with_unlimited_vehicle_ids! {
+    case01_no_collision: ("other_1", vec!["typeA_1"], None),
+    case02_listed_index: ("typeA_1", vec!["a"], None),
+    case03_other_type: ("typeA_2", vec!["a"], Some("E1317".to_string())),
+    case04_own_type: ("other_1", vec!["typeA_1", "typeA_5"], Some("E1317".to_string())),
+    case05_own_type_listed: ("other_1", vec!["typeA_1", "typeA_2"], None),
+}
+
+fn can_detect_collision_with_unlimited_vehicle_ids_impl(other_id: &str, own_ids: Vec<&str>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    type_id: "typeA".to_string(),
+                    vehicle_ids: own_ids.into_iter().map(|id| id.to_string()).collect(),
+                    is_unlimited: Some(true),
+                    ..create_default_vehicle_type()
+                },
+                VehicleType {
+                    type_id: "other".to_string(),
+                    vehicle_ids: vec![other_id.to_string()],
+                    ..create_default_vehicle_type()
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1317_unlimited_vehicle_ids_are_unique(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
@@ -3,6 +3,7 @@
 mod routing_test;
 
 use std::sync::Arc;
+use vrp_core::algorithms::geometry::Point;
 use vrp_core::models::common::Location;
 use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData, TransportCost};
 
@@ -27,14 +28,13 @@ impl CoordIndex {
 
     /// Creates transport.
     pub fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
-        let matrix_values = self
-            .locations
+        let points = self.locations.iter().map(|&(x, y)| Point::new(x as f64, y as f64)).collect::<Vec<_>>();
+
+        let matrix_values = points
             .iter()
-            .flat_map(|&(x1, y1)| {
-                self.locations.iter().map(move |&(x2, y2)| {
-                    let x = x1 as f64 - x2 as f64;
-                    let y = y1 as f64 - y2 as f64;
-                    let value = (x * x + y * y).sqrt();
+            .flat_map(|p1| {
+                points.iter().map(move |p2| {
+                    let value = p1.distance_to_point(p2);
 
                     if is_rounded {
                         value.round()